@@ -0,0 +1,246 @@
+use crate::decimal::{add_by_internal, is_all_zero, overflow_error};
+#[cfg(feature = "stats")]
+use crate::decimal::{add_one_internal, MAX_PRECISION};
+#[cfg(feature = "stats")]
+use crate::ops::wide::div_rem_internal;
+use crate::ops::wide::{cmp_slices, mul_by_pow10, sub_slices, wide_to_decimal_rounded};
+use crate::{Decimal, Error, RoundingStrategy};
+
+use core::{
+    cmp::Ordering,
+    iter::FromIterator,
+    ops::{AddAssign, SubAssign},
+};
+
+/// An accumulator which sums `Decimal` values exactly, only rounding once when the result is
+/// requested.
+///
+/// Adding two `Decimal` numbers with different scales may require more than 28 significant digits
+/// to represent exactly, in which case the result is rounded. When summing many values this
+/// rounding error can build up. A `DecimalAccumulator` instead keeps a 256 bit running total at
+/// the largest scale it has seen, which is enough to exactly sum more than 2<sup>64</sup> values
+/// of any magnitude and scale.
+///
+/// # Example
+///
+/// ```
+/// use rust_decimal::{Decimal, DecimalAccumulator};
+/// use core::str::FromStr;
+///
+/// let large = Decimal::from_str("10000000000000000000000000000").unwrap();
+/// let small = Decimal::from_str("0.4").unwrap();
+///
+/// // Summing directly rounds after every addition, so the small values are lost
+/// let naive = large + small + small;
+/// assert_eq!(naive.to_string(), "10000000000000000000000000000");
+///
+/// let mut accumulator = DecimalAccumulator::new();
+/// accumulator.add(large);
+/// accumulator.add(small);
+/// accumulator.add(small);
+/// let (sum, exact) = accumulator.finish().unwrap();
+/// assert_eq!(sum.to_string(), "10000000000000000000000000001");
+/// assert!(!exact);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DecimalAccumulator {
+    mantissa: [u32; 8],
+    scale: u32,
+    negative: bool,
+}
+
+impl DecimalAccumulator {
+    /// Creates a new accumulator with a running total of zero.
+    pub const fn new() -> DecimalAccumulator {
+        DecimalAccumulator {
+            mantissa: [0; 8],
+            scale: 0,
+            negative: false,
+        }
+    }
+
+    /// Returns `true` if the running total is exactly zero.
+    pub fn is_zero(&self) -> bool {
+        is_all_zero(&self.mantissa)
+    }
+
+    /// Returns the scale of the running total, which is the largest scale of any value added so
+    /// far.
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Adds `value` to the running total without any loss of precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the running total exceeds 256 bits, which requires more than 2<sup>64</sup>
+    /// additions.
+    pub fn add(&mut self, value: Decimal) {
+        self.accumulate(value, value.is_sign_negative());
+    }
+
+    /// Subtracts `value` from the running total without any loss of precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the running total exceeds 256 bits, which requires more than 2<sup>64</sup>
+    /// subtractions.
+    pub fn sub(&mut self, value: Decimal) {
+        self.accumulate(value, !value.is_sign_negative());
+    }
+
+    /// Rounds the running total to a `Decimal` using "Bankers Rounding", discarding as few digits
+    /// as possible. Returns the result along with whether it is exactly equal to the running
+    /// total.
+    ///
+    /// Returns an error if the integral portion of the running total is out of range.
+    pub fn finish(&self) -> Result<(Decimal, bool), Error> {
+        self.finish_with_strategy(RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Rounds the running total to a `Decimal` using the provided [`RoundingStrategy`],
+    /// discarding as few digits as possible. Returns the result along with whether it is exactly
+    /// equal to the running total.
+    ///
+    /// Returns an error if the integral portion of the running total is out of range.
+    pub fn finish_with_strategy(&self, strategy: RoundingStrategy) -> Result<(Decimal, bool), Error> {
+        let result = match wide_to_decimal_rounded(&self.mantissa, self.negative, self.scale, strategy) {
+            Some(result) => result,
+            None => {
+                return Err(overflow_error(
+                    self.negative,
+                    "Accumulated sum exceeds the range of a Decimal",
+                ))
+            }
+        };
+
+        // Rounding only ever reduces the scale, so bring the result back to the scale of the
+        // running total to check whether any digits were lost.
+        let mut rescaled = [0u32; 8];
+        rescaled[..3].copy_from_slice(&result.mantissa_array3());
+        mul_by_pow10(&mut rescaled, self.scale - result.scale());
+        let exact = cmp_slices(&rescaled, &self.mantissa) == Ordering::Equal;
+        Ok((result, exact))
+    }
+
+    /// Divides the running total by `divisor` and rounds the quotient to a `Decimal` using "Bankers
+    /// Rounding", discarding as few digits as possible. This allows a running total beyond the
+    /// range of a `Decimal` to be averaged. Used by the `stats` module.
+    ///
+    /// Returns `None` if `divisor` is zero or if the integral portion of the quotient is out of
+    /// range.
+    #[cfg(feature = "stats")]
+    pub(crate) fn finish_div(&self, divisor: u64) -> Option<Decimal> {
+        if divisor == 0 {
+            return None;
+        }
+        // Divide at one more decimal place than a Decimal can hold, and then append a non-zero
+        // digit if the division is inexact, so that the quotient is only rounded once.
+        let mut quotient = [0u32; 13];
+        quotient[..8].copy_from_slice(&self.mantissa);
+        mul_by_pow10(&mut quotient, MAX_PRECISION + 1 - self.scale);
+        let mut remainder = [0u32; 2];
+        div_rem_internal(&mut quotient, &[divisor as u32, (divisor >> 32) as u32], &mut remainder);
+        mul_by_pow10(&mut quotient, 1);
+        if !is_all_zero(&remainder) {
+            add_one_internal(&mut quotient);
+        }
+        if !is_all_zero(&quotient[10..]) {
+            return None;
+        }
+        wide_to_decimal_rounded(
+            &quotient[..10],
+            self.negative,
+            MAX_PRECISION + 2,
+            RoundingStrategy::MidpointNearestEven,
+        )
+    }
+
+    fn accumulate(&mut self, value: Decimal, negative: bool) {
+        if value.scale() > self.scale {
+            if mul_by_pow10(&mut self.mantissa, value.scale() - self.scale) > 0 {
+                panic!("Accumulator overflowed");
+            }
+            self.scale = value.scale();
+        }
+
+        let mut other = [0u32; 8];
+        other[..3].copy_from_slice(&value.mantissa_array3());
+        if mul_by_pow10(&mut other, self.scale - value.scale()) > 0 {
+            panic!("Accumulator overflowed");
+        }
+
+        if self.negative == negative {
+            if add_by_internal(&mut self.mantissa, &other) > 0 {
+                panic!("Accumulator overflowed");
+            }
+        } else if cmp_slices(&self.mantissa, &other) == Ordering::Less {
+            sub_slices(&mut other, &self.mantissa);
+            self.mantissa = other;
+            self.negative = negative;
+        } else {
+            sub_slices(&mut self.mantissa, &other);
+        }
+
+        if self.is_zero() {
+            self.negative = false;
+        }
+    }
+}
+
+impl AddAssign<Decimal> for DecimalAccumulator {
+    fn add_assign(&mut self, other: Decimal) {
+        self.add(other);
+    }
+}
+
+impl<'a> AddAssign<&'a Decimal> for DecimalAccumulator {
+    fn add_assign(&mut self, other: &'a Decimal) {
+        self.add(*other);
+    }
+}
+
+impl SubAssign<Decimal> for DecimalAccumulator {
+    fn sub_assign(&mut self, other: Decimal) {
+        self.sub(other);
+    }
+}
+
+impl<'a> SubAssign<&'a Decimal> for DecimalAccumulator {
+    fn sub_assign(&mut self, other: &'a Decimal) {
+        self.sub(*other);
+    }
+}
+
+impl Extend<Decimal> for DecimalAccumulator {
+    fn extend<I: IntoIterator<Item = Decimal>>(&mut self, iter: I) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+impl<'a> Extend<&'a Decimal> for DecimalAccumulator {
+    fn extend<I: IntoIterator<Item = &'a Decimal>>(&mut self, iter: I) {
+        for value in iter {
+            self.add(*value);
+        }
+    }
+}
+
+impl FromIterator<Decimal> for DecimalAccumulator {
+    fn from_iter<I: IntoIterator<Item = Decimal>>(iter: I) -> Self {
+        let mut accumulator = DecimalAccumulator::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
+
+impl<'a> FromIterator<&'a Decimal> for DecimalAccumulator {
+    fn from_iter<I: IntoIterator<Item = &'a Decimal>>(iter: I) -> Self {
+        let mut accumulator = DecimalAccumulator::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
@@ -0,0 +1,65 @@
+//! Mathematical constants rounded to 28 decimal places, mirroring `core::f64::consts`.
+//!
+//! ```rust
+//! use rust_decimal::consts::PI;
+//!
+//! assert_eq!("3.1415926535897932384626433833", PI.to_string());
+//! ```
+use crate::Decimal;
+
+/// Archimedes' constant (π)
+pub const PI: Decimal = Decimal::from_parts(1102470953, 185874565, 1703060790, false, 28);
+
+/// The full circle constant (τ), equal to 2π
+pub const TAU: Decimal = Decimal::from_parts(2204941906, 371749130, 3406121580, false, 28);
+
+/// π/2
+pub const FRAC_PI_2: Decimal = Decimal::from_parts(2698719124, 92937282, 851530395, false, 28);
+
+/// π/3
+pub const FRAC_PI_3: Decimal = Decimal::from_parts(1799146083, 61958188, 567686930, false, 28);
+
+/// π/4
+pub const FRAC_PI_4: Decimal = Decimal::from_parts(1349359562, 2193952289, 425765197, false, 28);
+
+/// π/6
+pub const FRAC_PI_6: Decimal = Decimal::from_parts(899573041, 30979094, 283843465, false, 28);
+
+/// π/8
+pub const FRAC_PI_8: Decimal = Decimal::from_parts(2822163429, 3244459792, 212882598, false, 28);
+
+/// 1/π
+pub const FRAC_1_PI: Decimal = Decimal::from_parts(3767806979, 266458693, 172556135, false, 28);
+
+/// 2/π
+pub const FRAC_2_PI: Decimal = Decimal::from_parts(3240646663, 532917387, 345112270, false, 28);
+
+/// 2/sqrt(π)
+pub const FRAC_2_SQRT_PI: Decimal = Decimal::from_parts(2109428519, 756641710, 611695572, false, 28);
+
+/// sqrt(2)
+pub const SQRT_2: Decimal = Decimal::from_parts(1533091274, 1467534665, 766646708, false, 28);
+
+/// 1/sqrt(2)
+pub const FRAC_1_SQRT_2: Decimal = Decimal::from_parts(2914029285, 733767332, 383323354, false, 28);
+
+/// Euler's number (e)
+pub const E: Decimal = Decimal::from_parts(2239425882, 3958169141, 1473583531, false, 28);
+
+/// log<sub>2</sub>(e)
+pub const LOG2_E: Decimal = Decimal::from_parts(2149024218, 3362696919, 782086548, false, 28);
+
+/// log<sub>2</sub>(10)
+pub const LOG2_10: Decimal = Decimal::from_parts(3688595639, 2829333698, 1800820828, false, 28);
+
+/// log<sub>10</sub>(e)
+pub const LOG10_E: Decimal = Decimal::from_parts(1763037029, 1670682625, 235431510, false, 28);
+
+/// log<sub>10</sub>(2)
+pub const LOG10_2: Decimal = Decimal::from_parts(3263349651, 2753482899, 163188687, false, 28);
+
+/// ln(2)
+pub const LN_2: Decimal = Decimal::from_parts(2860148159, 2180329217, 375755839, false, 28);
+
+/// ln(10)
+pub const LN_10: Decimal = Decimal::from_parts(2678495027, 336900640, 1248233880, false, 28);
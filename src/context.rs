@@ -0,0 +1,325 @@
+use crate::decimal::{add_one_internal, div_by_u32, is_all_zero, mul_by_u32, overflow_error, MAX_PRECISION};
+use crate::error::{Error, ErrorKind};
+use crate::ops::wide::{
+    count_digits, div_by_pow10_with_strategy, div_rem_internal, exact_sum, mul_by_pow10, mul_internal,
+    quantize_internal, wide_to_decimal,
+};
+use crate::{Decimal, RoundingStrategy};
+
+/// The exceptional conditions that can be signaled by the operations of a [`Context`].
+///
+/// Each signal has a corresponding flag that is set whenever the condition occurs, as well as a
+/// trap which, when enabled, causes the operation to return an error instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Signal {
+    /// The result was rounded and the discarded digits were not all zero.
+    Inexact,
+    /// The result was rounded, even if the discarded digits were all zero.
+    Rounded,
+    /// The result exceeded the range of a `Decimal`. If not trapped, the result saturates at
+    /// `Decimal::MAX` or `Decimal::MIN`.
+    Overflow,
+    /// A non-zero value was divided by zero. If not trapped, the result saturates at
+    /// `Decimal::MAX` or `Decimal::MIN`.
+    DivisionByZero,
+    /// The scale of the result exceeded the maximum of 28 and was reduced to fit, discarding more
+    /// digits than the precision of the context alone would require.
+    Clamped,
+    /// The operation has no meaningful result, e.g. quantizing a value to a scale that requires
+    /// more significant digits than the precision. Since there is no value to saturate at, an
+    /// error is returned regardless of whether this is trapped.
+    InvalidOperation,
+}
+
+impl Signal {
+    // The order in which signals are reported when more than one of them is trapped
+    const PRIORITY: [Signal; 6] = [
+        Signal::InvalidOperation,
+        Signal::DivisionByZero,
+        Signal::Overflow,
+        Signal::Clamped,
+        Signal::Inexact,
+        Signal::Rounded,
+    ];
+
+    const fn mask(self) -> u8 {
+        1 << self as u8
+    }
+
+    fn error(self, negative: bool) -> Error {
+        match self {
+            Signal::Inexact => Error::new(ErrorKind::Inexact, "Result is inexact"),
+            Signal::Rounded => Error::new(ErrorKind::Rounded, "Result was rounded"),
+            Signal::Overflow => overflow_error(negative, "Result overflowed"),
+            Signal::DivisionByZero => Error::new(ErrorKind::DivisionByZero, "Division by zero"),
+            Signal::Clamped => Error::new(ErrorKind::Clamped, "Result scale was clamped"),
+            Signal::InvalidOperation => Error::new(ErrorKind::InvalidOperation, "Invalid operation"),
+        }
+    }
+}
+
+/// A context for arithmetic operations, modeled after the `Context` of Python's `decimal` module.
+///
+/// A context rounds the result of each operation to a maximum number of significant digits using
+/// its [`RoundingStrategy`]. The exact result is calculated before rounding, so an operation only
+/// ever rounds once. Any exceptional conditions encountered are recorded as flags on the context
+/// and, if the corresponding trap is enabled, cause the operation to return an error.
+///
+/// The default context has a precision of 28 significant digits, rounds using
+/// [`RoundingStrategy::MidpointNearestEven`] and traps [`Signal::Overflow`] and
+/// [`Signal::DivisionByZero`].
+///
+/// # Example
+///
+/// ```
+/// use rust_decimal::{Context, Decimal, RoundingStrategy, Signal};
+/// use core::str::FromStr;
+///
+/// let mut ctx = Context::new(5, RoundingStrategy::MidpointAwayFromZero).unwrap();
+/// let result = ctx.div(Decimal::from(2), Decimal::from(3)).unwrap();
+/// assert_eq!(result.to_string(), "0.66667");
+/// assert!(ctx.is_flagged(Signal::Inexact));
+///
+/// ctx.set_trap(Signal::Inexact, true);
+/// assert!(ctx.div(Decimal::from(1), Decimal::from(3)).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Context {
+    precision: u32,
+    rounding: RoundingStrategy,
+    traps: u8,
+    flags: u8,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context {
+            precision: MAX_PRECISION,
+            rounding: RoundingStrategy::MidpointNearestEven,
+            traps: Signal::Overflow.mask() | Signal::DivisionByZero.mask(),
+            flags: 0,
+        }
+    }
+}
+
+impl Context {
+    /// Creates a new context with the given precision in significant digits and rounding strategy,
+    /// using the default traps.
+    ///
+    /// Returns an error if the precision is not between 1 and 28 inclusive.
+    pub fn new(precision: u32, rounding: RoundingStrategy) -> Result<Context, Error> {
+        let mut ctx = Context::default();
+        ctx.set_precision(precision)?;
+        ctx.rounding = rounding;
+        Ok(ctx)
+    }
+
+    /// Returns the maximum number of significant digits of a result.
+    pub const fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Sets the maximum number of significant digits of a result.
+    ///
+    /// Returns an error if the precision is not between 1 and 28 inclusive.
+    pub fn set_precision(&mut self, precision: u32) -> Result<(), Error> {
+        if precision == 0 || precision > MAX_PRECISION {
+            return Err(Error::new(
+                ErrorKind::PrecisionOutOfRange(precision),
+                "Precision must be between 1 and 28",
+            ));
+        }
+        self.precision = precision;
+        Ok(())
+    }
+
+    /// Returns the rounding strategy used to round results.
+    pub const fn rounding(&self) -> RoundingStrategy {
+        self.rounding
+    }
+
+    /// Sets the rounding strategy used to round results.
+    pub fn set_rounding(&mut self, rounding: RoundingStrategy) {
+        self.rounding = rounding;
+    }
+
+    /// Returns true if the given signal causes operations to return an error.
+    pub const fn is_trapped(&self, signal: Signal) -> bool {
+        self.traps & signal.mask() != 0
+    }
+
+    /// Enables or disables the trap for the given signal.
+    pub fn set_trap(&mut self, signal: Signal, enabled: bool) {
+        if enabled {
+            self.traps |= signal.mask();
+        } else {
+            self.traps &= !signal.mask();
+        }
+    }
+
+    /// Returns true if the given signal has been raised since the flags were last cleared.
+    pub const fn is_flagged(&self, signal: Signal) -> bool {
+        self.flags & signal.mask() != 0
+    }
+
+    /// Clears all of the flags that have been raised.
+    pub fn clear_flags(&mut self) {
+        self.flags = 0;
+    }
+
+    /// Adds two decimals, rounding the result to the precision of the context.
+    pub fn add(&mut self, a: Decimal, b: Decimal) -> Result<Decimal, Error> {
+        let (sum, negative, scale) = exact_sum(&a, &b);
+        let mut bits = [0u32; 10];
+        bits[..sum.len()].copy_from_slice(&sum);
+        self.round(bits, negative, scale)
+    }
+
+    /// Subtracts `b` from `a`, rounding the result to the precision of the context.
+    pub fn sub(&mut self, a: Decimal, b: Decimal) -> Result<Decimal, Error> {
+        self.add(a, -b)
+    }
+
+    /// Multiplies two decimals, rounding the result to the precision of the context.
+    pub fn mul(&mut self, a: Decimal, b: Decimal) -> Result<Decimal, Error> {
+        let mut bits = [0u32; 10];
+        mul_internal(&a.mantissa_array3(), &b.mantissa_array3(), &mut bits);
+        let negative = a.is_sign_negative() ^ b.is_sign_negative();
+        self.round(bits, negative, a.scale() + b.scale())
+    }
+
+    /// Divides `a` by `b`, rounding the result to the precision of the context.
+    ///
+    /// If the quotient is exact, trailing zeros are removed down to the difference between the
+    /// scales of `a` and `b`. Since the quotient is undefined, dividing zero by zero always
+    /// returns an error regardless of whether [`Signal::DivisionByZero`] is trapped.
+    pub fn div(&mut self, a: Decimal, b: Decimal) -> Result<Decimal, Error> {
+        let negative = a.is_sign_negative() ^ b.is_sign_negative();
+        if b.is_zero() {
+            if a.is_zero() {
+                self.flags |= Signal::DivisionByZero.mask();
+                return Err(Signal::DivisionByZero.error(negative));
+            }
+            let result = if negative { Decimal::MIN } else { Decimal::MAX };
+            return self.raise(Signal::DivisionByZero.mask(), result, negative);
+        }
+
+        // Calculate the quotient with one more decimal place than can be represented, so that
+        // rounding always has at least one digit to discard.
+        let mut scale = MAX_PRECISION + 1;
+        let mut bits = [0u32; 10];
+        bits[..3].copy_from_slice(&a.mantissa_array3());
+        mul_by_pow10(&mut bits, scale + b.scale() - a.scale());
+        let mut remainder = [0u32; 4];
+        div_rem_internal(&mut bits, &b.mantissa_array3(), &mut remainder);
+
+        if is_all_zero(&remainder) {
+            let ideal = a.scale().saturating_sub(b.scale());
+            while scale > ideal {
+                let mut tmp = bits;
+                if div_by_u32(&mut tmp, 10) != 0 {
+                    break;
+                }
+                bits = tmp;
+                scale -= 1;
+            }
+        } else {
+            // Append a non-zero digit to represent the remainder. This makes sure that the result
+            // is flagged as inexact and that it is never mistaken for a midpoint.
+            mul_by_u32(&mut bits, 10);
+            add_one_internal(&mut bits);
+            scale += 1;
+        }
+        self.round(bits, negative, scale)
+    }
+
+    /// Rounds `value` to the scale of `exp` using the rounding strategy of the context.
+    ///
+    /// If the scale of `exp` is larger than the scale of `value` then trailing zeros are added.
+    /// [`Signal::InvalidOperation`] is raised and an error returned if the result requires more
+    /// significant digits than the precision of the context, or can't be represented at all.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Context, Decimal};
+    /// use core::str::FromStr;
+    ///
+    /// let mut ctx = Context::default();
+    /// let value = Decimal::from_str("1.255").unwrap();
+    /// let exp = Decimal::from_str("0.01").unwrap();
+    /// assert_eq!(ctx.quantize(value, exp).unwrap().to_string(), "1.26");
+    /// assert_eq!(ctx.quantize(Decimal::from(7), exp).unwrap().to_string(), "7.00");
+    /// ```
+    pub fn quantize(&mut self, value: Decimal, exp: Decimal) -> Result<Decimal, Error> {
+        let negative = value.is_sign_negative();
+        let (bits, inexact) = quantize_internal(&value, exp.scale(), self.rounding);
+        let mut raised = 0;
+        if exp.scale() < value.scale() {
+            raised |= Signal::Rounded.mask();
+        }
+        if inexact {
+            raised |= Signal::Inexact.mask();
+        }
+        match wide_to_decimal(&bits, negative, exp.scale()) {
+            Some(result) if count_digits(&bits) <= self.precision => self.raise(raised, result, negative),
+            _ => {
+                self.flags |= Signal::InvalidOperation.mask();
+                Err(Signal::InvalidOperation.error(negative))
+            }
+        }
+    }
+
+    // Rounds an exact intermediate result to the precision of the context, raising any signals.
+    fn round(&mut self, mut bits: [u32; 10], negative: bool, mut scale: u32) -> Result<Decimal, Error> {
+        let mut raised = 0;
+        let mut discard = count_digits(&bits).saturating_sub(self.precision);
+        if scale > MAX_PRECISION + discard {
+            // The scale can't be represented, so we need to discard more digits than required by
+            // the precision alone.
+            raised |= Signal::Clamped.mask();
+            discard = scale - MAX_PRECISION;
+        }
+        if discard > 0 {
+            raised |= Signal::Rounded.mask();
+            if div_by_pow10_with_strategy(&mut bits, discard, negative, self.rounding) {
+                raised |= Signal::Inexact.mask();
+            }
+            // Rounding up may have carried into an additional digit, in which case the digit
+            // being discarded is always zero.
+            if count_digits(&bits) > self.precision {
+                div_by_u32(&mut bits, 10);
+                discard += 1;
+            }
+            // If we discarded integral digits then they need to be replaced with zeros
+            if discard > scale {
+                if mul_by_pow10(&mut bits, discard - scale) != 0 {
+                    return self.overflow(raised, negative);
+                }
+                scale = 0;
+            } else {
+                scale -= discard;
+            }
+        }
+        match wide_to_decimal(&bits, negative, scale) {
+            Some(result) => self.raise(raised, result, negative),
+            None => self.overflow(raised, negative),
+        }
+    }
+
+    fn overflow(&mut self, raised: u8, negative: bool) -> Result<Decimal, Error> {
+        let raised = raised | Signal::Overflow.mask() | Signal::Inexact.mask() | Signal::Rounded.mask();
+        let result = if negative { Decimal::MIN } else { Decimal::MAX };
+        self.raise(raised, result, negative)
+    }
+
+    // Records the raised signals as flags, returning an error for the first one that is trapped.
+    fn raise(&mut self, raised: u8, result: Decimal, negative: bool) -> Result<Decimal, Error> {
+        self.flags |= raised;
+        let trapped = raised & self.traps;
+        match Signal::PRIORITY.iter().find(|signal| trapped & signal.mask() != 0) {
+            Some(signal) => Err(signal.error(negative)),
+            None => Ok(result),
+        }
+    }
+}
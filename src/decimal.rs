@@ -1,13 +1,18 @@
+use crate::error::{Error, ErrorKind};
 use crate::ops;
-use crate::Error;
+use crate::ops::wide::{
+    cmp_slices, count_digits, div_by_pow10_with_strategy, div_rem_internal, div_rounded, eq_scaled, exact_sum,
+    mul_by_pow10, mul_internal, quantize_internal, sub_slices, wide_to_decimal, wide_to_decimal_rounded,
+    wide_to_decimal_rounded_dp,
+};
 
-use alloc::{string::String, vec::Vec};
 use arrayvec::{ArrayString, ArrayVec};
 use core::{
+    borrow::Borrow,
     cmp::{Ordering::Equal, *},
     fmt,
     hash::{Hash, Hasher},
-    iter::Sum,
+    iter::{Product, Sum},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
     str::FromStr,
 };
@@ -17,7 +22,9 @@ use diesel::sql_types::Numeric;
 #[cfg(not(feature = "std"))]
 use num_traits::float::FloatCore;
 use num_traits::{
-    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive, Num, One, Signed, ToPrimitive, Zero,
+    Bounded, CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Euclid,
+    FromPrimitive, Inv, MulAdd, MulAddAssign, Num, One, Saturating, SaturatingAdd, SaturatingMul, SaturatingSub,
+    Signed, ToPrimitive, Zero,
 };
 
 // Sign mask for the flags field. A value of zero in this bit indicates a
@@ -41,6 +48,9 @@ const SIGN_SHIFT: u32 = 31;
 // The maximum string buffer size used for serialization purposes. 31 is optimal, however we align
 // to the byte boundary for simplicity.
 const MAX_STR_BUFFER_SIZE: usize = 32;
+// The maximum string buffer size used for scientific notation. This allows for the significand, a
+// decimal point and an exponent of up to 28, once again aligned to the byte boundary.
+const MAX_SCIENTIFIC_STR_BUFFER_SIZE: usize = 40;
 
 // The maximum supported precision
 pub(crate) const MAX_PRECISION: u32 = 28;
@@ -118,7 +128,11 @@ pub struct Decimal {
 
 /// `RoundingStrategy` represents the different rounding strategies that can be used by
 /// `round_dp_with_strategy`.
+///
+/// New strategies may be added in the future, so it is not recommended to exhaustively match
+/// against it.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum RoundingStrategy {
     /// When a number is halfway between two others, it is rounded toward the nearest even number.
     /// Also known as "Bankers Rounding".
@@ -139,6 +153,15 @@ pub enum RoundingStrategy {
     ToNegativeInfinity,
     /// The number is always rounded towards positive infinity. e.g. 6.8 -> 7, -6.8 -> -6
     ToPositiveInfinity,
+    /// When a number is halfway between two others, it is rounded toward the nearest odd number.
+    /// Rounding to odd is useful for intermediate results as it avoids double rounding errors.
+    /// e.g.
+    /// 6.5 -> 7, 7.5 -> 7
+    MidpointNearestOdd,
+    /// The number is rounded toward zero, unless that would leave a 0 or 5 as the last digit,
+    /// in which case it is rounded away from zero. Also known as "ROUND_05UP".
+    /// e.g. 6.8 -> 6, 5.1 -> 6, -0.2 -> -1, 5.0 -> 5
+    ZeroFiveUp,
 
     /// When a number is halfway between two others, it is rounded toward the nearest even number.
     /// e.g.
@@ -329,25 +352,34 @@ impl Decimal {
     /// assert_eq!(value.to_string(), "0.00000097");
     /// ```
     pub fn from_scientific(value: &str) -> Result<Decimal, Error> {
-        let err = Error::new("Failed to parse");
+        const ERROR_INVALID_FORMAT: Error = Error::new(ErrorKind::InvalidFormat, "Failed to parse");
         let mut split = value.splitn(2, |c| c == 'e' || c == 'E');
 
-        let base = split.next().ok_or_else(|| err.clone())?;
-        let exp = split.next().ok_or_else(|| err.clone())?;
+        let base = split.next().ok_or(ERROR_INVALID_FORMAT)?;
+        let exp = split.next().ok_or(ERROR_INVALID_FORMAT)?;
 
         let mut ret = Decimal::from_str(base)?;
         let current_scale = ret.scale();
 
         if let Some(stripped) = exp.strip_prefix('-') {
-            let exp: u32 = stripped.parse().map_err(move |_| err)?;
-            ret.set_scale(current_scale + exp)?;
+            let exp: u32 = stripped.parse().map_err(|_| ERROR_INVALID_FORMAT)?;
+            // Too many decimal places to represent means that the number is too small
+            ret.set_scale(current_scale.saturating_add(exp))
+                .map_err(|_| Error::new(ErrorKind::Underflow, "Scale exceeds maximum precision"))?;
         } else {
-            let exp: u32 = exp.parse().map_err(move |_| err)?;
+            let exp: u32 = exp.parse().map_err(|_| ERROR_INVALID_FORMAT)?;
             if exp <= current_scale {
                 ret.set_scale(current_scale - exp)?;
             } else {
-                ret *= Decimal::from_i64(10_i64.pow(exp)).unwrap();
-                ret = ret.normalize();
+                let negative = ret.is_sign_negative();
+                ret = match 10_i128
+                    .checked_pow(exp)
+                    .and_then(Decimal::from_i128)
+                    .and_then(|pow| ret.checked_mul(pow))
+                {
+                    Some(product) => product.normalize(),
+                    None => return Err(overflow_error(negative, "Invalid decimal: overflow from exponent")),
+                };
             }
         }
         Ok(ret)
@@ -483,7 +515,10 @@ impl Decimal {
     /// ```
     pub fn set_scale(&mut self, scale: u32) -> Result<(), Error> {
         if scale > MAX_PRECISION {
-            return Err(Error::new("Scale exceeds maximum precision"));
+            return Err(Error::new(
+                ErrorKind::ScaleExceedsMaximumPrecision(scale),
+                "Scale exceeds maximum precision",
+            ));
         }
         self.flags = (scale << SCALE_SHIFT) | (self.flags & SIGN_MASK);
         Ok(())
@@ -497,6 +532,8 @@ impl Decimal {
     /// Scales greater than the maximum precision supported by `Decimal` will be automatically
     /// rounded to `Decimal::MAX_PRECISION`.
     /// Rounding leverages the half up strategy.
+    /// If the number cannot be represented with the given scale then the scale is reduced
+    /// until it can be; use [`quantize`](Decimal::quantize) to have this reported as an error.
     ///
     /// # Arguments
     /// * `scale`: The scale to use for the new `Decimal` number.
@@ -674,6 +711,108 @@ impl Decimal {
         me
     }
 
+    /// Checked negation. Computes `-self`, returning `None` if the result cannot be represented.
+    ///
+    /// As the range of `Decimal` is symmetric this always succeeds, however it is provided for
+    /// parity with the primitive numeric types and for use in generic code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::MAX.checked_neg(), Some(Decimal::MIN));
+    /// assert_eq!(Decimal::new(-15, 1).checked_neg(), Some(Decimal::new(15, 1)));
+    /// ```
+    pub fn checked_neg(self) -> Option<Decimal> {
+        Some(-self)
+    }
+
+    /// Checked absolute value. Computes `self.abs()`, returning `None` if the result cannot be
+    /// represented.
+    ///
+    /// As the range of `Decimal` is symmetric this always succeeds, however it is provided for
+    /// parity with the primitive numeric types and for use in generic code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::MIN.checked_abs(), Some(Decimal::MAX));
+    /// ```
+    pub fn checked_abs(self) -> Option<Decimal> {
+        Some(self.abs())
+    }
+
+    /// Returns a number representing the sign of `self`:
+    ///
+    /// * `0` if the number is zero
+    /// * `1` if the number is positive
+    /// * `-1` if the number is negative
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::new(-314, 2).signum(), Decimal::new(-1, 0));
+    /// assert_eq!(Decimal::new(0, 2).signum(), Decimal::ZERO);
+    /// ```
+    pub fn signum(&self) -> Decimal {
+        if self.is_zero() {
+            ZERO
+        } else {
+            let mut value = ONE;
+            if self.is_sign_negative() {
+                value.set_sign_negative(true);
+            }
+            value
+        }
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of `sign`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// let num = Decimal::new(3141, 3);
+    /// assert_eq!(num.copysign(Decimal::new(-1, 0)).to_string(), "-3.141");
+    /// assert_eq!((-num).copysign(Decimal::ONE).to_string(), "3.141");
+    /// ```
+    pub fn copysign(&self, sign: Decimal) -> Decimal {
+        let mut me = *self;
+        me.set_sign_negative(sign.is_sign_negative());
+        me
+    }
+
+    /// Returns the positive difference of `self` and `other`, which is `Decimal::ZERO` if `self` is
+    /// less than or equal to `other`, otherwise `self - other`. This is the behavior of the
+    /// `num_traits::Signed` implementation.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the difference overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// let num = Decimal::new(123, 0);
+    /// assert_eq!(num.abs_sub(&Decimal::new(124, 0)), Decimal::ZERO);
+    /// assert_eq!(num.abs_sub(&Decimal::new(122, 0)), Decimal::ONE);
+    /// ```
+    pub fn abs_sub(&self, other: &Decimal) -> Decimal {
+        if self <= other {
+            ZERO
+        } else {
+            self - other
+        }
+    }
+
     /// Returns the largest integer less than or equal to a number.
     ///
     /// # Example
@@ -917,57 +1056,8 @@ impl Decimal {
         }
         let order = cmp_internal(&decimal_portion, &cap);
 
-        #[allow(deprecated)]
-        match strategy {
-            RoundingStrategy::BankersRounding | RoundingStrategy::MidpointNearestEven => {
-                match order {
-                    Ordering::Equal => {
-                        if (value[0] & 1) == 1 {
-                            add_one_internal(&mut value);
-                        }
-                    }
-                    Ordering::Greater => {
-                        // Doesn't matter about the decimal portion
-                        add_one_internal(&mut value);
-                    }
-                    _ => {}
-                }
-            }
-            RoundingStrategy::RoundHalfDown | RoundingStrategy::MidpointTowardZero => {
-                if let Ordering::Greater = order {
-                    add_one_internal(&mut value);
-                }
-            }
-            RoundingStrategy::RoundHalfUp | RoundingStrategy::MidpointAwayFromZero => {
-                // when Ordering::Equal, decimal_portion is 0.5 exactly
-                // when Ordering::Greater, decimal_portion is > 0.5
-                match order {
-                    Ordering::Equal => {
-                        add_one_internal(&mut value);
-                    }
-                    Ordering::Greater => {
-                        // Doesn't matter about the decimal portion
-                        add_one_internal(&mut value);
-                    }
-                    _ => {}
-                }
-            }
-            RoundingStrategy::RoundUp | RoundingStrategy::AwayFromZero => {
-                if !is_all_zero(&decimal_portion) {
-                    add_one_internal(&mut value);
-                }
-            }
-            RoundingStrategy::ToPositiveInfinity => {
-                if !negative && !is_all_zero(&decimal_portion) {
-                    add_one_internal(&mut value);
-                }
-            }
-            RoundingStrategy::ToNegativeInfinity => {
-                if negative && !is_all_zero(&decimal_portion) {
-                    add_one_internal(&mut value);
-                }
-            }
-            RoundingStrategy::RoundDown | RoundingStrategy::ToZero => (),
+        if round_increment_required(strategy, negative, &value, order, !is_all_zero(&decimal_portion)) {
+            add_one_internal(&mut value);
         }
 
         Decimal {
@@ -997,6 +1087,196 @@ impl Decimal {
         self.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven)
     }
 
+    /// Returns `Some(Decimal)` rounded to the specified number of significant digits using
+    /// "Bankers Rounding" rules, e.g. 0.0012345 -> 0.00123. Returns `None` if `digits` is zero
+    /// or if the result would overflow.
+    ///
+    /// Trailing zeros of the original number are preserved if they fall within the significant
+    /// digits. Integral digits that are rounded away are replaced with zeros.
+    ///
+    /// # Arguments
+    /// * `digits`: the number of significant digits to round to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let value = Decimal::from_str("0.0012345").unwrap();
+    /// assert_eq!(value.round_sf(3).unwrap().to_string(), "0.00123");
+    ///
+    /// let value = Decimal::from_str("123456").unwrap();
+    /// assert_eq!(value.round_sf(3).unwrap().to_string(), "123000");
+    /// ```
+    pub fn round_sf(&self, digits: u32) -> Option<Decimal> {
+        self.round_sf_with_strategy(digits, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Returns `Some(Decimal)` rounded to the specified number of significant digits using the
+    /// provided [`RoundingStrategy`]. Returns `None` if `digits` is zero or if the result would
+    /// overflow.
+    ///
+    /// # Arguments
+    /// * `digits`: the number of significant digits to round to.
+    /// * `strategy`: the rounding strategy to use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, RoundingStrategy};
+    /// use core::str::FromStr;
+    ///
+    /// let value = Decimal::from_str("123456").unwrap();
+    /// let rounded = value.round_sf_with_strategy(3, RoundingStrategy::ToPositiveInfinity);
+    /// assert_eq!(rounded.unwrap().to_string(), "124000");
+    /// ```
+    pub fn round_sf_with_strategy(&self, digits: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+        if digits == 0 {
+            return None;
+        }
+        let mut bits = self.mantissa_array3();
+        let mut discard = count_digits(&bits).saturating_sub(digits);
+        if discard == 0 {
+            return Some(*self);
+        }
+
+        let negative = self.is_sign_negative();
+        div_by_pow10_with_strategy(&mut bits, discard, negative, strategy);
+        // Rounding up may have carried into an additional digit (e.g. 9.99 -> 10.0), in which
+        // case the trailing zero can be discarded too.
+        if count_digits(&bits) > digits {
+            div_by_u32(&mut bits, 10);
+            discard += 1;
+        }
+
+        let scale = self.scale();
+        if discard > scale {
+            // Replace any integral digits that were discarded with zeros
+            if mul_by_pow10(&mut bits, discard - scale) != 0 {
+                return None;
+            }
+            Some(Decimal::from_parts(bits[0], bits[1], bits[2], negative, 0))
+        } else {
+            Some(Decimal::from_parts(
+                bits[0],
+                bits[1],
+                bits[2],
+                negative,
+                scale - discard,
+            ))
+        }
+    }
+
+    /// Returns `Some(Decimal)` rounded to the nearest multiple of `increment` using the provided
+    /// [`RoundingStrategy`], e.g. rounding to the nearest 0.05 for cash transactions. The result
+    /// has the same scale as `increment`.
+    ///
+    /// Returns `None` if `increment` is not positive or if the result would overflow.
+    ///
+    /// # Arguments
+    /// * `increment`: the positive increment to round to a multiple of.
+    /// * `strategy`: the rounding strategy to use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, RoundingStrategy};
+    /// use core::str::FromStr;
+    ///
+    /// let nickel = Decimal::from_str("0.05").unwrap();
+    /// let price = Decimal::from_str("1.225").unwrap();
+    /// let rounded = price.round_to_increment(nickel, RoundingStrategy::MidpointAwayFromZero);
+    /// assert_eq!(rounded.unwrap().to_string(), "1.25");
+    /// ```
+    pub fn round_to_increment(&self, increment: Decimal, strategy: RoundingStrategy) -> Option<Decimal> {
+        if increment.is_sign_negative() || increment.is_zero() {
+            return None;
+        }
+
+        // Bring both values to the same scale so that we can divide them as integers
+        let scale = self.scale().max(increment.scale());
+        let mut quotient = [0u32; 7];
+        let mut divisor = [0u32; 7];
+        quotient[..3].copy_from_slice(&self.mantissa_array3());
+        divisor[..3].copy_from_slice(&increment.mantissa_array3());
+        mul_by_pow10(&mut quotient, scale - self.scale());
+        mul_by_pow10(&mut divisor, scale - increment.scale());
+        let negative = self.is_sign_negative();
+        div_rounded(&mut quotient, &divisor, negative, strategy);
+
+        let mut result = [0u32; 10];
+        mul_internal(&quotient, &increment.mantissa_array3(), &mut result);
+        wide_to_decimal(&result, negative, increment.scale())
+    }
+
+    /// Returns `Some(Decimal)` rounded down (towards negative infinity) to a multiple of
+    /// `increment`. Returns `None` if `increment` is not positive or if the result would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let tick = Decimal::from_str("0.25").unwrap();
+    /// let price = Decimal::from_str("-10.3").unwrap();
+    /// assert_eq!(price.floor_to_increment(tick).unwrap().to_string(), "-10.50");
+    /// ```
+    pub fn floor_to_increment(&self, increment: Decimal) -> Option<Decimal> {
+        self.round_to_increment(increment, RoundingStrategy::ToNegativeInfinity)
+    }
+
+    /// Returns `Some(Decimal)` rounded up (towards positive infinity) to a multiple of
+    /// `increment`. Returns `None` if `increment` is not positive or if the result would overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let tick = Decimal::from_str("0.25").unwrap();
+    /// let price = Decimal::from_str("10.3").unwrap();
+    /// assert_eq!(price.ceil_to_increment(tick).unwrap().to_string(), "10.50");
+    /// ```
+    pub fn ceil_to_increment(&self, increment: Decimal) -> Option<Decimal> {
+        self.round_to_increment(increment, RoundingStrategy::ToPositiveInfinity)
+    }
+
+    /// Returns a `Decimal` with exactly the same scale as `exp`, rounding using the provided
+    /// [`RoundingStrategy`] if the scale is reduced and padding with zeros if it is increased.
+    ///
+    /// Unlike [`round_dp_with_strategy`](Decimal::round_dp_with_strategy), the scale of the result
+    /// may be larger than the original scale. Unlike [`rescale`](Decimal::rescale), an error is
+    /// returned if the result cannot be represented instead of silently reducing the scale.
+    ///
+    /// # Arguments
+    /// * `exp`: the `Decimal` whose scale the result should have. Its value is ignored.
+    /// * `strategy`: the rounding strategy to use if digits need to be discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, RoundingStrategy};
+    /// use core::str::FromStr;
+    ///
+    /// let cent = Decimal::from_str("0.01").unwrap();
+    /// let strategy = RoundingStrategy::MidpointAwayFromZero;
+    /// let amount = Decimal::from_str("12.345").unwrap();
+    /// assert_eq!(amount.quantize(cent, strategy).unwrap().to_string(), "12.35");
+    /// assert_eq!(Decimal::from(7).quantize(cent, strategy).unwrap().to_string(), "7.00");
+    /// assert!(Decimal::MAX.quantize(cent, strategy).is_err());
+    /// ```
+    pub fn quantize(&self, exp: Decimal, strategy: RoundingStrategy) -> Result<Decimal, Error> {
+        let negative = self.is_sign_negative();
+        let (bits, _) = quantize_internal(self, exp.scale(), strategy);
+        match wide_to_decimal(&bits, negative, exp.scale()) {
+            Some(result) => Ok(result),
+            None => Err(overflow_error(negative, "Quantized value cannot be represented")),
+        }
+    }
+
     /// Convert `Decimal` to an internal representation of the underlying struct. This is useful
     /// for debugging the internal state of the object.
     ///
@@ -1145,102 +1425,732 @@ impl Decimal {
             } else if rem10 >= 5 {
                 add_one_internal(bits);
             }
-        }
+        }
+
+        // This step is required in order to remove excess bits of precision from the
+        // end of the bit representation, down to the precision guaranteed by the
+        // floating point number
+        if is64 {
+            // Guaranteed to about 16 dp
+            while exponent10 < 0 && (bits[2] != 0 || (bits[1] & 0xFFF0_0000) != 0) {
+                let rem10 = div_by_u32(bits, 10);
+                exponent10 += 1;
+                if rem10 >= 5 {
+                    add_one_internal(bits);
+                }
+            }
+        } else {
+            // Guaranteed to about 7 dp
+            while exponent10 < 0 && ((bits[0] & 0xFF00_0000) != 0 || bits[1] != 0 || bits[2] != 0) {
+                let rem10 = div_by_u32(bits, 10);
+                exponent10 += 1;
+                if rem10 >= 5 {
+                    add_one_internal(bits);
+                }
+            }
+        }
+
+        // Remove multiples of 10 from the representation
+        while exponent10 < 0 {
+            let mut temp = [bits[0], bits[1], bits[2]];
+            let remainder = div_by_u32(&mut temp, 10);
+            if remainder == 0 {
+                exponent10 += 1;
+                bits[0] = temp[0];
+                bits[1] = temp[1];
+                bits[2] = temp[2];
+            } else {
+                break;
+            }
+        }
+
+        Some(Decimal {
+            lo: bits[0],
+            mid: bits[1],
+            hi: bits[2],
+            flags: flags(!positive, -exponent10 as u32),
+        })
+    }
+
+    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    pub fn checked_add(self, other: Decimal) -> Option<Decimal> {
+        match ops::add_impl(&self, &other) {
+            CalculationResult::Ok(result) => Some(result),
+            CalculationResult::Overflow => None,
+            _ => None,
+        }
+    }
+
+    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    #[inline(always)]
+    pub fn checked_sub(self, other: Decimal) -> Option<Decimal> {
+        match ops::sub_impl(&self, &other) {
+            CalculationResult::Ok(result) => Some(result),
+            CalculationResult::Overflow => None,
+            _ => None,
+        }
+    }
+
+    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    #[inline]
+    pub fn checked_mul(self, other: Decimal) -> Option<Decimal> {
+        match ops::mul_impl(&self, &other) {
+            CalculationResult::Ok(result) => Some(result),
+            CalculationResult::Overflow => None,
+            _ => None,
+        }
+    }
+
+    /// Checked division. Computes `self / other`, returning `None` if `other == 0.0` or the
+    /// division results in overflow.
+    pub fn checked_div(self, other: Decimal) -> Option<Decimal> {
+        match ops::div_impl(&self, &other) {
+            CalculationResult::Ok(quot) => Some(quot),
+            CalculationResult::Overflow => None,
+            CalculationResult::DivByZero => None,
+        }
+    }
+
+    /// Checked remainder. Computes `self % other`, returning `None` if `other == 0.0`.
+    pub fn checked_rem(self, other: Decimal) -> Option<Decimal> {
+        match ops::rem_impl(&self, &other) {
+            CalculationResult::Ok(quot) => Some(quot),
+            CalculationResult::Overflow => None,
+            CalculationResult::DivByZero => None,
+        }
+    }
+
+    /// Computes `self * a + b` with only a single rounding at the end, which is more accurate than
+    /// performing the multiplication and addition separately.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the result cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let balance = Decimal::from_str("1000.00").unwrap();
+    /// let rate = Decimal::from_str("1.015").unwrap();
+    /// let fee = Decimal::from_str("-2.50").unwrap();
+    /// assert_eq!(balance.mul_add(rate, fee).to_string(), "1012.50000");
+    /// ```
+    pub fn mul_add(self, a: Decimal, b: Decimal) -> Decimal {
+        match self.checked_mul_add(a, b) {
+            Some(result) => result,
+            None => panic!("Multiplication overflowed"),
+        }
+    }
+
+    /// Checked fused multiply-add. Computes `self * a + b` with only a single rounding at the end,
+    /// returning `None` if the result cannot be represented.
+    pub fn checked_mul_add(self, a: Decimal, b: Decimal) -> Option<Decimal> {
+        // The product requires at most 192 bits at a scale of up to 56. Bringing both sides to the
+        // same scale requires at most a further 28 * log2(10) bits.
+        let product_scale = self.scale() + a.scale();
+        let scale = product_scale.max(b.scale());
+        let mut product = [0u32; 10];
+        let mut addend = [0u32; 10];
+        mul_internal(&self.mantissa_array3(), &a.mantissa_array3(), &mut product);
+        addend[..3].copy_from_slice(&b.mantissa_array3());
+        mul_by_pow10(&mut product, scale - product_scale);
+        mul_by_pow10(&mut addend, scale - b.scale());
+
+        let product_negative = self.is_sign_negative() ^ a.is_sign_negative();
+        let negative = if product_negative == b.is_sign_negative() {
+            add_by_internal(&mut product, &addend);
+            product_negative
+        } else if cmp_slices(&product, &addend) == Ordering::Less {
+            sub_slices(&mut addend, &product);
+            product = addend;
+            b.is_sign_negative()
+        } else {
+            sub_slices(&mut product, &addend);
+            product_negative
+        };
+        wide_to_decimal_rounded(&product, negative, scale, RoundingStrategy::MidpointNearestEven)
+    }
+
+    /// Computes the integer quotient of `self / other` rounded toward zero, along with the
+    /// remainder `self - quotient * other`, in a single pass. The remainder has the same sign as
+    /// `self`, matching the `%` operator.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let total = Decimal::from_str("-10.5").unwrap();
+    /// let lot = Decimal::from_str("4").unwrap();
+    /// let (lots, left_over) = total.div_rem(lot);
+    /// assert_eq!(lots.to_string(), "-2");
+    /// assert_eq!(left_over.to_string(), "-2.5");
+    /// ```
+    pub fn div_rem(self, other: Decimal) -> (Decimal, Decimal) {
+        match self.checked_div_rem(other) {
+            Some(result) => result,
+            None => integer_division_panic(&other),
+        }
+    }
+
+    /// Checked integer division with remainder. Computes the integer quotient of `self / other`
+    /// rounded toward zero along with the remainder, returning `None` if `other == 0.0` or the
+    /// quotient cannot be represented.
+    pub fn checked_div_rem(self, other: Decimal) -> Option<(Decimal, Decimal)> {
+        let (quotient, remainder) = self.div_rem_trunc_internal(&other)?;
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+        Some((wide_to_decimal(&quotient, negative, 0)?, remainder))
+    }
+
+    /// Computes the integer quotient of `self / other`, rounded toward zero.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-7.5").unwrap();
+    /// assert_eq!(a.div_trunc(Decimal::from(2)).to_string(), "-3");
+    /// ```
+    pub fn div_trunc(self, other: Decimal) -> Decimal {
+        match self.checked_div_trunc(other) {
+            Some(quotient) => quotient,
+            None => integer_division_panic(&other),
+        }
+    }
+
+    /// Checked integer division rounded toward zero. Computes the integer quotient of
+    /// `self / other`, returning `None` if `other == 0.0` or the quotient cannot be represented.
+    pub fn checked_div_trunc(self, other: Decimal) -> Option<Decimal> {
+        self.checked_div_rem(other).map(|(quotient, _)| quotient)
+    }
+
+    /// Computes the integer quotient of `self / other`, rounded toward negative infinity.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-7.5").unwrap();
+    /// assert_eq!(a.div_floor(Decimal::from(2)).to_string(), "-4");
+    /// ```
+    pub fn div_floor(self, other: Decimal) -> Decimal {
+        match self.checked_div_floor(other) {
+            Some(quotient) => quotient,
+            None => integer_division_panic(&other),
+        }
+    }
+
+    /// Checked integer division rounded toward negative infinity. Computes the integer quotient
+    /// of `self / other`, returning `None` if `other == 0.0` or the quotient cannot be represented.
+    pub fn checked_div_floor(self, other: Decimal) -> Option<Decimal> {
+        let (mut quotient, remainder) = self.div_rem_trunc_internal(&other)?;
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+        if negative && !remainder.is_zero() {
+            add_one_internal(&mut quotient);
+        }
+        wide_to_decimal(&quotient, negative, 0)
+    }
+
+    /// Computes the Euclidean quotient of `self / other`. This is the integer `n` such that
+    /// `self = n * other + self.rem_euclid(other)`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the quotient cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-7.5").unwrap();
+    /// assert_eq!(a.div_euclid(Decimal::from(2)).to_string(), "-4");
+    /// assert_eq!(a.div_euclid(Decimal::from(-2)).to_string(), "4");
+    /// ```
+    pub fn div_euclid(self, other: Decimal) -> Decimal {
+        match self.checked_div_euclid(other) {
+            Some(quotient) => quotient,
+            None => integer_division_panic(&other),
+        }
+    }
+
+    /// Checked Euclidean division. Computes `self.div_euclid(other)`, returning `None` if
+    /// `other == 0.0` or the quotient cannot be represented.
+    pub fn checked_div_euclid(self, other: Decimal) -> Option<Decimal> {
+        let (mut quotient, remainder) = self.div_rem_trunc_internal(&other)?;
+        // A negative remainder means that the quotient needs to move away from zero, whatever its sign
+        if remainder.is_sign_negative() {
+            add_one_internal(&mut quotient);
+        }
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+        wide_to_decimal(&quotient, negative, 0)
+    }
+
+    /// Computes the least non-negative remainder of `self / other`. The result is always in
+    /// the range `0 <= r < other.abs()`. If the exact remainder is so close to `other.abs()` that
+    /// it rounds to it, zero is returned instead.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-7.5").unwrap();
+    /// assert_eq!(a.rem_euclid(Decimal::from(2)).to_string(), "0.5");
+    /// assert_eq!(a.rem_euclid(Decimal::from(-2)).to_string(), "0.5");
+    /// ```
+    pub fn rem_euclid(self, other: Decimal) -> Decimal {
+        match self.checked_rem_euclid(other) {
+            Some(remainder) => remainder,
+            None => integer_division_panic(&other),
+        }
+    }
+
+    /// Checked Euclidean remainder. Computes `self.rem_euclid(other)`, returning `None` if
+    /// `other == 0.0`.
+    pub fn checked_rem_euclid(self, other: Decimal) -> Option<Decimal> {
+        let (_, remainder) = self.div_rem_trunc_internal(&other)?;
+        if remainder.is_sign_negative() {
+            // The magnitude of the remainder is less than that of `other`, so this can't overflow.
+            // It may however need to be rounded if `other` has a large number of integral digits,
+            // which can round it up to `other` itself when the remainder is tiny.
+            let modulus = other.abs();
+            let remainder = remainder.checked_add(modulus)?;
+            if remainder == modulus {
+                Some(ZERO)
+            } else {
+                Some(remainder)
+            }
+        } else {
+            Some(remainder)
+        }
+    }
+
+    // Performs integer division rounded toward zero, returning the magnitude of the quotient, which
+    // may require more than 96 bits, along with the remainder. Returns `None` if `other` is zero.
+    fn div_rem_trunc_internal(&self, other: &Decimal) -> Option<([u32; 7], Decimal)> {
+        if other.is_zero() {
+            return None;
+        }
+
+        // Bring both values to the same scale so that we can divide them as integers. Since the
+        // remainder is smaller than both values it always fits within 96 bits at this scale.
+        let scale = self.scale().max(other.scale());
+        let mut quotient = [0u32; 7];
+        let mut divisor = [0u32; 7];
+        quotient[..3].copy_from_slice(&self.mantissa_array3());
+        divisor[..3].copy_from_slice(&other.mantissa_array3());
+        mul_by_pow10(&mut quotient, scale - self.scale());
+        mul_by_pow10(&mut divisor, scale - other.scale());
+        let mut remainder = [0u32; 8];
+        div_rem_internal(&mut quotient, &divisor, &mut remainder);
+        let remainder = Decimal::from_parts(remainder[0], remainder[1], remainder[2], self.is_sign_negative(), scale);
+        Some((quotient, remainder))
+    }
+
+    /// Multiplies `self` by `other`, rounding the exact product once to `dp` decimal places using
+    /// the provided [`RoundingStrategy`].
+    ///
+    /// Unlike `(a * b).round_dp_with_strategy(dp, strategy)`, the product is not first rounded to
+    /// fit within 28 decimal places, so no double rounding can occur. As with
+    /// [`round_dp_with_strategy`](Decimal::round_dp_with_strategy), the scale of the result will
+    /// not be increased if the exact product has fewer than `dp` decimal places.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the rounded product cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, RoundingStrategy};
+    /// use core::str::FromStr;
+    ///
+    /// let price = Decimal::from_str("1.2345").unwrap();
+    /// let quantity = Decimal::from_str("0.5").unwrap();
+    /// let total = price.mul_with_strategy(quantity, 3, RoundingStrategy::MidpointAwayFromZero);
+    /// assert_eq!(total.to_string(), "0.617");
+    /// ```
+    pub fn mul_with_strategy(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Decimal {
+        match self.checked_mul_with_strategy(other, dp, strategy) {
+            Some(prod) => prod,
+            None => panic!("Multiplication overflowed"),
+        }
+    }
+
+    /// Checked multiplication with an explicit rounding strategy. Computes `self * other` rounded
+    /// once to `dp` decimal places, returning `None` if the result cannot be represented.
+    pub fn checked_mul_with_strategy(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+        let dp = dp.min(MAX_PRECISION);
+        let scale = self.scale() + other.scale();
+        // The regular multiplication only rounds the product when it doesn't fit, which always
+        // reduces its scale. Otherwise the product is exact and can be rounded once from there.
+        if let CalculationResult::Ok(product) = ops::mul_impl(&self, &other) {
+            if product.scale() == scale {
+                return Some(product.round_dp_with_strategy(dp, strategy));
+            }
+        }
+
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+        let mut product = [0u32; 6];
+        mul_internal(&self.mantissa_array3(), &other.mantissa_array3(), &mut product);
+        wide_to_decimal_rounded_dp(&product, negative, scale, dp, strategy)
+    }
+
+    /// Divides `self` by `other`, rounding the exact quotient once to at most `dp` decimal places
+    /// using the provided [`RoundingStrategy`].
+    ///
+    /// Unlike `(a / b).round_dp_with_strategy(dp, strategy)`, the quotient is not first rounded
+    /// to 28 decimal places, so no double rounding can occur. As with regular division, an exact
+    /// quotient doesn't keep more decimal places than it needs, and fewer than `dp` decimal places
+    /// are kept if the integral portion doesn't leave room for them.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero or if the rounded quotient cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, RoundingStrategy};
+    /// use core::str::FromStr;
+    ///
+    /// let amount = Decimal::from_str("100").unwrap();
+    /// let parts = Decimal::from_str("3").unwrap();
+    /// let share = amount.div_with_strategy(parts, 2, RoundingStrategy::ToPositiveInfinity);
+    /// assert_eq!(share.to_string(), "33.34");
+    /// ```
+    pub fn div_with_strategy(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Decimal {
+        if other.is_zero() {
+            panic!("Division by zero");
+        }
+        match self.checked_div_with_strategy(other, dp, strategy) {
+            Some(quot) => quot,
+            None => panic!("Division overflowed"),
+        }
+    }
+
+    /// Checked division with an explicit rounding strategy. Computes `self / other` rounded once
+    /// to at most `dp` decimal places, returning `None` if `other == 0.0` or the integral portion
+    /// of the result cannot be represented.
+    pub fn checked_div_with_strategy(self, other: Decimal, dp: u32, strategy: RoundingStrategy) -> Option<Decimal> {
+        if other.is_zero() {
+            return None;
+        }
+        let dp = dp.min(MAX_PRECISION);
+        let negative = self.is_sign_negative() ^ other.is_sign_negative();
+
+        // a / b = (a.m / 10^a.e) / (b.m / 10^b.e) = (a.m * 10^(s + b.e - a.e) / b.m) / 10^s
+        // We truncate the quotient at s = dp + 1 decimal places, scaling up whichever side is
+        // needed to make the power positive. The dividend can require up to 96 + 57 * log2(10)
+        // bits, so we use a suitably large buffer.
+        let mut quotient = [0u32; 10];
+        let mut divisor = [0u32; 7];
+        let mut remainder = [0u32; 7];
+        quotient[..3].copy_from_slice(&self.mantissa_array3());
+        divisor[..3].copy_from_slice(&other.mantissa_array3());
+        let power = dp as i32 + 1 + other.scale() as i32 - self.scale() as i32;
+        if power >= 0 {
+            mul_by_pow10(&mut quotient, power as u32);
+        } else {
+            mul_by_pow10(&mut divisor, -power as u32);
+        }
+        div_rem_internal(&mut quotient, &divisor, &mut remainder);
+
+        // Append a non-zero digit to an inexact quotient so that it is only rounded once, and
+        // otherwise drop the trailing zeros that regular division wouldn't have kept.
+        mul_by_pow10(&mut quotient, 1);
+        let mut scale = dp + 2;
+        if !is_all_zero(&remainder) {
+            add_one_internal(&mut quotient);
+        } else {
+            let preferred = self.scale().saturating_sub(other.scale());
+            while scale > preferred {
+                let mut reduced = quotient;
+                if div_by_u32(&mut reduced, 10) != 0 {
+                    break;
+                }
+                quotient = reduced;
+                scale -= 1;
+            }
+        }
+        wide_to_decimal_rounded_dp(&quotient, negative, scale, dp, strategy)
+    }
+
+    /// Checked addition that only succeeds if the sum can be represented exactly.
+    ///
+    /// Returns an error of kind [`ErrorKind::Inexact`] if the sum would have to be rounded to fit
+    /// within 96 bits and a maximum scale of 28, or an overflow error if it is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, ErrorKind};
+    /// use core::str::FromStr;
+    ///
+    /// let ten = Decimal::from(10);
+    /// let tiny = Decimal::from_str("0.0000000000000000000000000001").unwrap();
+    /// assert_eq!(ten.checked_add_exact(Decimal::ONE), Ok(Decimal::from(11)));
+    /// assert_eq!(ten.checked_add_exact(tiny).unwrap_err().kind(), ErrorKind::Inexact);
+    /// ```
+    pub fn checked_add_exact(self, other: Decimal) -> Result<Decimal, Error> {
+        let result = match ops::add_impl(&self, &other) {
+            CalculationResult::Ok(result) => result,
+            _ => return Err(overflow_error(self.is_sign_negative(), "Addition overflowed")),
+        };
+
+        let (sum, _, scale) = exact_sum(&self, &other);
+        exact_result(result, &sum, scale)
+    }
+
+    /// Checked subtraction that only succeeds if the difference can be represented exactly.
+    ///
+    /// Returns an error of kind [`ErrorKind::Inexact`] if the difference would have to be rounded
+    /// to fit within 96 bits and a maximum scale of 28, or an overflow error if it is out of range.
+    pub fn checked_sub_exact(self, other: Decimal) -> Result<Decimal, Error> {
+        self.checked_add_exact(-other)
+    }
+
+    /// Checked multiplication that only succeeds if the product can be represented exactly.
+    ///
+    /// Returns an error of kind [`ErrorKind::Inexact`] if the product would have to be rounded to
+    /// fit within 96 bits and a maximum scale of 28, or an overflow error if it is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, ErrorKind};
+    /// use core::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("1.5").unwrap();
+    /// let b = Decimal::from_str("0.0000000000000000000000000001").unwrap();
+    /// assert_eq!(a.checked_mul_exact(a).unwrap().to_string(), "2.25");
+    /// assert_eq!(a.checked_mul_exact(b).unwrap_err().kind(), ErrorKind::Inexact);
+    /// ```
+    pub fn checked_mul_exact(self, other: Decimal) -> Result<Decimal, Error> {
+        let result = match ops::mul_impl(&self, &other) {
+            CalculationResult::Ok(result) => result,
+            _ => {
+                let negative = self.is_sign_negative() ^ other.is_sign_negative();
+                return Err(overflow_error(negative, "Multiplication overflowed"));
+            }
+        };
+        let mut product = [0u32; 6];
+        mul_internal(&self.mantissa_array3(), &other.mantissa_array3(), &mut product);
+        exact_result(result, &product, self.scale() + other.scale())
+    }
 
-        // This step is required in order to remove excess bits of precision from the
-        // end of the bit representation, down to the precision guaranteed by the
-        // floating point number
-        if is64 {
-            // Guaranteed to about 16 dp
-            while exponent10 < 0 && (bits[2] != 0 || (bits[1] & 0xFFF0_0000) != 0) {
-                let rem10 = div_by_u32(bits, 10);
-                exponent10 += 1;
-                if rem10 >= 5 {
-                    add_one_internal(bits);
-                }
-            }
-        } else {
-            // Guaranteed to about 7 dp
-            while exponent10 < 0 && ((bits[0] & 0xFF00_0000) != 0 || bits[1] != 0 || bits[2] != 0) {
-                let rem10 = div_by_u32(bits, 10);
-                exponent10 += 1;
-                if rem10 >= 5 {
-                    add_one_internal(bits);
-                }
+    /// Checked division that only succeeds if the quotient can be represented exactly.
+    ///
+    /// Returns an error of kind [`ErrorKind::Inexact`] if the quotient would have to be rounded to
+    /// fit within 96 bits and a maximum scale of 28, an error of kind
+    /// [`ErrorKind::DivisionByZero`] if `other` is zero, or an overflow error if it is out of
+    /// range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, ErrorKind};
+    /// use core::str::FromStr;
+    ///
+    /// let total = Decimal::from_str("100").unwrap();
+    /// assert_eq!(total.checked_div_exact(Decimal::from(4)).unwrap().to_string(), "25");
+    /// assert_eq!(total.checked_div_exact(Decimal::from(3)).unwrap_err().kind(), ErrorKind::Inexact);
+    /// ```
+    pub fn checked_div_exact(self, other: Decimal) -> Result<Decimal, Error> {
+        let result = match ops::div_impl(&self, &other) {
+            CalculationResult::Ok(result) => result,
+            CalculationResult::DivByZero => return Err(Error::new(ErrorKind::DivisionByZero, "Division by zero")),
+            CalculationResult::Overflow => {
+                let negative = self.is_sign_negative() ^ other.is_sign_negative();
+                return Err(overflow_error(negative, "Division overflowed"));
             }
-        }
+        };
 
-        // Remove multiples of 10 from the representation
-        while exponent10 < 0 {
-            let mut temp = [bits[0], bits[1], bits[2]];
-            let remainder = div_by_u32(&mut temp, 10);
-            if remainder == 0 {
-                exponent10 += 1;
-                bits[0] = temp[0];
-                bits[1] = temp[1];
-                bits[2] = temp[2];
-            } else {
-                break;
-            }
+        // The quotient is exact if multiplying it back by the divisor gives us the dividend
+        let mut product = [0u32; 6];
+        mul_internal(&result.mantissa_array3(), &other.mantissa_array3(), &mut product);
+        if eq_scaled(
+            &product,
+            result.scale() + other.scale(),
+            &self.mantissa_array3(),
+            self.scale(),
+        ) {
+            Ok(result)
+        } else {
+            Err(Error::new(ErrorKind::Inexact, "Division result is inexact"))
         }
-
-        Some(Decimal {
-            lo: bits[0],
-            mid: bits[1],
-            hi: bits[2],
-            flags: flags(!positive, -exponent10 as u32),
-        })
     }
 
-    /// Checked addition. Computes `self + other`, returning `None` if overflow occurred.
+    /// Saturating addition. Computes `self + other`, saturating at the relevant upper or lower
+    /// boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::MAX.saturating_add(Decimal::ONE), Decimal::MAX);
+    /// assert_eq!(Decimal::MIN.saturating_add(-Decimal::ONE), Decimal::MIN);
+    /// ```
     #[inline(always)]
-    pub fn checked_add(self, other: Decimal) -> Option<Decimal> {
+    pub fn saturating_add(self, other: Decimal) -> Decimal {
         match ops::add_impl(&self, &other) {
-            CalculationResult::Ok(result) => Some(result),
-            CalculationResult::Overflow => None,
-            _ => None,
+            CalculationResult::Ok(result) => result,
+            _ => saturated(self.is_sign_negative()),
         }
     }
 
-    /// Checked subtraction. Computes `self - other`, returning `None` if overflow occurred.
+    /// Saturating subtraction. Computes `self - other`, saturating at the relevant upper or lower
+    /// boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::MAX.saturating_sub(-Decimal::ONE), Decimal::MAX);
+    /// assert_eq!(Decimal::MIN.saturating_sub(Decimal::ONE), Decimal::MIN);
+    /// ```
     #[inline(always)]
-    pub fn checked_sub(self, other: Decimal) -> Option<Decimal> {
+    pub fn saturating_sub(self, other: Decimal) -> Decimal {
         match ops::sub_impl(&self, &other) {
-            CalculationResult::Ok(result) => Some(result),
-            CalculationResult::Overflow => None,
-            _ => None,
+            CalculationResult::Ok(result) => result,
+            _ => saturated(self.is_sign_negative()),
         }
     }
 
-    /// Checked multiplication. Computes `self * other`, returning `None` if overflow occurred.
+    /// Saturating multiplication. Computes `self * other`, saturating at the relevant upper or
+    /// lower boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::MAX.saturating_mul(Decimal::new(2, 0)), Decimal::MAX);
+    /// assert_eq!(Decimal::MAX.saturating_mul(Decimal::new(-2, 0)), Decimal::MIN);
+    /// ```
+    ///
+    /// Saturating powers are provided by `MathematicalOps::saturating_powi` and
+    /// `MathematicalOps::saturating_powu`, which require the `maths` feature.
     #[inline]
-    pub fn checked_mul(self, other: Decimal) -> Option<Decimal> {
+    pub fn saturating_mul(self, other: Decimal) -> Decimal {
         match ops::mul_impl(&self, &other) {
-            CalculationResult::Ok(result) => Some(result),
-            CalculationResult::Overflow => None,
-            _ => None,
+            CalculationResult::Ok(result) => result,
+            _ => saturated(self.is_sign_negative() ^ other.is_sign_negative()),
         }
     }
 
-    /// Checked division. Computes `self / other`, returning `None` if `other == 0.0` or the
-    /// division results in overflow.
-    pub fn checked_div(self, other: Decimal) -> Option<Decimal> {
+    /// Saturating division. Computes `self / other`, saturating at the relevant upper or lower
+    /// boundary.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// let tiny = Decimal::new(1, 28);
+    /// assert_eq!(Decimal::MAX.saturating_div(tiny), Decimal::MAX);
+    /// assert_eq!(Decimal::MAX.saturating_div(-tiny), Decimal::MIN);
+    /// ```
+    pub fn saturating_div(self, other: Decimal) -> Decimal {
         match ops::div_impl(&self, &other) {
-            CalculationResult::Ok(quot) => Some(quot),
-            CalculationResult::Overflow => None,
-            CalculationResult::DivByZero => None,
+            CalculationResult::Ok(quot) => quot,
+            CalculationResult::Overflow => saturated(self.is_sign_negative() ^ other.is_sign_negative()),
+            CalculationResult::DivByZero => panic!("Division by zero"),
         }
     }
 
-    /// Checked remainder. Computes `self % other`, returning `None` if `other == 0.0`.
-    pub fn checked_rem(self, other: Decimal) -> Option<Decimal> {
-        match ops::rem_impl(&self, &other) {
-            CalculationResult::Ok(quot) => Some(quot),
-            CalculationResult::Overflow => None,
-            CalculationResult::DivByZero => None,
-        }
+    /// Sums the values of an iterator, returning `None` if overflow occurred at any point.
+    ///
+    /// Unlike the `Sum` implementation this will not panic, which makes it suitable for
+    /// aggregating values from untrusted sources.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// let values = [Decimal::new(15, 1), Decimal::new(25, 1)];
+    /// assert_eq!(Decimal::checked_sum(&values), Some(Decimal::new(4, 0)));
+    /// assert_eq!(Decimal::checked_sum(vec![Decimal::MAX, Decimal::ONE]), None);
+    /// ```
+    pub fn checked_sum<I, T>(iter: I) -> Option<Decimal>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Decimal>,
+    {
+        iter.into_iter()
+            .try_fold(ZERO, |sum, value| sum.checked_add(*value.borrow()))
+    }
+
+    /// Multiplies the values of an iterator together, returning `None` if overflow occurred at
+    /// any point. The product of an empty iterator is one.
+    ///
+    /// Unlike the `Product` implementation this will not panic, which makes it suitable for
+    /// aggregating values from untrusted sources.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// let values = [Decimal::new(15, 1), Decimal::new(4, 0)];
+    /// assert_eq!(Decimal::checked_product(&values), Some(Decimal::new(6, 0)));
+    /// assert_eq!(Decimal::checked_product(vec![Decimal::MAX, Decimal::new(2, 0)]), None);
+    /// ```
+    pub fn checked_product<I, T>(iter: I) -> Option<Decimal>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Decimal>,
+    {
+        iter.into_iter()
+            .try_fold(ONE, |product, value| product.checked_mul(*value.borrow()))
     }
 
+    /// Parses a string slice into a decimal using the given radix, which must be between 2 and 36
+    /// inclusive. This is also used by the `num_traits::Num` implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    ///
+    /// assert_eq!(Decimal::from_str_radix("ff", 16).unwrap(), Decimal::from(255));
+    /// assert_eq!(Decimal::from_str_radix("-101", 2).unwrap(), Decimal::from(-5));
+    /// ```
     pub fn from_str_radix(str: &str, radix: u32) -> Result<Self, crate::Error> {
         if radix == 10 {
             parse_str_radix_10(str)
@@ -1262,6 +2172,16 @@ pub(crate) enum CalculationResult {
     DivByZero,
 }
 
+// The boundary that a saturating operation clamps to, given the sign of the overflowed result.
+#[inline(always)]
+const fn saturated(negative: bool) -> Decimal {
+    if negative {
+        MIN
+    } else {
+        MAX
+    }
+}
+
 #[inline]
 const fn flags(neg: bool, scale: u32) -> u32 {
     (scale << SCALE_SHIFT) | ((neg as u32) << SIGN_SHIFT)
@@ -1360,7 +2280,7 @@ pub(crate) fn add_by_internal(value: &mut [u32], by: &[u32]) -> u32 {
 }
 
 #[inline]
-fn add_one_internal(value: &mut [u32]) -> u32 {
+pub(crate) fn add_one_internal(value: &mut [u32]) -> u32 {
     let mut carry: u64 = 1; // Start with one, since adding one
     let mut sum: u64;
     for i in value.iter_mut() {
@@ -1519,6 +2439,62 @@ pub(crate) fn is_all_zero(bits: &[u32]) -> bool {
     bits.iter().all(|b| *b == 0)
 }
 
+// Determines whether a value that has been truncated should have its magnitude incremented by one
+// in order to be rounded according to `strategy`.
+// * `truncated` - the truncated value.
+// * `midpoint` - how the discarded portion compares to half of the unit being rounded to.
+// * `inexact` - whether the discarded portion is non-zero.
+#[inline]
+pub(crate) fn round_increment_required(
+    strategy: RoundingStrategy,
+    negative: bool,
+    truncated: &[u32],
+    midpoint: Ordering,
+    inexact: bool,
+) -> bool {
+    #[allow(deprecated)]
+    match strategy {
+        RoundingStrategy::BankersRounding | RoundingStrategy::MidpointNearestEven => match midpoint {
+            Ordering::Equal => (truncated[0] & 1) == 1,
+            Ordering::Greater => true,
+            Ordering::Less => false,
+        },
+        RoundingStrategy::MidpointNearestOdd => match midpoint {
+            Ordering::Equal => (truncated[0] & 1) == 0,
+            Ordering::Greater => true,
+            Ordering::Less => false,
+        },
+        RoundingStrategy::RoundHalfDown | RoundingStrategy::MidpointTowardZero => midpoint == Ordering::Greater,
+        // when Ordering::Equal, the discarded portion is 0.5 exactly
+        // when Ordering::Greater, the discarded portion is > 0.5
+        RoundingStrategy::RoundHalfUp | RoundingStrategy::MidpointAwayFromZero => midpoint != Ordering::Less,
+        RoundingStrategy::RoundUp | RoundingStrategy::AwayFromZero => inexact,
+        RoundingStrategy::ToPositiveInfinity => !negative && inexact,
+        RoundingStrategy::ToNegativeInfinity => negative && inexact,
+        RoundingStrategy::RoundDown | RoundingStrategy::ToZero => false,
+        RoundingStrategy::ZeroFiveUp => inexact && matches!(rem_by_u32(truncated, 10), 0 | 5),
+    }
+}
+
+// Calculates the remainder of dividing an arbitrary length integer by a u32, without modifying it.
+fn rem_by_u32(bits: &[u32], divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for part in bits.iter().rev() {
+        remainder = ((remainder << 32) | u64::from(*part)) % u64::from(divisor);
+    }
+    remainder as u32
+}
+
+// Returns the result of an operation if its magnitude is equal to the exact magnitude provided,
+// otherwise an inexact error.
+fn exact_result(result: Decimal, exact: &[u32], scale: u32) -> Result<Decimal, Error> {
+    if eq_scaled(exact, scale, &result.mantissa_array3(), result.scale()) {
+        Ok(result)
+    } else {
+        Err(Error::new(ErrorKind::Inexact, "Result is inexact"))
+    }
+}
+
 macro_rules! impl_from {
     ($T:ty, $from_ty:path) => {
         impl core::convert::From<$T> for Decimal {
@@ -1606,29 +2582,27 @@ impl One for Decimal {
     }
 }
 
+impl Bounded for Decimal {
+    fn min_value() -> Decimal {
+        MIN
+    }
+
+    fn max_value() -> Decimal {
+        MAX
+    }
+}
+
 impl Signed for Decimal {
     fn abs(&self) -> Self {
         self.abs()
     }
 
     fn abs_sub(&self, other: &Self) -> Self {
-        if self <= other {
-            ZERO
-        } else {
-            self.abs()
-        }
+        self.abs_sub(other)
     }
 
     fn signum(&self) -> Self {
-        if self.is_zero() {
-            ZERO
-        } else {
-            let mut value = ONE;
-            if self.is_sign_negative() {
-                value.set_sign_negative(true);
-            }
-            value
-        }
+        self.signum()
     }
 
     fn is_positive(&self) -> bool {
@@ -1675,10 +2649,128 @@ impl CheckedRem for Decimal {
     }
 }
 
+impl CheckedNeg for Decimal {
+    fn checked_neg(&self) -> Option<Decimal> {
+        Decimal::checked_neg(*self)
+    }
+}
+
+impl Saturating for Decimal {
+    #[inline]
+    fn saturating_add(self, v: Decimal) -> Decimal {
+        Decimal::saturating_add(self, v)
+    }
+
+    #[inline]
+    fn saturating_sub(self, v: Decimal) -> Decimal {
+        Decimal::saturating_sub(self, v)
+    }
+}
+
+impl SaturatingAdd for Decimal {
+    #[inline]
+    fn saturating_add(&self, v: &Decimal) -> Decimal {
+        Decimal::saturating_add(*self, *v)
+    }
+}
+
+impl SaturatingSub for Decimal {
+    #[inline]
+    fn saturating_sub(&self, v: &Decimal) -> Decimal {
+        Decimal::saturating_sub(*self, *v)
+    }
+}
+
+impl SaturatingMul for Decimal {
+    #[inline]
+    fn saturating_mul(&self, v: &Decimal) -> Decimal {
+        Decimal::saturating_mul(*self, *v)
+    }
+}
+
+impl Euclid for Decimal {
+    #[inline]
+    fn div_euclid(&self, v: &Decimal) -> Decimal {
+        Decimal::div_euclid(*self, *v)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &Decimal) -> Decimal {
+        Decimal::rem_euclid(*self, *v)
+    }
+}
+
+impl CheckedEuclid for Decimal {
+    #[inline]
+    fn checked_div_euclid(&self, v: &Decimal) -> Option<Decimal> {
+        Decimal::checked_div_euclid(*self, *v)
+    }
+
+    #[inline]
+    fn checked_rem_euclid(&self, v: &Decimal) -> Option<Decimal> {
+        Decimal::checked_rem_euclid(*self, *v)
+    }
+}
+
+impl MulAdd for Decimal {
+    type Output = Decimal;
+
+    #[inline]
+    fn mul_add(self, a: Decimal, b: Decimal) -> Decimal {
+        Decimal::mul_add(self, a, b)
+    }
+}
+
+impl MulAddAssign for Decimal {
+    #[inline]
+    fn mul_add_assign(&mut self, a: Decimal, b: Decimal) {
+        *self = Decimal::mul_add(*self, a, b);
+    }
+}
+
+impl Inv for Decimal {
+    type Output = Decimal;
+
+    fn inv(self) -> Decimal {
+        ONE / self
+    }
+}
+
+impl Inv for &Decimal {
+    type Output = Decimal;
+
+    fn inv(self) -> Decimal {
+        ONE / self
+    }
+}
+
+#[cold]
+fn integer_division_panic(divisor: &Decimal) -> ! {
+    if divisor.is_zero() {
+        panic!("Division by zero")
+    } else {
+        panic!("Division overflowed")
+    }
+}
+
+#[inline]
+pub(crate) fn overflow_error(negative: bool, message: &'static str) -> Error {
+    if negative {
+        Error::new(ErrorKind::LessThanMinimumPossibleValue, message)
+    } else {
+        Error::new(ErrorKind::ExceedsMaximumPossibleValue, message)
+    }
+}
+
+#[inline]
+fn invalid_digit(position: usize, message: &'static str) -> Error {
+    Error::new(ErrorKind::InvalidDigit { position }, message)
+}
+
 // dedicated implementation for the most common case.
 fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
     if str.is_empty() {
-        return Err(Error::new("Invalid decimal: empty"));
+        return Err(Error::new(ErrorKind::Empty, "Invalid decimal: empty"));
     }
 
     let mut offset = 0;
@@ -1718,7 +2810,10 @@ fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
             }
             b'.' => {
                 if digits_before_dot >= 0 {
-                    return Err(Error::new("Invalid decimal: two decimal points"));
+                    return Err(Error::new(
+                        ErrorKind::MultipleDecimalPoints,
+                        "Invalid decimal: two decimal points",
+                    ));
                 }
                 digits_before_dot = coeff.len() as i32;
                 offset += 1;
@@ -1727,12 +2822,15 @@ fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
             b'_' => {
                 // Must start with a number...
                 if coeff.is_empty() {
-                    return Err(Error::new("Invalid decimal: must start lead with a number"));
+                    return Err(Error::new(
+                        ErrorKind::LeadingSeparator,
+                        "Invalid decimal: must start lead with a number",
+                    ));
                 }
                 offset += 1;
                 len -= 1;
             }
-            _ => return Err(Error::new("Invalid decimal: unknown character")),
+            _ => return Err(invalid_digit(offset, "Invalid decimal: unknown character")),
         }
     }
 
@@ -1745,11 +2843,14 @@ fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
             b'.' => {
                 // Still an error if we have a second dp
                 if digits_before_dot >= 0 {
-                    return Err(Error::new("Invalid decimal: two decimal points"));
+                    return Err(Error::new(
+                        ErrorKind::MultipleDecimalPoints,
+                        "Invalid decimal: two decimal points",
+                    ));
                 }
                 0
             }
-            _ => return Err(Error::new("Invalid decimal: unknown character")),
+            _ => return Err(invalid_digit(offset, "Invalid decimal: unknown character")),
         };
 
         // Round at midpoint
@@ -1776,7 +2877,7 @@ fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
 
     // here when no characters left
     if coeff.is_empty() {
-        return Err(Error::new("Invalid decimal: no digits found"));
+        return Err(Error::new(ErrorKind::NoDigits, "Invalid decimal: no digits found"));
     }
 
     let mut scale = if digits_before_dot >= 0 {
@@ -1800,20 +2901,26 @@ fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
             // This may or may not be an issue - depending on whether we're past a decimal point
             // or not.
             if (i as i32) < digits_before_dot && i + 1 < len {
-                return Err(Error::new("Invalid decimal: overflow from too many digits"));
+                return Err(overflow_error(
+                    negative,
+                    "Invalid decimal: overflow from too many digits",
+                ));
             }
 
             if *digit >= 5 {
                 let carry = add_one_internal(&mut data);
                 if carry > 0 {
                     // Highly unlikely scenario which is more indicative of a bug
-                    return Err(Error::new("Invalid decimal: overflow when rounding"));
+                    return Err(overflow_error(negative, "Invalid decimal: overflow when rounding"));
                 }
             }
             // We're also one less digit so reduce the scale
             let diff = (len - i) as u32;
             if diff > scale {
-                return Err(Error::new("Invalid decimal: overflow from scale mismatch"));
+                return Err(overflow_error(
+                    negative,
+                    "Invalid decimal: overflow from scale mismatch",
+                ));
             }
             scale -= diff;
             break;
@@ -1824,7 +2931,7 @@ fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
             let carry = add_by_internal(&mut data, &[*digit]);
             if carry > 0 {
                 // Highly unlikely scenario which is more indicative of a bug
-                return Err(Error::new("Invalid decimal: overflow from carry"));
+                return Err(overflow_error(negative, "Invalid decimal: overflow from carry"));
             }
         }
     }
@@ -1839,14 +2946,14 @@ fn parse_str_radix_10(str: &str) -> Result<Decimal, crate::Error> {
 
 pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error> {
     if str.is_empty() {
-        return Err(Error::new("Invalid decimal: empty"));
+        return Err(Error::new(ErrorKind::Empty, "Invalid decimal: empty"));
     }
     if radix < 2 {
-        return Err(Error::new("Unsupported radix < 2"));
+        return Err(Error::new(ErrorKind::UnsupportedRadix(radix), "Unsupported radix < 2"));
     }
     if radix > 36 {
         // As per trait documentation
-        return Err(Error::new("Unsupported radix > 36"));
+        return Err(Error::new(ErrorKind::UnsupportedRadix(radix), "Unsupported radix > 36"));
     }
 
     let mut offset = 0;
@@ -1916,7 +3023,7 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
         34 => 19,
         35 => 19,
         36 => 19,
-        _ => return Err(Error::new("Unsupported radix")),
+        _ => return Err(Error::new(ErrorKind::UnsupportedRadix(radix), "Unsupported radix")),
     };
 
     let mut maybe_round = false;
@@ -1925,7 +3032,7 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
         match b {
             b'0'..=b'9' => {
                 if b > max_n {
-                    return Err(Error::new("Invalid decimal: invalid character"));
+                    return Err(invalid_digit(offset, "Invalid decimal: invalid character"));
                 }
                 coeff.push(u32::from(b - b'0'));
                 offset += 1;
@@ -1939,7 +3046,7 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
             }
             b'a'..=b'z' => {
                 if b > max_alpha_lower {
-                    return Err(Error::new("Invalid decimal: invalid character"));
+                    return Err(invalid_digit(offset, "Invalid decimal: invalid character"));
                 }
                 coeff.push(u32::from(b - b'a') + 10);
                 offset += 1;
@@ -1952,7 +3059,7 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
             }
             b'A'..=b'Z' => {
                 if b > max_alpha_upper {
-                    return Err(Error::new("Invalid decimal: invalid character"));
+                    return Err(invalid_digit(offset, "Invalid decimal: invalid character"));
                 }
                 coeff.push(u32::from(b - b'A') + 10);
                 offset += 1;
@@ -1965,7 +3072,10 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
             }
             b'.' => {
                 if digits_before_dot >= 0 {
-                    return Err(Error::new("Invalid decimal: two decimal points"));
+                    return Err(Error::new(
+                        ErrorKind::MultipleDecimalPoints,
+                        "Invalid decimal: two decimal points",
+                    ));
                 }
                 digits_before_dot = coeff.len() as i32;
                 offset += 1;
@@ -1974,12 +3084,15 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
             b'_' => {
                 // Must start with a number...
                 if coeff.is_empty() {
-                    return Err(Error::new("Invalid decimal: must start lead with a number"));
+                    return Err(Error::new(
+                        ErrorKind::LeadingSeparator,
+                        "Invalid decimal: must start lead with a number",
+                    ));
                 }
                 offset += 1;
                 len -= 1;
             }
-            _ => return Err(Error::new("Invalid decimal: unknown character")),
+            _ => return Err(invalid_digit(offset, "Invalid decimal: unknown character")),
         }
     }
 
@@ -1989,19 +3102,19 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
         let digit = match next_byte {
             b'0'..=b'9' => {
                 if next_byte > max_n {
-                    return Err(Error::new("Invalid decimal: invalid character"));
+                    return Err(invalid_digit(offset, "Invalid decimal: invalid character"));
                 }
                 u32::from(next_byte - b'0')
             }
             b'a'..=b'z' => {
                 if next_byte > max_alpha_lower {
-                    return Err(Error::new("Invalid decimal: invalid character"));
+                    return Err(invalid_digit(offset, "Invalid decimal: invalid character"));
                 }
                 u32::from(next_byte - b'a') + 10
             }
             b'A'..=b'Z' => {
                 if next_byte > max_alpha_upper {
-                    return Err(Error::new("Invalid decimal: invalid character"));
+                    return Err(invalid_digit(offset, "Invalid decimal: invalid character"));
                 }
                 u32::from(next_byte - b'A') + 10
             }
@@ -2009,11 +3122,14 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
             b'.' => {
                 // Still an error if we have a second dp
                 if digits_before_dot >= 0 {
-                    return Err(Error::new("Invalid decimal: two decimal points"));
+                    return Err(Error::new(
+                        ErrorKind::MultipleDecimalPoints,
+                        "Invalid decimal: two decimal points",
+                    ));
                 }
                 0
             }
-            _ => return Err(Error::new("Invalid decimal: unknown character")),
+            _ => return Err(invalid_digit(offset, "Invalid decimal: unknown character")),
         };
 
         // Round at midpoint
@@ -2041,7 +3157,7 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
 
     // here when no characters left
     if coeff.is_empty() {
-        return Err(Error::new("Invalid decimal: no digits found"));
+        return Err(Error::new(ErrorKind::NoDigits, "Invalid decimal: no digits found"));
     }
 
     let mut scale = if digits_before_dot >= 0 {
@@ -2066,20 +3182,26 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
             // This may or may not be an issue - depending on whether we're past a decimal point
             // or not.
             if (i as i32) < digits_before_dot && i + 1 < len {
-                return Err(Error::new("Invalid decimal: overflow from too many digits"));
+                return Err(overflow_error(
+                    negative,
+                    "Invalid decimal: overflow from too many digits",
+                ));
             }
 
             if *digit >= 5 {
                 let carry = add_one_internal(&mut data);
                 if carry > 0 {
                     // Highly unlikely scenario which is more indicative of a bug
-                    return Err(Error::new("Invalid decimal: overflow when rounding"));
+                    return Err(overflow_error(negative, "Invalid decimal: overflow when rounding"));
                 }
             }
             // We're also one less digit so reduce the scale
             let diff = (len - i) as u32;
             if diff > scale {
-                return Err(Error::new("Invalid decimal: overflow from scale mismatch"));
+                return Err(overflow_error(
+                    negative,
+                    "Invalid decimal: overflow from scale mismatch",
+                ));
             }
             scale -= diff;
             break;
@@ -2090,7 +3212,7 @@ pub fn parse_str_radix_n(str: &str, radix: u32) -> Result<Decimal, crate::Error>
             let carry = add_by_internal(&mut data, &[*digit]);
             if carry > 0 {
                 // Highly unlikely scenario which is more indicative of a bug
-                return Err(Error::new("Invalid decimal: overflow from carry"));
+                return Err(overflow_error(negative, "Invalid decimal: overflow from carry"));
             }
         }
     }
@@ -2381,7 +3503,8 @@ impl core::convert::TryFrom<f32> for Decimal {
     type Error = crate::Error;
 
     fn try_from(value: f32) -> Result<Self, Error> {
-        Self::from_f32(value).ok_or_else(|| Error::new("Failed to convert to Decimal"))
+        Self::from_f32(value)
+            .ok_or_else(|| Error::new(ErrorKind::ConversionTo("Decimal"), "Failed to convert to Decimal"))
     }
 }
 
@@ -2389,7 +3512,8 @@ impl core::convert::TryFrom<f64> for Decimal {
     type Error = crate::Error;
 
     fn try_from(value: f64) -> Result<Self, Error> {
-        Self::from_f64(value).ok_or_else(|| Error::new("Failed to convert to Decimal"))
+        Self::from_f64(value)
+            .ok_or_else(|| Error::new(ErrorKind::ConversionTo("Decimal"), "Failed to convert to Decimal"))
     }
 }
 
@@ -2397,7 +3521,7 @@ impl core::convert::TryFrom<Decimal> for f32 {
     type Error = crate::Error;
 
     fn try_from(value: Decimal) -> Result<Self, Self::Error> {
-        Decimal::to_f32(&value).ok_or_else(|| Error::new("Failed to convert to f32"))
+        Decimal::to_f32(&value).ok_or_else(|| Error::new(ErrorKind::ConversionTo("f32"), "Failed to convert to f32"))
     }
 }
 
@@ -2405,7 +3529,7 @@ impl core::convert::TryFrom<Decimal> for f64 {
     type Error = crate::Error;
 
     fn try_from(value: Decimal) -> Result<Self, Self::Error> {
-        Decimal::to_f64(&value).ok_or_else(|| Error::new("Failed to convert to f64"))
+        Decimal::to_f64(&value).ok_or_else(|| Error::new(ErrorKind::ConversionTo("f64"), "Failed to convert to f64"))
     }
 }
 
@@ -2478,14 +3602,13 @@ impl fmt::Debug for Decimal {
 }
 
 fn fmt_scientific_notation(value: &Decimal, exponent_symbol: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    #[cfg(not(feature = "std"))]
-    use alloc::string::ToString;
+    use core::fmt::Write;
 
     // Get the scale - this is the e value. With multiples of 10 this may get bigger.
     let mut exponent = -(value.scale() as isize);
 
     // Convert the integral to a string
-    let mut chars = Vec::new();
+    let mut chars = ArrayVec::<[_; MAX_STR_BUFFER_SIZE]>::new();
     let mut working = [value.lo, value.mid, value.hi];
     while !is_all_zero(&working) {
         let remainder = div_by_u32(&mut working, 10u32);
@@ -2499,22 +3622,30 @@ fn fmt_scientific_notation(value: &Decimal, exponent_symbol: &str, f: &mut fmt::
     // point in the right place and adjust the exponent accordingly.
 
     let len = chars.len();
-    let mut rep;
+    let mut rep = ArrayString::<[u8; MAX_SCIENTIFIC_STR_BUFFER_SIZE]>::new();
     if len > 1 {
         if chars.iter().take(len - 1).all(|c| *c == '0') {
             // Chomp off the zero's.
-            rep = chars.iter().skip(len - 1).collect::<String>();
+            rep.push(chars[len - 1]);
         } else {
-            chars.insert(len - 1, '.');
-            rep = chars.iter().rev().collect::<String>();
+            let mut digits = chars.iter().rev();
+            if let Some(first) = digits.next() {
+                rep.push(*first);
+            }
+            rep.push('.');
+            for c in digits {
+                rep.push(*c);
+            }
         }
         exponent += (len - 1) as isize;
     } else {
-        rep = chars.iter().collect::<String>();
+        for c in chars.iter() {
+            rep.push(*c);
+        }
     }
 
     rep.push_str(exponent_symbol);
-    rep.push_str(&exponent.to_string());
+    write!(rep, "{}", exponent)?;
     f.pad_integral(value.is_sign_positive(), "", &rep)
 }
 
@@ -2816,6 +3947,26 @@ impl<'a> Sum<&'a Decimal> for Decimal {
     }
 }
 
+impl Product for Decimal {
+    fn product<I: Iterator<Item = Decimal>>(iter: I) -> Self {
+        let mut product = ONE;
+        for i in iter {
+            product *= i;
+        }
+        product
+    }
+}
+
+impl<'a> Product<&'a Decimal> for Decimal {
+    fn product<I: Iterator<Item = &'a Decimal>>(iter: I) -> Self {
+        let mut product = ONE;
+        for i in iter {
+            product *= i;
+        }
+        product
+    }
+}
+
 #[cfg(test)]
 mod test {
     // Tests on private methods.
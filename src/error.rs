@@ -1,33 +1,94 @@
-use alloc::string::String;
 use core::fmt;
 
 /// Error type for the library.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Error {
-    message: String,
+    kind: ErrorKind,
+    message: &'static str,
+}
+
+/// A list specifying the general categories of errors that can be returned by the library.
+///
+/// This list is intended to grow over time and it is not recommended to exhaustively match
+/// against it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The string being parsed was empty.
+    Empty,
+    /// The string being parsed contained a character that is not a valid digit for the radix.
+    /// `position` is the byte offset of the offending character.
+    InvalidDigit { position: usize },
+    /// The string being parsed contained more than one decimal point.
+    MultipleDecimalPoints,
+    /// The string being parsed started with a digit separator (`_`) instead of a digit.
+    LeadingSeparator,
+    /// The string being parsed did not contain any digits.
+    NoDigits,
+    /// The string being parsed was not in a valid format, e.g. an invalid scientific notation.
+    InvalidFormat,
+    /// The radix requested is not within the supported range of 2 to 36 inclusive.
+    UnsupportedRadix(u32),
+    /// The value is larger than the maximum value that a `Decimal` can represent.
+    ExceedsMaximumPossibleValue,
+    /// The value is smaller than the minimum value that a `Decimal` can represent.
+    LessThanMinimumPossibleValue,
+    /// The requested scale exceeds the maximum precision of 28.
+    ScaleExceedsMaximumPrecision(u32),
+    /// The value is too small in magnitude to be represented with the maximum precision.
+    Underflow,
+    /// The value could not be converted to the named type, e.g. `"f64"`.
+    ConversionTo(&'static str),
+    /// An attempt was made to divide by zero.
+    DivisionByZero,
+    /// The result of an operation could not be represented exactly and would have been rounded.
+    Inexact,
+    /// The result of an operation had digits discarded in order to fit the precision of a
+    /// [`Context`](crate::Context), even if those digits were zero.
+    Rounded,
+    /// The scale of the result of an operation had to be reduced in order to be represented.
+    Clamped,
+    /// The requested precision is not within the supported range of 1 to 28 significant digits.
+    PrecisionOutOfRange(u32),
+    /// The operation has no meaningful result, e.g. quantizing a value to a scale that would
+    /// require more significant digits than the precision of a [`Context`](crate::Context).
+    InvalidOperation,
 }
 
 impl Error {
-    /// Instantiate an error with the specified error message.
+    /// Instantiate an error of the given kind with the specified error message.
     ///
     /// This function is only available within the crate as there should never
     /// be a need to create this error outside of the library.
-    pub(crate) fn new<S: Into<String>>(message: S) -> Error {
-        Error {
-            message: message.into(),
-        }
+    pub(crate) const fn new(kind: ErrorKind, message: &'static str) -> Error {
+        Error { kind, message }
+    }
+
+    /// Returns the corresponding `ErrorKind` for this error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::{Decimal, ErrorKind};
+    /// use core::str::FromStr;
+    ///
+    /// let err = Decimal::from_str("1.2a").unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidDigit { position: 3 });
+    /// ```
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {
     fn description(&self) -> &str {
-        &self.message
+        self.message
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        f.pad(&self.message)
+        f.pad(self.message)
     }
 }
@@ -0,0 +1,401 @@
+//! Time value of money functions which are compatible with the spreadsheet functions of the same
+//! name.
+//!
+//! Cash flows follow the usual sign convention: money received is positive and money paid out is
+//! negative. Results are calculated to the full precision of a `Decimal` and are left unrounded,
+//! so that callers can round them to the currency and strategy they require. Every function
+//! returns `None` if its inputs are invalid, if the result overflows, or if an iterative solution
+//! fails to converge.
+//!
+//! ```rust
+//! use rust_decimal::finance::{self, PaymentTiming};
+//! use rust_decimal::prelude::*;
+//!
+//! // The monthly repayment on a 30 year loan of 200,000 at 6% per annum
+//! let rate = Decimal::new(6, 2) / Decimal::from(12);
+//! let payment = finance::pmt(rate, Decimal::from(360), Decimal::from(200_000), Decimal::ZERO, PaymentTiming::End)
+//!     .unwrap();
+//! assert_eq!("-1199.10", payment.round_dp(2).to_string());
+//! ```
+use crate::prelude::*;
+
+const TWO: Decimal = Decimal::from_parts(2, 0, 0, false, 0);
+// The initial estimate of the rate used by `rate`, `irr` and `xirr`
+const GUESS: Decimal = Decimal::from_parts(1, 0, 0, false, 1);
+// Iterative solutions stop once the estimate changes by less than this
+const TOLERANCE: Decimal = Decimal::from_parts(1, 0, 0, false, 24);
+const MAX_ITERATIONS: u32 = 100;
+
+/// When payments are made within each period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaymentTiming {
+    /// Payments are made at the end of each period, as is usual for loans.
+    End,
+    /// Payments are made at the beginning of each period, e.g. rent paid in advance.
+    Beginning,
+}
+
+/// How the fraction of a year between two dated cash flows is counted by [`xnpv`] and [`xirr`].
+/// Dates are day numbers, so each convention divides the actual number of days between them by a
+/// fixed length of year. Conventions which depend on the calendar, such as 30/360 or
+/// Actual/Actual, can't be derived from day numbers alone.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DayCount {
+    /// The actual number of days divided by 365, as used by the spreadsheet functions. This is the
+    /// default.
+    #[default]
+    Actual365,
+    /// The actual number of days divided by 360, as is usual for money market instruments.
+    Actual360,
+    /// The actual number of days divided by 365.25, the average length of a Julian year.
+    Actual36525,
+}
+
+impl DayCount {
+    /// The number of days in a year under this convention. Used internally.
+    fn days_in_year(self) -> Decimal {
+        match self {
+            DayCount::Actual365 => Decimal::from_parts(365, 0, 0, false, 0),
+            DayCount::Actual360 => Decimal::from_parts(360, 0, 0, false, 0),
+            DayCount::Actual36525 => Decimal::from_parts(36525, 0, 0, false, 2),
+        }
+    }
+}
+
+/// The future value of an investment with a constant interest `rate` per period, making a
+/// payment of `pmt` in each of `nper` periods after an initial investment of `pv`.
+pub fn fv(rate: Decimal, nper: Decimal, pmt: Decimal, pv: Decimal, timing: PaymentTiming) -> Option<Decimal> {
+    let (growth, annuity) = growth_and_annuity(rate, nper, timing)?;
+    pv.checked_mul(growth)?
+        .checked_add(pmt.checked_mul(annuity)?)
+        .map(|fv| -fv)
+}
+
+/// The present value of an investment with a constant interest `rate` per period, making a
+/// payment of `pmt` in each of `nper` periods and leaving a final balance of `fv`.
+pub fn pv(rate: Decimal, nper: Decimal, pmt: Decimal, fv: Decimal, timing: PaymentTiming) -> Option<Decimal> {
+    let (growth, annuity) = growth_and_annuity(rate, nper, timing)?;
+    fv.checked_add(pmt.checked_mul(annuity)?)?
+        .checked_div(growth)
+        .map(|pv| -pv)
+}
+
+/// The payment required in each of `nper` periods to take a loan or investment from a present
+/// value of `pv` to a future value of `fv`, with a constant interest `rate` per period.
+pub fn pmt(rate: Decimal, nper: Decimal, pv: Decimal, fv: Decimal, timing: PaymentTiming) -> Option<Decimal> {
+    let (growth, annuity) = growth_and_annuity(rate, nper, timing)?;
+    fv.checked_add(pv.checked_mul(growth)?)?
+        .checked_div(annuity)
+        .map(|pmt| -pmt)
+}
+
+/// The number of periods required to take a loan or investment from a present value of `pv` to a
+/// future value of `fv` by making a payment of `pmt` each period, with a constant interest `rate`
+/// per period. The result is not necessarily an integer.
+pub fn nper(rate: Decimal, pmt: Decimal, pv: Decimal, fv: Decimal, timing: PaymentTiming) -> Option<Decimal> {
+    if rate.is_zero() {
+        return fv.checked_add(pv)?.checked_div(pmt).map(|nper| -nper);
+    }
+    // Solving fv = -(pv (1 + r)^n + pmt (1 + r t) ((1 + r)^n - 1) / r) for n
+    let payment = pmt.checked_mul(timing_factor(rate, timing)?)?.checked_div(rate)?;
+    let ratio = payment.checked_sub(fv)?.checked_div(payment.checked_add(pv)?)?;
+    ratio
+        .checked_ln()?
+        .checked_div(Decimal::ONE.checked_add(rate)?.checked_ln()?)
+}
+
+/// The interest rate per period which takes a loan or investment from a present value of `pv` to
+/// a future value of `fv` over `nper` periods when making a payment of `pmt` each period. The rate
+/// is found using Newton's method starting from an estimate of 10%.
+pub fn rate(nper: Decimal, pmt: Decimal, pv: Decimal, fv: Decimal, timing: PaymentTiming) -> Option<Decimal> {
+    // Newton's method can't start from zero, so check whether that is the solution up front
+    if pv.checked_add(pmt.checked_mul(nper)?)?.checked_add(fv)?.is_zero() {
+        return Some(Decimal::ZERO);
+    }
+    solve(GUESS, |rate| {
+        // f(r) = pv g + pmt a + fv, where g = (1 + r)^n and a = (1 + r t) (g - 1) / r
+        let (growth, annuity) = growth_and_annuity(rate, nper, timing)?;
+        let value = pv
+            .checked_mul(growth)?
+            .checked_add(pmt.checked_mul(annuity)?)?
+            .checked_add(fv)?;
+
+        let factor = Decimal::ONE.checked_add(rate)?;
+        let growth_derivative = nper.checked_mul(growth)?.checked_div(factor)?;
+        let interest = growth.checked_sub(Decimal::ONE)?.checked_div(rate)?;
+        let interest_derivative = growth_derivative.checked_sub(interest)?.checked_div(rate)?;
+        let annuity_derivative = match timing {
+            PaymentTiming::End => interest_derivative,
+            PaymentTiming::Beginning => interest.checked_add(factor.checked_mul(interest_derivative)?)?,
+        };
+        let derivative = pv
+            .checked_mul(growth_derivative)?
+            .checked_add(pmt.checked_mul(annuity_derivative)?)?;
+        Some((value, derivative))
+    })
+}
+
+/// The net present value of a series of cash flows at the end of each period, discounted at a
+/// constant `rate` per period. The first value is discounted by one full period.
+pub fn npv(rate: Decimal, values: &[Decimal]) -> Option<Decimal> {
+    let discount = Decimal::ONE.checked_div(Decimal::ONE.checked_add(rate)?)?;
+    let (value, _) = polynomial(discount, values)?;
+    value.checked_mul(discount)
+}
+
+/// The internal rate of return of a series of cash flows occurring at regular periods, which is
+/// the rate at which their net present value is zero. The rate is found using Newton's method
+/// starting from an estimate of 10%.
+///
+/// Returns `None` if there are fewer than two cash flows.
+pub fn irr(values: &[Decimal]) -> Option<Decimal> {
+    if values.len() < 2 {
+        return None;
+    }
+    solve(GUESS, |rate| {
+        // The value is p(x) = sum(v_i x^i) where x = 1 / (1 + r), so dp/dr = -x^2 p'(x)
+        let discount = Decimal::ONE.checked_div(Decimal::ONE.checked_add(rate)?)?;
+        let (value, derivative) = polynomial(discount, values)?;
+        let derivative = derivative.checked_mul(discount)?.checked_mul(discount)?;
+        Some((value, -derivative))
+    })
+}
+
+/// The net present value of a series of cash flows occurring on the given `dates`, discounted at
+/// a constant annual `rate`. The dates are day numbers, such as the number of days since an epoch
+/// or a spreadsheet serial date, and each cash flow is discounted by the years since the first
+/// date as counted by `day_count`. [`DayCount::Actual365`] gives the same result as the
+/// spreadsheet function.
+///
+/// Returns `None` if the number of values and dates differ or if there are no values.
+pub fn xnpv(rate: Decimal, values: &[Decimal], dates: &[i64], day_count: DayCount) -> Option<Decimal> {
+    xnpv_with_derivative(rate, values, dates, day_count).map(|(value, _)| value)
+}
+
+/// The internal rate of return of a series of cash flows occurring on the given `dates`, which is
+/// the annual rate at which their [`xnpv`] with the same `day_count` is zero. The rate is found
+/// using Newton's method starting from an estimate of 10%.
+///
+/// Returns `None` if the number of values and dates differ or if there are fewer than two values.
+pub fn xirr(values: &[Decimal], dates: &[i64], day_count: DayCount) -> Option<Decimal> {
+    if values.len() < 2 {
+        return None;
+    }
+    solve(GUESS, |rate| xnpv_with_derivative(rate, values, dates, day_count))
+}
+
+/// A single period of an [`AmortizationSchedule`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AmortizationPeriod {
+    /// The period number, starting from 1.
+    pub period: u32,
+    /// The payment made at the end of the period, which is the sum of `interest` and `principal`.
+    pub payment: Decimal,
+    /// The portion of the payment which pays the interest accrued during the period.
+    pub interest: Decimal,
+    /// The portion of the payment which repays the principal.
+    pub principal: Decimal,
+    /// The principal still outstanding after the payment.
+    pub balance: Decimal,
+}
+
+/// An iterator over the periods of a fully amortizing loan with a constant interest rate and
+/// payments made at the end of each period.
+///
+/// The payment and the interest of each period are rounded to a fixed number of decimal places
+/// with the chosen `RoundingStrategy`, so the principal of each period is exact and the balance
+/// is reduced by exactly the amount repaid. The final payment is adjusted to clear any balance
+/// left over from rounding.
+///
+/// # Panics
+///
+/// Iterating panics if the interest of a period can't be represented. The interest on the
+/// principal is checked by [`AmortizationSchedule::new`], so this can only happen when the rounded
+/// payment doesn't cover the interest and the balance creeps past a principal that is already
+/// close to `Decimal::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use rust_decimal::finance::AmortizationSchedule;
+/// use rust_decimal::prelude::*;
+///
+/// let rate = Decimal::new(1, 2);
+/// let schedule = AmortizationSchedule::new(Decimal::from(1000), rate, 3, 2, RoundingStrategy::MidpointAwayFromZero)
+///     .unwrap();
+/// let payments = schedule.map(|period| period.payment.to_string()).collect::<Vec<_>>();
+/// assert_eq!(payments, vec!["340.02", "340.02", "340.03"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct AmortizationSchedule {
+    rate: Decimal,
+    periods: u32,
+    dp: u32,
+    strategy: RoundingStrategy,
+    payment: Decimal,
+    balance: Decimal,
+    period: u32,
+}
+
+impl AmortizationSchedule {
+    /// Creates a schedule for repaying `principal` over `periods` periods with a constant interest
+    /// `rate` per period, rounding amounts to `dp` decimal places using `strategy`.
+    ///
+    /// Returns `None` if there are no periods, or if either the payment or the interest on the
+    /// principal can not be represented.
+    pub fn new(
+        principal: Decimal,
+        rate: Decimal,
+        periods: u32,
+        dp: u32,
+        strategy: RoundingStrategy,
+    ) -> Option<AmortizationSchedule> {
+        if periods == 0 {
+            return None;
+        }
+        // The balance only exceeds the principal through rounding, so this keeps the interest of
+        // each period within range
+        principal.checked_mul(rate)?;
+        let payment = -pmt(
+            rate,
+            Decimal::from(periods),
+            principal,
+            Decimal::ZERO,
+            PaymentTiming::End,
+        )?;
+        Some(AmortizationSchedule {
+            rate,
+            periods,
+            dp,
+            strategy,
+            payment: payment.round_dp_with_strategy(dp, strategy),
+            balance: principal,
+            period: 0,
+        })
+    }
+
+    /// The rounded payment made in every period except the last.
+    pub fn payment(&self) -> Decimal {
+        self.payment
+    }
+}
+
+impl Iterator for AmortizationSchedule {
+    type Item = AmortizationPeriod;
+
+    fn next(&mut self) -> Option<AmortizationPeriod> {
+        if self.period == self.periods {
+            return None;
+        }
+        self.period += 1;
+        let interest = (self.balance * self.rate).round_dp_with_strategy(self.dp, self.strategy);
+        let principal = if self.period == self.periods {
+            self.balance
+        } else {
+            self.payment - interest
+        };
+        self.balance -= principal;
+        Some(AmortizationPeriod {
+            period: self.period,
+            payment: interest + principal,
+            interest,
+            principal,
+            balance: self.balance,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.periods - self.period) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for AmortizationSchedule {}
+
+/// Returns (1 + r)^n along with the value of an annuity paying one each period, which is
+/// (1 + r t) ((1 + r)^n - 1) / r, or simply n when the rate is zero. Used internally.
+fn growth_and_annuity(rate: Decimal, nper: Decimal, timing: PaymentTiming) -> Option<(Decimal, Decimal)> {
+    if rate.is_zero() {
+        return Some((Decimal::ONE, nper));
+    }
+    let growth = Decimal::ONE.checked_add(rate)?.checked_powd(nper)?;
+    let annuity = growth
+        .checked_sub(Decimal::ONE)?
+        .checked_div(rate)?
+        .checked_mul(timing_factor(rate, timing)?)?;
+    Some((growth, annuity))
+}
+
+/// The factor (1 + r t) which accounts for payments at the beginning of each period. Used
+/// internally.
+fn timing_factor(rate: Decimal, timing: PaymentTiming) -> Option<Decimal> {
+    match timing {
+        PaymentTiming::End => Some(Decimal::ONE),
+        PaymentTiming::Beginning => Decimal::ONE.checked_add(rate),
+    }
+}
+
+/// Evaluates the polynomial sum(c_i x^i) along with its derivative using Horner's method. Used
+/// internally.
+fn polynomial(x: Decimal, coefficients: &[Decimal]) -> Option<(Decimal, Decimal)> {
+    let mut value = Decimal::ZERO;
+    let mut derivative = Decimal::ZERO;
+    for coefficient in coefficients.iter().rev() {
+        derivative = derivative.checked_mul(x)?.checked_add(value)?;
+        value = value.checked_mul(x)?.checked_add(*coefficient)?;
+    }
+    Some((value, derivative))
+}
+
+/// The net present value of dated cash flows along with its derivative with respect to the rate.
+/// Used internally.
+fn xnpv_with_derivative(
+    rate: Decimal,
+    values: &[Decimal],
+    dates: &[i64],
+    day_count: DayCount,
+) -> Option<(Decimal, Decimal)> {
+    if values.is_empty() || values.len() != dates.len() {
+        return None;
+    }
+    let factor = Decimal::ONE.checked_add(rate)?;
+    if factor.is_sign_negative() || factor.is_zero() {
+        return None;
+    }
+    let days_in_year = day_count.days_in_year();
+    let mut value = Decimal::ZERO;
+    let mut derivative = Decimal::ZERO;
+    for (amount, date) in values.iter().zip(dates) {
+        // Each cash flow is v (1 + r)^-e, which has the derivative -e v (1 + r)^(-e - 1)
+        let years = Decimal::from(date.checked_sub(dates[0])?).checked_div(days_in_year)?;
+        let discounted = amount.checked_div(factor.checked_powd(years)?)?;
+        value = value.checked_add(discounted)?;
+        derivative = derivative.checked_sub(years.checked_mul(discounted)?.checked_div(factor)?)?;
+    }
+    Some((value, derivative))
+}
+
+/// Finds a root of a function using Newton's method, given a function which returns both its
+/// value and its derivative. Used internally.
+fn solve<F>(guess: Decimal, f: F) -> Option<Decimal>
+where
+    F: Fn(Decimal) -> Option<(Decimal, Decimal)>,
+{
+    let mut rate = guess;
+    for _ in 0..MAX_ITERATIONS {
+        let (value, derivative) = f(rate)?;
+        if value.is_zero() {
+            return Some(rate);
+        }
+        let mut next = rate.checked_sub(value.checked_div(derivative)?)?;
+        // A rate of -100% or less has no meaning, so step halfway towards it instead
+        if next <= -Decimal::ONE {
+            next = rate.checked_sub(Decimal::ONE)?.checked_div(TWO)?;
+        }
+        if next.checked_sub(rate)?.abs() < TOLERANCE {
+            return Some(next);
+        }
+        rate = next;
+    }
+    None
+}
@@ -55,11 +55,13 @@
 //! * [db-postgres](#db-postgres)
 //! * [db-tokio-postgres](#db-tokio-postgres)
 //! * [db-diesel-postgres](#db-diesel-postgres)
+//! * [finance](#finance)
 //! * [legacy-ops](#legacy-ops)
 //! * [maths](#maths)
 //! * [rust-fuzz](#rust-fuzz)
 //! * [serde-float](#serde-float)
 //! * [serde-str](#serde-str)
+//! * [stats](#stats)
 //! * [std](#std)
 //!
 //! ## `db-postgres`
@@ -75,6 +77,11 @@
 //!
 //! Enable `diesel` PostgreSQL support.
 //!
+//! ## `finance`
+//!
+//! Enables the `finance` module, which provides time value of money functions such as `pmt`, `npv` and `irr` along
+//! with amortization schedules. This implies the `maths` feature.
+//!
 //! ## `legacy-ops`
 //!
 //! As of `1.10` the algorithms used to perform basic operations have changed which has benefits of significant speed improvements.
@@ -82,7 +89,10 @@
 //!
 //! ## `maths`
 //!
-//! This feature enables mathematical functionality such as `pow`, `ln`, `enf` etc.
+//! This feature enables mathematical functionality such as `pow`, `ln`, `enf`, `sin` etc.
+//!
+//! Powers, including the saturating `saturating_powi` and `saturating_powu`, are only available with this feature
+//! enabled as they are provided by the `MathematicalOps` trait.
 //!
 //! ## `rust-fuzz`
 //!
@@ -118,19 +128,34 @@
 //!
 //! This is recommended when parsing "float" looking data as it will prevent data loss.
 //!
+//! ## `stats`
+//!
+//! Enables the `stats` module, which provides descriptive statistics such as the mean, median, variance and percentiles
+//! of a collection of decimals. This implies the `maths` feature and requires `alloc`.
+//!
 //! ## `std`
 //!
 //! Enable `std` library support. This is enabled by default, however in the future will be opt in. For now, to support `no_std`
 //! libraries, this crate can be compiled with `--no-default-features`.
 //!
+//! When none of `std`, `serde` or `stats` are enabled the crate does not depend on `alloc` at all, making it usable on targets
+//! without a global allocator.
+//!
 #![forbid(unsafe_code)]
 #![cfg_attr(not(feature = "std"), no_std)]
+#[cfg(any(feature = "serde", feature = "stats"))]
 extern crate alloc;
 
+mod accumulator;
+pub mod consts;
+mod context;
 mod decimal;
 mod error;
 mod ops;
+mod wide_decimal;
 
+#[cfg(feature = "finance")]
+pub mod finance;
 #[cfg(feature = "rust-fuzz")]
 mod fuzz;
 #[cfg(feature = "maths")]
@@ -139,11 +164,16 @@ mod maths;
 mod postgres;
 #[cfg(feature = "serde")]
 mod serde_types;
+#[cfg(feature = "stats")]
+pub mod stats;
 
+pub use accumulator::DecimalAccumulator;
+pub use context::{Context, Signal};
 pub use decimal::{Decimal, RoundingStrategy};
-pub use error::Error;
+pub use error::{Error, ErrorKind};
 #[cfg(feature = "maths")]
 pub use maths::MathematicalOps;
+pub use wide_decimal::WideDecimal;
 
 /// A convenience module appropriate for glob imports (`use rust_decimal::prelude::*;`).
 pub mod prelude {
@@ -1,18 +1,62 @@
+mod fixed;
+
+use self::fixed::Fixed;
+use crate::consts::{FRAC_PI_2, FRAC_PI_4, LN_10, PI};
+use crate::decimal::MAX_PRECISION;
 use crate::prelude::*;
+use num_traits::Pow;
 
 const TWO: Decimal = Decimal::from_parts_raw(2, 0, 0, 0);
-const PI: Decimal = Decimal::from_parts_raw(1102470953, 185874565, 1703060790, 1835008);
-const LN2: Decimal = Decimal::from_parts_raw(2831677809, 328455696, 3757558395, 1900544);
-const EXP_TOLERANCE: Decimal = Decimal::from_parts(2, 0, 0, false, 7);
+const TEN: Decimal = Decimal::from_parts_raw(10, 0, 0, 0);
+const LN10_FIXED: Fixed = Fixed::from_raw([482622896, 1290123188, 3319679215, 29062709]);
+const SQRT_2: Fixed = Fixed::from_raw([3491005727, 308990678, 2816775040, 17849884]);
+const FRAC_1_SQRT_2: Fixed = Fixed::from_raw([1745502863, 154495339, 1408387520, 8924942]);
+const FRAC_2_SQRT_PI: Fixed = Fixed::from_raw([4239460308, 2778421607, 1627792425, 14242147]);
+const FRAC_1_SQRT_PI: Fixed = Fixed::from_raw([4267213802, 3536694451, 2961379860, 7121073]);
+const FRAC_1_SQRT_2PI: Fixed = Fixed::from_raw([3974018860, 1585316689, 2126758465, 5035359]);
+const PI_FIXED: Fixed = Fixed::from_raw([665273892, 2004718118, 4263804721, 39652473]);
+// π/2 split into its first 36 decimal places, truncated, and the following 36 decimal places
+const FRAC_PI_2_HIGH: Fixed = Fixed::from_raw([332636946, 3149842707, 4279386008, 19826236]);
+const FRAC_PI_2_LOW: Fixed = Fixed::from_raw([261161963, 3379932916, 3637766146, 1244313]);
 
 /// Trait exposing various mathematical operations that can be applied using a Decimal. This is only
 /// present when the `maths` feature has been enabled.
+///
+/// Every function that rounds its result has a `_with_precision` variant that rounds to a given
+/// number of decimal places instead of 28, except for the following:
+///
+/// * The integral powers and [`cbrt`](MathematicalOps::cbrt), which are covered by
+///   [`powd_with_precision`](MathematicalOps::powd_with_precision) and
+///   [`nth_root_with_precision`](MathematicalOps::nth_root_with_precision).
+/// * [`powf`](MathematicalOps::powf), since converting the exponent from a float already limits
+///   its precision; convert the exponent and use `powd_with_precision` instead.
+/// * [`exp_with_tolerance`](MathematicalOps::exp_with_tolerance), which is kept for compatibility
+///   and is superseded by [`exp_with_precision`](MathematicalOps::exp_with_precision).
+/// * The `checked_` and `saturating_` forms, which round in the same way as the function they
+///   wrap.
 pub trait MathematicalOps {
-    /// The estimated exponential function, e<sup>x</sup>, rounded to 8 decimal places. Stops
-    /// calculating when it is within tolerance of roughly 0.000002 in order to prevent
-    /// multiplication overflow.
+    /// The exponential function, e<sup>x</sup>. The argument is reduced by a multiple of ln(10)
+    /// before calculating the Taylor series with eight guard digits, so the result is within one
+    /// unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large to represent.
     fn exp(&self) -> Decimal;
 
+    /// The exponential function, e<sup>x</sup>, returning `None` if the result is too large to
+    /// represent.
+    fn checked_exp(&self) -> Option<Decimal>;
+
+    /// The exponential function, e<sup>x</sup>, rounded to `dp` decimal places using "Bankers
+    /// Rounding". If the result is too large to have `dp` decimal places then as few as possible
+    /// are discarded. The result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large to represent.
+    fn exp_with_precision(&self, dp: u32) -> Decimal;
+
     /// The estimated exponential function, e<sup>x</sup>, rounded to 8 decimal places. Stops
     /// calculating when it is within `tolerance`.
     /// Multiplication overflows are likely if you are not careful with the size of `tolerance`.
@@ -20,36 +64,422 @@ pub trait MathematicalOps {
     /// numbers to avoid multiplication overflow.
     fn exp_with_tolerance(&self, tolerance: Decimal) -> Decimal;
 
-    /// Raise self to the given unsigned integer exponent: x<sup>y</sup>
-    fn powi(&self, exp: u64) -> Decimal;
+    /// Raise self to the given integer exponent: x<sup>y</sup>. Exact powers are found by
+    /// repeated squaring, otherwise the result is calculated as e<sup>y ln(x)</sup> with eight
+    /// guard digits and rounded once, so it is within one unit in the last place. A negative
+    /// exponent gives zero if x<sup>-y</sup> is too large to represent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, or if self is zero and the exponent is negative.
+    fn powi(&self, exp: i64) -> Decimal;
+
+    /// Raise self to the given integer exponent x<sup>y</sup> returning `None` on overflow, or if
+    /// self is zero and the exponent is negative.
+    fn checked_powi(&self, exp: i64) -> Option<Decimal>;
+
+    /// Raise self to the given integer exponent x<sup>y</sup> saturating at `Decimal::MAX` or
+    /// `Decimal::MIN` on overflow. Zero raised to a negative exponent saturates at `Decimal::MAX`.
+    fn saturating_powi(&self, exp: i64) -> Decimal;
+
+    /// Raise self to the given unsigned integer exponent: x<sup>y</sup>. This is calculated in the
+    /// same way as [`powi`](MathematicalOps::powi).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    fn powu(&self, exp: u64) -> Decimal;
 
     /// Raise self to the given unsigned integer exponent x<sup>y</sup> returning
     /// `None` on overflow.
-    fn checked_powi(&self, exp: u64) -> Option<Decimal>;
+    fn checked_powu(&self, exp: u64) -> Option<Decimal>;
+
+    /// Raise self to the given unsigned integer exponent x<sup>y</sup> saturating at
+    /// `Decimal::MAX` or `Decimal::MIN` on overflow.
+    fn saturating_powu(&self, exp: u64) -> Decimal;
+
+    /// Raise self to the given Decimal exponent: x<sup>y</sup>. Integral exponents are calculated
+    /// in the same way as [`powi`](MathematicalOps::powi), otherwise this is calculated as
+    /// e<sup>y ln(x)</sup> with eight guard digits and rounded once, so the result is within one
+    /// unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, if self is zero and the exponent is negative, or if self is
+    /// negative and the exponent is not an integer.
+    fn powd(&self, exp: Decimal) -> Decimal;
+
+    /// Raise self to the given Decimal exponent x<sup>y</sup> returning `None` on overflow, if
+    /// self is zero and the exponent is negative, or if self is negative and the exponent is not
+    /// an integer.
+    fn checked_powd(&self, exp: Decimal) -> Option<Decimal>;
+
+    /// Raise self to the given Decimal exponent, rounded to `dp` decimal places using "Bankers
+    /// Rounding". If the result is too large to have `dp` decimal places then as few as possible
+    /// are discarded. The result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics for any of the reasons that [`powd`](MathematicalOps::powd) panics.
+    fn powd_with_precision(&self, exp: Decimal, dp: u32) -> Decimal;
+
+    /// Raise self to the given floating point exponent: x<sup>y</sup>. The exponent is converted
+    /// to a Decimal before calculating the result using [`powd`](MathematicalOps::powd).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the exponent is not finite or for any of the reasons that `powd` panics.
+    fn powf(&self, exp: f64) -> Decimal;
+
+    /// Raise self to the given floating point exponent x<sup>y</sup> returning `None` if the
+    /// exponent is not finite or for any of the reasons that `checked_powd` returns `None`.
+    fn checked_powf(&self, exp: f64) -> Option<Decimal>;
 
-    /// The square root of a Decimal. Uses a standard Babylonian method.
+    /// The square root of a Decimal, returning `None` if self is negative. A standard Babylonian
+    /// method is refined with a Newton step using eight guard digits, so the result is within one
+    /// unit in the last place.
     fn sqrt(&self) -> Option<Decimal>;
 
-    /// The natural logarithm for a Decimal. Uses a [fast estimation algorithm](https://en.wikipedia.org/wiki/Natural_logarithm#High_precision)
-    /// This is more accurate on larger numbers and less on numbers less than 1.
+    /// The square root of a Decimal rounded to `dp` decimal places using "Bankers Rounding",
+    /// returning `None` if self is negative. The result is within one unit in the last place.
+    fn sqrt_with_precision(&self, dp: u32) -> Option<Decimal>;
+
+    /// The cube root of a Decimal. Negative numbers have a negative cube root. The result is
+    /// within one unit in the last place, and exact cubes give an exact result.
+    fn cbrt(&self) -> Decimal;
+
+    /// The n-th root of a Decimal, calculated as e<sup>ln(x) / n</sup> with eight guard digits.
+    /// Negative numbers have a negative root when `n` is odd. The result is within one unit in the
+    /// last place, and exact powers give an exact result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if self is negative and `n` is even.
+    fn nth_root(&self, n: u32) -> Decimal;
+
+    /// The n-th root of a Decimal, returning `None` if `n` is zero, or if self is negative and `n`
+    /// is even.
+    fn checked_nth_root(&self, n: u32) -> Option<Decimal>;
+
+    /// The n-th root of a Decimal rounded to `dp` decimal places using "Bankers Rounding". The
+    /// result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero, or if self is negative and `n` is even.
+    fn nth_root_with_precision(&self, n: u32, dp: u32) -> Decimal;
+
+    /// The natural logarithm for a Decimal. The argument is scaled by a power of 10 to lie
+    /// between 0.3 and 3 before calculating the series for 2 atanh((x - 1) / (x + 1)) with eight
+    /// guard digits, so the result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative.
     fn ln(&self) -> Decimal;
 
-    /// Abramowitz Approximation of Error Function from [wikipedia](https://en.wikipedia.org/wiki/Error_function#Numerical_approximations)
+    /// The natural logarithm for a Decimal, returning `None` if self is zero or negative.
+    fn checked_ln(&self) -> Option<Decimal>;
+
+    /// The natural logarithm for a Decimal rounded to `dp` decimal places using "Bankers
+    /// Rounding". The result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative.
+    fn ln_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The base 10 logarithm for a Decimal. The result is exact if self is an exact power of 10,
+    /// otherwise it is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative.
+    fn log10(&self) -> Decimal;
+
+    /// The base 10 logarithm for a Decimal, returning `None` if self is zero or negative.
+    fn checked_log10(&self) -> Option<Decimal>;
+
+    /// The base 10 logarithm for a Decimal rounded to `dp` decimal places using "Bankers
+    /// Rounding". The result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative.
+    fn log10_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The base 2 logarithm for a Decimal. The result is exact if self is an exact power of 2,
+    /// otherwise it is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative.
+    fn log2(&self) -> Decimal;
+
+    /// The base 2 logarithm for a Decimal, returning `None` if self is zero or negative.
+    fn checked_log2(&self) -> Option<Decimal>;
+
+    /// The base 2 logarithm for a Decimal rounded to `dp` decimal places using "Bankers
+    /// Rounding". The result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative.
+    fn log2_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The logarithm of a Decimal with respect to an arbitrary base. The result is exact if self
+    /// is an exact integral power of the base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative, or if the base is zero, negative or one.
+    fn log(&self, base: Decimal) -> Decimal;
+
+    /// The logarithm of a Decimal with respect to an arbitrary base, returning `None` if self is
+    /// zero or negative, or if the base is zero, negative or one.
+    fn checked_log(&self, base: Decimal) -> Option<Decimal>;
+
+    /// The logarithm of a Decimal with respect to an arbitrary base, rounded to `dp` decimal
+    /// places using "Bankers Rounding". The result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero or negative, or if the base is zero, negative or one.
+    fn log_with_precision(&self, base: Decimal, dp: u32) -> Decimal;
+
+    /// The error function. Uses the Taylor series for arguments below 3, and the continued
+    /// fraction for the complementary error function beyond that, both with eight guard digits so
+    /// that the result is within one unit in the last place.
     fn erf(&self) -> Decimal;
 
-    /// The Cumulative distribution function for a Normal distribution
+    /// The error function rounded to `dp` decimal places using "Bankers Rounding". The result is
+    /// within one unit in the last place.
+    fn erf_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The Cumulative distribution function for a Normal distribution, accurate to within one
+    /// unit in the last place.
     fn norm_cdf(&self) -> Decimal;
 
-    /// The Probability density function for a Normal distribution
+    /// The Cumulative distribution function for a Normal distribution rounded to `dp` decimal
+    /// places using "Bankers Rounding". The result is within one unit in the last place.
+    fn norm_cdf_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The Probability density function for a Normal distribution, accurate to within one unit in
+    /// the last place.
     fn norm_pdf(&self) -> Decimal;
+
+    /// The Probability density function for a Normal distribution rounded to `dp` decimal places
+    /// using "Bankers Rounding". The result is within one unit in the last place.
+    fn norm_pdf_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The complementary error function, 1 - erf(x), within one unit in the last place.
+    fn erfc(&self) -> Decimal;
+
+    /// The complementary error function rounded to `dp` decimal places using "Bankers Rounding".
+    /// The result is within one unit in the last place.
+    fn erfc_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The inverse error function, found using Newton's method from an initial approximation.
+    /// The result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is not strictly between -1 and 1.
+    fn erf_inv(&self) -> Decimal;
+
+    /// The inverse error function, returning `None` if self is not strictly between -1 and 1.
+    fn checked_erf_inv(&self) -> Option<Decimal>;
+
+    /// The inverse error function rounded to `dp` decimal places using "Bankers Rounding". The
+    /// result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is not strictly between -1 and 1.
+    fn erf_inv_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The inverse of the Cumulative distribution function for a Normal distribution, also known
+    /// as the quantile or probit function. The result is within one unit in the last place, even
+    /// for probabilities very close to 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is not strictly between 0 and 1.
+    fn norm_inv_cdf(&self) -> Decimal;
+
+    /// The inverse of the Cumulative distribution function for a Normal distribution, returning
+    /// `None` if self is not strictly between 0 and 1.
+    fn checked_norm_inv_cdf(&self) -> Option<Decimal>;
+
+    /// The inverse of the Cumulative distribution function for a Normal distribution rounded to
+    /// `dp` decimal places using "Bankers Rounding". The result is within one unit in the last
+    /// place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is not strictly between 0 and 1.
+    fn norm_inv_cdf_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The sine of `self`, where `self` is in radians. The argument is reduced to within π/4 of
+    /// a multiple of π/2, using π to 72 decimal places so that even the largest arguments keep
+    /// their precision, before calculating the Taylor series with eight guard digits. The result
+    /// is within one unit in the last place.
+    fn sin(&self) -> Decimal;
+
+    /// The sine of `self` rounded to `dp` decimal places using "Bankers Rounding". The result is
+    /// within one unit in the last place.
+    fn sin_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The cosine of `self`, where `self` is in radians. The argument is reduced in the same way
+    /// as for [`sin`](MathematicalOps::sin), and the result is within one unit in the last place.
+    fn cos(&self) -> Decimal;
+
+    /// The cosine of `self` rounded to `dp` decimal places using "Bankers Rounding". The result
+    /// is within one unit in the last place.
+    fn cos_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The tangent of `self`, where `self` is in radians. Calculated as the sine divided by the
+    /// cosine with eight guard digits, keeping the relative precision of the reduced angle close
+    /// to an odd multiple of π/2, so the result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cosine of `self` evaluates to zero or the result overflows.
+    fn tan(&self) -> Decimal;
+
+    /// The tangent of `self` rounded to `dp` decimal places using "Bankers Rounding".
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cosine of `self` evaluates to zero or the result overflows.
+    fn tan_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The tangent of `self`, where `self` is in radians, returning `None` if the cosine of `self`
+    /// evaluates to zero or the result overflows.
+    fn checked_tan(&self) -> Option<Decimal>;
+
+    /// The arcsine of `self` in radians, within the range [-π/2, π/2]. Calculated as
+    /// atan(x / sqrt(1 - x<sup>2</sup>)) with eight guard digits, using the half angle formula
+    /// close to ±1 so that the square root keeps its precision. The result is within one unit in
+    /// the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside of the range [-1, 1].
+    fn asin(&self) -> Decimal;
+
+    /// The arcsine of `self` in radians, within the range [-π/2, π/2], returning `None` if `self`
+    /// is outside of the range [-1, 1].
+    fn checked_asin(&self) -> Option<Decimal>;
+
+    /// The arcsine of `self` rounded to `dp` decimal places using "Bankers Rounding". The result
+    /// is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside of the range [-1, 1].
+    fn asin_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The arccosine of `self` in radians, within the range [0, π]. Calculated in the same way
+    /// as [`asin`](MathematicalOps::asin), and the result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside of the range [-1, 1].
+    fn acos(&self) -> Decimal;
+
+    /// The arccosine of `self` in radians, within the range [0, π], returning `None` if `self` is
+    /// outside of the range [-1, 1].
+    fn checked_acos(&self) -> Option<Decimal>;
+
+    /// The arccosine of `self` rounded to `dp` decimal places using "Bankers Rounding". The
+    /// result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is outside of the range [-1, 1].
+    fn acos_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The arctangent of `self` in radians, within the range [-π/2, π/2]. Uses a Taylor series
+    /// with eight guard digits after halving the angle until the argument is small, so the result
+    /// is within one unit in the last place.
+    fn atan(&self) -> Decimal;
+
+    /// The arctangent of `self` rounded to `dp` decimal places using "Bankers Rounding". The
+    /// result is within one unit in the last place.
+    fn atan_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The four quadrant arctangent of `self` (`y`) and `other` (`x`) in radians, within the
+    /// range [-π, π]. If both `self` and `other` are zero then zero is returned. The result is
+    /// within one unit in the last place.
+    fn atan2(&self, other: &Decimal) -> Decimal;
+
+    /// The four quadrant arctangent of `self` (`y`) and `other` (`x`) rounded to `dp` decimal
+    /// places using "Bankers Rounding". The result is within one unit in the last place.
+    fn atan2_with_precision(&self, other: &Decimal, dp: u32) -> Decimal;
+
+    /// The hyperbolic sine, (e<sup>x</sup> - e<sup>-x</sup>) / 2, within one unit in the last
+    /// place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large to represent.
+    fn sinh(&self) -> Decimal;
+
+    /// The hyperbolic sine, returning `None` if the result is too large to represent.
+    fn checked_sinh(&self) -> Option<Decimal>;
+
+    /// The hyperbolic sine rounded to `dp` decimal places using "Bankers Rounding". If the result
+    /// is too large to have `dp` decimal places then as few as possible are discarded. The result
+    /// is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large to represent.
+    fn sinh_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The hyperbolic cosine, (e<sup>x</sup> + e<sup>-x</sup>) / 2, within one unit in the last
+    /// place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large to represent.
+    fn cosh(&self) -> Decimal;
+
+    /// The hyperbolic cosine, returning `None` if the result is too large to represent.
+    fn checked_cosh(&self) -> Option<Decimal>;
+
+    /// The hyperbolic cosine rounded to `dp` decimal places using "Bankers Rounding". If the
+    /// result is too large to have `dp` decimal places then as few as possible are discarded. The
+    /// result is within one unit in the last place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is too large to represent.
+    fn cosh_with_precision(&self, dp: u32) -> Decimal;
+
+    /// The hyperbolic tangent, sinh(x) / cosh(x), within one unit in the last place.
+    fn tanh(&self) -> Decimal;
+
+    /// The hyperbolic tangent rounded to `dp` decimal places using "Bankers Rounding". The result
+    /// is within one unit in the last place.
+    fn tanh_with_precision(&self, dp: u32) -> Decimal;
 }
 
 impl MathematicalOps for Decimal {
-    /// The estimated exponential function, e<sup>x</sup>, rounded to 8 decimal places. Stops
-    /// calculating when it is within tolerance of roughly 0.000002 in order to prevent
-    /// multiplication overflow.
     fn exp(&self) -> Decimal {
-        self.exp_with_tolerance(EXP_TOLERANCE)
+        self.exp_with_precision(MAX_PRECISION)
+    }
+
+    fn checked_exp(&self) -> Option<Decimal> {
+        exp_rounded(self, MAX_PRECISION)
+    }
+
+    fn exp_with_precision(&self, dp: u32) -> Decimal {
+        match exp_rounded(self, dp) {
+            Some(result) => result,
+            None => panic!("Exponent overflowed"),
+        }
     }
 
     /// The estimated exponential function, e<sup>x</sup>, rounded to 8 decimal places. Stops
@@ -82,49 +512,92 @@ impl MathematicalOps for Decimal {
         result
     }
 
-    /// Raise self to the given unsigned integer exponent: x<sup>y</sup>
-    fn powi(&self, exp: u64) -> Decimal {
+    fn powi(&self, exp: i64) -> Decimal {
         match self.checked_powi(exp) {
             Some(result) => result,
             None => panic!("Pow overflowed"),
         }
     }
 
-    fn checked_powi(&self, exp: u64) -> Option<Decimal> {
-        match exp {
-            0 => Some(Decimal::ONE),
-            1 => Some(*self),
-            2 => self.checked_mul(*self),
-            _ => {
-                // Get the squared value
-                let squared = match self.checked_mul(*self) {
-                    Some(s) => s,
-                    None => return None,
-                };
-                // Square self once and make an infinite sized iterator of the square.
-                let iter = core::iter::repeat(squared);
-
-                // We then take half of the exponent to create a finite iterator and then multiply those together.
-                let mut product = Decimal::ONE;
-                for x in iter.take((exp >> 1) as usize) {
-                    match product.checked_mul(x) {
-                        Some(r) => product = r,
-                        None => return None,
-                    };
-                }
-
-                // If the exponent is odd we still need to multiply once more
-                if exp & 0x1 > 0 {
-                    self.checked_mul(product)
-                } else {
-                    Some(product)
-                }
-            }
+    fn checked_powi(&self, exp: i64) -> Option<Decimal> {
+        powi_rounded(self, exp, MAX_PRECISION)
+    }
+
+    fn saturating_powi(&self, exp: i64) -> Decimal {
+        if exp >= 0 {
+            return self.saturating_powu(exp as u64);
+        }
+        match self.checked_powi(exp) {
+            Some(result) => result,
+            // A negative number raised to an odd power remains negative
+            None if self.is_sign_negative() && exp & 0x1 > 0 => Decimal::MIN,
+            None => Decimal::MAX,
+        }
+    }
+
+    /// Raise self to the given unsigned integer exponent: x<sup>y</sup>
+    fn powu(&self, exp: u64) -> Decimal {
+        match self.checked_powu(exp) {
+            Some(result) => result,
+            None => panic!("Pow overflowed"),
+        }
+    }
+
+    fn checked_powu(&self, exp: u64) -> Option<Decimal> {
+        if let Some(result) = exact_powu(self, exp) {
+            return Some(result);
         }
+        pow_fixed(
+            self,
+            &Fixed::from_decimal(&Decimal::from(exp)),
+            exp & 1 == 1,
+            MAX_PRECISION,
+        )
+    }
+
+    fn saturating_powu(&self, exp: u64) -> Decimal {
+        match self.checked_powu(exp) {
+            Some(result) => result,
+            // A negative number raised to an odd power remains negative
+            None if self.is_sign_negative() && exp & 0x1 > 0 => Decimal::MIN,
+            None => Decimal::MAX,
+        }
+    }
+
+    fn powd(&self, exp: Decimal) -> Decimal {
+        match self.checked_powd(exp) {
+            Some(result) => result,
+            None => panic!("Pow overflowed"),
+        }
+    }
+
+    fn checked_powd(&self, exp: Decimal) -> Option<Decimal> {
+        powd_rounded(self, &exp, MAX_PRECISION)
+    }
+
+    fn powd_with_precision(&self, exp: Decimal, dp: u32) -> Decimal {
+        match powd_rounded(self, &exp, dp) {
+            Some(result) => result,
+            None => panic!("Pow overflowed"),
+        }
+    }
+
+    fn powf(&self, exp: f64) -> Decimal {
+        match self.checked_powf(exp) {
+            Some(result) => result,
+            None => panic!("Pow overflowed"),
+        }
+    }
+
+    fn checked_powf(&self, exp: f64) -> Option<Decimal> {
+        self.checked_powd(Decimal::from_f64(exp)?)
     }
 
-    /// The square root of a Decimal. Uses a standard Babylonian method.
     fn sqrt(&self) -> Option<Decimal> {
+        self.sqrt_with_precision(MAX_PRECISION)
+    }
+
+    fn sqrt_with_precision(&self, dp: u32) -> Option<Decimal> {
         if self.is_sign_negative() {
             return None;
         }
@@ -133,100 +606,1060 @@ impl MathematicalOps for Decimal {
             return Some(Decimal::ZERO);
         }
 
-        // Start with an arbitrary number as the first guess
-        let mut result = self / TWO;
-        // Too small to represent, so we start with self
-        // Future iterations could actually avoid using a decimal altogether and use a buffered
-        // vector, only combining back into a decimal on return
-        if result.is_zero() {
-            result = *self;
+        // A single Newton step doubles the number of correct digits in the estimate, which is more
+        // than enough to fill the guard digits.
+        let x = Fixed::from_decimal(self);
+        let estimate = Fixed::from_decimal(&sqrt_estimate(self));
+        (estimate + x / estimate).div_u32(2).to_decimal(0, dp)
+    }
+
+    fn cbrt(&self) -> Decimal {
+        // Every Decimal has a cube root
+        self.checked_nth_root(3).unwrap()
+    }
+
+    fn nth_root(&self, n: u32) -> Decimal {
+        self.nth_root_with_precision(n, MAX_PRECISION)
+    }
+
+    fn checked_nth_root(&self, n: u32) -> Option<Decimal> {
+        nth_root_rounded(self, n, MAX_PRECISION)
+    }
+
+    fn nth_root_with_precision(&self, n: u32, dp: u32) -> Decimal {
+        if n == 0 {
+            panic!("The zeroth root is undefined");
         }
-        let mut last = result + Decimal::ONE;
+        match nth_root_rounded(self, n, dp) {
+            Some(result) => result,
+            None => panic!("Even roots are only defined for non-negative values"),
+        }
+    }
 
-        // Keep going while the difference is larger than the tolerance
-        let mut circuit_breaker = 0;
-        while last != result {
-            circuit_breaker += 1;
-            assert!(circuit_breaker < 1000, "geo mean circuit breaker");
+    fn ln(&self) -> Decimal {
+        self.ln_with_precision(MAX_PRECISION)
+    }
 
-            last = result;
-            result = (result + self / result) / TWO;
+    fn checked_ln(&self) -> Option<Decimal> {
+        if self.is_sign_negative() || self.is_zero() {
+            return None;
         }
+        ln_fixed(self).to_decimal(0, MAX_PRECISION)
+    }
 
-        Some(result)
+    fn ln_with_precision(&self, dp: u32) -> Decimal {
+        if self.is_sign_negative() || self.is_zero() {
+            panic!("Logarithm is only defined for positive values");
+        }
+        // The logarithm of any Decimal is well within range
+        ln_fixed(self).to_decimal(0, dp).unwrap()
     }
 
-    /// The natural logarithm for a Decimal. Uses a [fast estimation algorithm](https://en.wikipedia.org/wiki/Natural_logarithm#High_precision)
-    /// This is more accurate on larger numbers and less on numbers less than 1.
-    fn ln(&self) -> Decimal {
-        if self.is_sign_positive() {
-            if self == &Decimal::ONE {
+    fn log10(&self) -> Decimal {
+        match self.checked_log10() {
+            Some(result) => result,
+            None => panic!("Logarithm is only defined for positive values"),
+        }
+    }
+
+    fn checked_log10(&self) -> Option<Decimal> {
+        if self.is_sign_negative() || self.is_zero() {
+            return None;
+        }
+        log_series(self, &TEN, &LN10_FIXED, MAX_PRECISION)
+    }
+
+    fn log10_with_precision(&self, dp: u32) -> Decimal {
+        if self.is_sign_negative() || self.is_zero() {
+            panic!("Logarithm is only defined for positive values");
+        }
+        // The base 10 logarithm of any Decimal is well within range
+        log_series(self, &TEN, &LN10_FIXED, dp).unwrap()
+    }
+
+    fn log2(&self) -> Decimal {
+        match self.checked_log2() {
+            Some(result) => result,
+            None => panic!("Logarithm is only defined for positive values"),
+        }
+    }
+
+    fn checked_log2(&self) -> Option<Decimal> {
+        if self.is_sign_negative() || self.is_zero() {
+            return None;
+        }
+        log_series(self, &TWO, &ln_fixed(&TWO), MAX_PRECISION)
+    }
+
+    fn log2_with_precision(&self, dp: u32) -> Decimal {
+        if self.is_sign_negative() || self.is_zero() {
+            panic!("Logarithm is only defined for positive values");
+        }
+        // The base 2 logarithm of any Decimal is well within range
+        log_series(self, &TWO, &ln_fixed(&TWO), dp).unwrap()
+    }
+
+    fn log(&self, base: Decimal) -> Decimal {
+        self.log_with_precision(base, MAX_PRECISION)
+    }
+
+    fn checked_log(&self, base: Decimal) -> Option<Decimal> {
+        log_rounded(self, &base, MAX_PRECISION)
+    }
+
+    fn log_with_precision(&self, base: Decimal, dp: u32) -> Decimal {
+        match log_rounded(self, &base, dp) {
+            Some(result) => result,
+            None => panic!("Logarithm is only defined for positive values and bases other than one"),
+        }
+    }
+
+    fn erf(&self) -> Decimal {
+        self.erf_with_precision(MAX_PRECISION)
+    }
+
+    fn erf_with_precision(&self, dp: u32) -> Decimal {
+        // The error function is always between -1 and 1
+        erf_fixed(&Fixed::from_decimal(self)).to_decimal(0, dp).unwrap()
+    }
+
+    fn norm_cdf(&self) -> Decimal {
+        self.norm_cdf_with_precision(MAX_PRECISION)
+    }
+
+    fn norm_cdf_with_precision(&self, dp: u32) -> Decimal {
+        let erf = erf_fixed(&(Fixed::from_decimal(self) * FRAC_1_SQRT_2));
+        (Fixed::ONE + erf).div_u32(2).to_decimal(0, dp).unwrap()
+    }
+
+    fn norm_pdf(&self) -> Decimal {
+        self.norm_pdf_with_precision(MAX_PRECISION)
+    }
+
+    fn norm_pdf_with_precision(&self, dp: u32) -> Decimal {
+        // The density rounds to zero long before the square could overflow
+        const LIMIT: Decimal = Decimal::from_parts(12, 0, 0, false, 0);
+        if self.abs() > LIMIT {
+            return Decimal::ZERO;
+        }
+        let x = Fixed::from_decimal(self);
+        let (mantissa, exponent) = exp_fixed(&-(x * x).div_u32(2));
+        (mantissa * FRAC_1_SQRT_2PI).to_decimal(exponent, dp).unwrap()
+    }
+
+    fn erfc(&self) -> Decimal {
+        self.erfc_with_precision(MAX_PRECISION)
+    }
+
+    fn erfc_with_precision(&self, dp: u32) -> Decimal {
+        // The complementary error function is always between 0 and 2
+        (Fixed::ONE - erf_fixed(&Fixed::from_decimal(self)))
+            .to_decimal(0, dp)
+            .unwrap()
+    }
+
+    fn erf_inv(&self) -> Decimal {
+        self.erf_inv_with_precision(MAX_PRECISION)
+    }
+
+    fn checked_erf_inv(&self) -> Option<Decimal> {
+        if self.abs() >= Decimal::ONE {
+            return None;
+        }
+        Some(self.erf_inv_with_precision(MAX_PRECISION))
+    }
+
+    fn erf_inv_with_precision(&self, dp: u32) -> Decimal {
+        if self.abs() >= Decimal::ONE {
+            panic!("Inverse error function is only defined for values between -1 and 1");
+        }
+        // erf_inv(x) = erfc_inv(1 - x), and 1 - |x| is exact so no precision is lost in the tails
+        let result = erfc_inv(&(Decimal::ONE - self.abs()));
+        let result = if self.is_sign_negative() { -result } else { result };
+        // The inverse error function of any Decimal is well within range
+        result.to_decimal(0, dp).unwrap()
+    }
+
+    fn norm_inv_cdf(&self) -> Decimal {
+        self.norm_inv_cdf_with_precision(MAX_PRECISION)
+    }
+
+    fn checked_norm_inv_cdf(&self) -> Option<Decimal> {
+        if self.is_sign_negative() || self.is_zero() || *self >= Decimal::ONE {
+            return None;
+        }
+        Some(self.norm_inv_cdf_with_precision(MAX_PRECISION))
+    }
+
+    fn norm_inv_cdf_with_precision(&self, dp: u32) -> Decimal {
+        if self.is_sign_negative() || self.is_zero() || *self >= Decimal::ONE {
+            panic!("Inverse normal distribution function is only defined for values between 0 and 1");
+        }
+        // norm_inv_cdf(p) = -sqrt(2) * erfc_inv(2p), using the tail closest to p since both 2p
+        // and 2(1 - p) are exact.
+        let result = if *self > Decimal::new(5, 1) {
+            SQRT_2 * erfc_inv(&((Decimal::ONE - self) * TWO))
+        } else {
+            -(SQRT_2 * erfc_inv(&(self * TWO)))
+        };
+        // The quantile of any Decimal is well within range
+        result.to_decimal(0, dp).unwrap()
+    }
+
+    fn sin(&self) -> Decimal {
+        self.sin_with_precision(MAX_PRECISION)
+    }
+
+    fn sin_with_precision(&self, dp: u32) -> Decimal {
+        // The sine is always between -1 and 1
+        sin_cos(self).0.to_decimal(0, dp).unwrap()
+    }
+
+    fn cos(&self) -> Decimal {
+        self.cos_with_precision(MAX_PRECISION)
+    }
+
+    fn cos_with_precision(&self, dp: u32) -> Decimal {
+        // The cosine is always between -1 and 1
+        sin_cos(self).1.to_decimal(0, dp).unwrap()
+    }
+
+    fn tan(&self) -> Decimal {
+        self.tan_with_precision(MAX_PRECISION)
+    }
+
+    fn tan_with_precision(&self, dp: u32) -> Decimal {
+        match tan_rounded(self, dp) {
+            Some(result) => result,
+            None => panic!("Tangent overflowed"),
+        }
+    }
+
+    fn checked_tan(&self) -> Option<Decimal> {
+        tan_rounded(self, MAX_PRECISION)
+    }
+
+    fn asin(&self) -> Decimal {
+        self.asin_with_precision(MAX_PRECISION)
+    }
+
+    fn checked_asin(&self) -> Option<Decimal> {
+        if self.abs() > Decimal::ONE {
+            return None;
+        }
+        Some(self.asin_with_precision(MAX_PRECISION))
+    }
+
+    fn asin_with_precision(&self, dp: u32) -> Decimal {
+        if self.abs() > Decimal::ONE {
+            panic!("Arcsine is only defined for values between -1 and 1");
+        }
+        // The arcsine is always between -π/2 and π/2
+        asin_acos(self).0.to_decimal(0, dp).unwrap()
+    }
+
+    fn acos(&self) -> Decimal {
+        self.acos_with_precision(MAX_PRECISION)
+    }
+
+    fn checked_acos(&self) -> Option<Decimal> {
+        if self.abs() > Decimal::ONE {
+            return None;
+        }
+        Some(self.acos_with_precision(MAX_PRECISION))
+    }
+
+    fn acos_with_precision(&self, dp: u32) -> Decimal {
+        if self.abs() > Decimal::ONE {
+            panic!("Arccosine is only defined for values between -1 and 1");
+        }
+        // The arccosine is always between 0 and π
+        asin_acos(self).1.to_decimal(0, dp).unwrap()
+    }
+
+    fn atan(&self) -> Decimal {
+        self.atan_with_precision(MAX_PRECISION)
+    }
+
+    fn atan_with_precision(&self, dp: u32) -> Decimal {
+        let x = Fixed::from_decimal(self);
+        let result = if self.abs() <= Decimal::ONE {
+            atan_series(&x)
+        } else if self.is_sign_negative() {
+            // atan(x) = ±π/2 - atan(1/x)
+            -FRAC_PI_2_HIGH - atan_series(&(Fixed::ONE / x))
+        } else {
+            FRAC_PI_2_HIGH - atan_series(&(Fixed::ONE / x))
+        };
+        // The arctangent is always between -π/2 and π/2
+        result.to_decimal(0, dp).unwrap()
+    }
+
+    fn sinh(&self) -> Decimal {
+        match self.checked_sinh() {
+            Some(result) => result,
+            None => panic!("Hyperbolic sine overflowed"),
+        }
+    }
+
+    fn checked_sinh(&self) -> Option<Decimal> {
+        sinh_rounded(self, MAX_PRECISION)
+    }
+
+    fn sinh_with_precision(&self, dp: u32) -> Decimal {
+        match sinh_rounded(self, dp) {
+            Some(result) => result,
+            None => panic!("Hyperbolic sine overflowed"),
+        }
+    }
+
+    fn cosh(&self) -> Decimal {
+        match self.checked_cosh() {
+            Some(result) => result,
+            None => panic!("Hyperbolic cosine overflowed"),
+        }
+    }
+
+    fn checked_cosh(&self) -> Option<Decimal> {
+        cosh_rounded(self, MAX_PRECISION)
+    }
+
+    fn cosh_with_precision(&self, dp: u32) -> Decimal {
+        match cosh_rounded(self, dp) {
+            Some(result) => result,
+            None => panic!("Hyperbolic cosine overflowed"),
+        }
+    }
+
+    fn tanh(&self) -> Decimal {
+        self.tanh_with_precision(MAX_PRECISION)
+    }
+
+    fn tanh_with_precision(&self, dp: u32) -> Decimal {
+        // tanh(x) is within 1e-38 of 1 beyond this
+        const LIMIT: Decimal = Decimal::from_parts(45, 0, 0, false, 0);
+        let result = if self.abs() > LIMIT {
+            Decimal::ONE
+        } else {
+            let (exp, inverse) = exp_pair(&self.abs()).unwrap();
+            ((exp - inverse) / (exp + inverse)).to_decimal(0, dp).unwrap()
+        };
+        if self.is_sign_negative() {
+            -result
+        } else {
+            result
+        }
+    }
+
+    fn atan2(&self, other: &Decimal) -> Decimal {
+        self.atan2_with_precision(other, MAX_PRECISION)
+    }
+
+    fn atan2_with_precision(&self, other: &Decimal, dp: u32) -> Decimal {
+        if other.is_zero() {
+            return if self.is_zero() {
                 Decimal::ZERO
+            } else if self.is_sign_negative() {
+                (-FRAC_PI_2_HIGH).to_decimal(0, dp).unwrap()
             } else {
-                // TODO: We could just shift left self by 8 here
-                let s = self * Decimal::new(256, 0);
-                let arith_geo_mean = arithmetic_geo_mean_of_2(&Decimal::ONE, &(Decimal::new(4, 0) / s));
+                FRAC_PI_2_HIGH.to_decimal(0, dp).unwrap()
+            };
+        }
 
-                // TODO: Multiplication by two could be bit shifted too
-                PI / (arith_geo_mean * TWO) - (Decimal::new(8, 0) * LN2)
-            }
+        // Divide the smaller magnitude by the larger so that the ratio is within [-1, 1]
+        let (y, x) = (Fixed::from_decimal(self), Fixed::from_decimal(other));
+        let angle = if self.abs() <= other.abs() {
+            atan_series(&(y / x))
         } else {
-            Decimal::ZERO
+            let half_pi = if self.is_sign_negative() == other.is_sign_negative() {
+                FRAC_PI_2_HIGH
+            } else {
+                -FRAC_PI_2_HIGH
+            };
+            half_pi - atan_series(&(x / y))
+        };
+
+        let angle = if other.is_sign_positive() {
+            angle
+        } else if self.is_sign_negative() && !self.is_zero() {
+            angle - PI_FIXED
+        } else {
+            angle + PI_FIXED
+        };
+        // The angle is always between -π and π
+        angle.to_decimal(0, dp).unwrap()
+    }
+}
+
+macro_rules! impl_pow {
+    ($exp:ty, $method:ident) => {
+        impl Pow<$exp> for Decimal {
+            type Output = Decimal;
+
+            fn pow(self, exp: $exp) -> Decimal {
+                self.$method(exp)
+            }
         }
+
+        impl Pow<$exp> for &Decimal {
+            type Output = Decimal;
+
+            fn pow(self, exp: $exp) -> Decimal {
+                self.$method(exp)
+            }
+        }
+    };
+}
+
+impl_pow!(u64, powu);
+impl_pow!(i64, powi);
+impl_pow!(Decimal, powd);
+impl_pow!(f64, powf);
+
+/// The natural logarithm of a positive number. The number is scaled by a power of 10 to lie
+/// between 0.3 and 3, and then the logarithm is calculated using the series
+/// ln(x) = 2 * (z + z^3 / 3 + z^5 / 5 + ...) where z = (x - 1) / (x + 1). Used internally.
+fn ln_fixed(x: &Decimal) -> Fixed {
+    let upper = Fixed::from_i64(3);
+    let lower = upper.div_u32(10);
+
+    // Only the position of the decimal point changes, and a Decimal never has more than 29 digits,
+    // so the scaling is exact
+    let mut x = Fixed::from_decimal(x);
+    let mut exponent = 0i64;
+    while x >= upper {
+        x = x.div_pow10(1);
+        exponent += 1;
+    }
+    while x < lower {
+        x = x.mul_pow10(1);
+        exponent -= 1;
     }
 
-    /// Abramowitz Approximation of Error Function from [wikipedia](https://en.wikipedia.org/wiki/Error_function#Numerical_approximations)
-    fn erf(&self) -> Decimal {
-        if self.is_sign_positive() {
-            let one = &Decimal::ONE;
-
-            let xa1 = self * Decimal::from_parts(705230784, 0, 0, false, 10);
-            let xa2 = self.powi(2) * Decimal::from_parts(422820123, 0, 0, false, 10);
-            let xa3 = self.powi(3) * Decimal::from_parts(92705272, 0, 0, false, 10);
-            let xa4 = self.powi(4) * Decimal::from_parts(1520143, 0, 0, false, 10);
-            let xa5 = self.powi(5) * Decimal::from_parts(2765672, 0, 0, false, 10);
-            let xa6 = self.powi(6) * Decimal::from_parts(430638, 0, 0, false, 10);
-
-            let sum = one + xa1 + xa2 + xa3 + xa4 + xa5 + xa6;
-            one - (one / sum.powi(16))
+    let z = (x - Fixed::ONE) / (x + Fixed::ONE);
+    let z2 = z * z;
+    let mut power = z;
+    let mut result = z;
+    let mut n = 1;
+    loop {
+        power = power * z2;
+        let term = power.div_u32(2 * n + 1);
+        if term.is_zero() {
+            break;
+        }
+        result = result + term;
+        n += 1;
+    }
+    result + result + LN10_FIXED * Fixed::from_i64(exponent)
+}
+
+/// y ln(x) for a positive x. When x is within 0.01 of one the logarithm is calculated from
+/// d = x - 1, which is exact, using ln(1 + d) = d * (1 - d / 2 + d^2 / 3 - ...). This keeps the
+/// relative precision of the logarithm so that a large y doesn't magnify its rounding error.
+/// Elsewhere y can't be larger than about 7,000 without the power overflowing. Used internally.
+fn ln_mul(x: &Decimal, y: &Fixed) -> Fixed {
+    const NEAR_ONE: Decimal = Decimal::from_parts(1, 0, 0, false, 2);
+    let d = x - Decimal::ONE;
+    if d.abs() >= NEAR_ONE {
+        return *y * ln_fixed(x);
+    }
+
+    let d = Fixed::from_decimal(&d);
+    let mut power = Fixed::ONE;
+    let mut series = Fixed::ONE;
+    let mut n = 2;
+    loop {
+        power = -(power * d);
+        let term = power.div_u32(n);
+        if term.is_zero() {
+            break;
+        }
+        series = series + term;
+        n += 1;
+    }
+    (*y * d) * series
+}
+
+/// x<sup>y</sup> = e<sup>y ln(x)</sup> for a non-zero x, where a negative x is only valid for an
+/// integral y. If y is odd then the sign of x is kept. Returns `None` on overflow. Used
+/// internally.
+fn pow_fixed(x: &Decimal, y: &Fixed, odd: bool, dp: u32) -> Option<Decimal> {
+    // e^70 exceeds Decimal::MAX and e^-70 rounds to zero, so this also keeps the argument well
+    // within the range of a fixed point number.
+    let limit = Fixed::from_i64(70);
+    let product = ln_mul(&x.abs(), y);
+    if product > limit {
+        return None;
+    }
+    if product < -limit {
+        return Some(Decimal::ZERO);
+    }
+    let (mantissa, exponent) = exp_fixed(&product);
+    let result = mantissa.to_decimal(exponent, dp)?;
+    if x.is_sign_negative() && odd {
+        Some(-result)
+    } else {
+        Some(result)
+    }
+}
+
+/// x<sup>y</sup> for an integral y rounded to `dp` decimal places, returning `None` on overflow or
+/// if x is zero and y is negative. Used internally.
+fn powi_rounded(x: &Decimal, exp: i64, dp: u32) -> Option<Decimal> {
+    if exp >= 0 {
+        if let Some(result) = exact_powu(x, exp as u64) {
+            return Some(result.round_dp(dp));
+        }
+    } else {
+        if x.is_zero() {
+            return None;
+        }
+        // Wrapping negation gives the correct magnitude for i64::MIN once cast to u64. The
+        // reciprocal of an exact power only needs to be rounded once by the division.
+        if let Some(denominator) = exact_powu(x, exp.wrapping_neg() as u64) {
+            return Decimal::ONE.checked_div_with_strategy(denominator, dp, RoundingStrategy::MidpointNearestEven);
+        }
+    }
+    pow_fixed(x, &Fixed::from_i64(exp), exp & 1 == 1, dp)
+}
+
+/// x<sup>y</sup> for a Decimal y rounded to `dp` decimal places, returning `None` on overflow, if
+/// x is zero and y is negative, or if x is negative and y is not an integer. Used internally.
+fn powd_rounded(x: &Decimal, exp: &Decimal, dp: u32) -> Option<Decimal> {
+    let integral = exp.fract().is_zero();
+    if integral {
+        if let Some(exp) = exp.to_i64() {
+            return powi_rounded(x, exp, dp);
+        }
+    }
+    if x.is_zero() {
+        return if exp.is_sign_positive() {
+            Some(Decimal::ZERO)
         } else {
-            -self.abs().erf()
+            None
+        };
+    }
+    if x.is_sign_negative() && !integral {
+        return None;
+    }
+    let result = pow_fixed(x, &Fixed::from_decimal(exp), !(exp % TWO).is_zero(), dp)?;
+    // The guard digits are rounded away when the power is exact, leaving only trailing zeros
+    let normalized = result.normalize();
+    if is_exact_rational_power(x, &normalized, exp) {
+        Some(normalized)
+    } else {
+        Some(result)
+    }
+}
+
+/// The n-th root rounded to `dp` decimal places, returning `None` if `n` is zero, or if x is
+/// negative and `n` is even. Used internally.
+fn nth_root_rounded(x: &Decimal, n: u32, dp: u32) -> Option<Decimal> {
+    match n {
+        0 => return None,
+        1 => return Some(x.round_dp(dp)),
+        _ => {}
+    }
+    if x.is_sign_negative() && n & 1 == 0 {
+        return None;
+    }
+    if x.is_zero() {
+        return Some(Decimal::ZERO);
+    }
+    if n == 2 {
+        return x.sqrt_with_precision(dp);
+    }
+
+    // The root always lies between one and the Decimal itself, so it can't overflow
+    let abs = x.abs();
+    let (mantissa, exponent) = exp_fixed(&ln_fixed(&abs).div_u32(n));
+    let mut result = mantissa.to_decimal(exponent, dp)?;
+    // The guard digits are rounded away when the root is exact, leaving only trailing zeros
+    let normalized = result.normalize();
+    if is_exact_power(&abs, &normalized, &Decimal::from(n)) {
+        result = normalized;
+    }
+    if x.is_sign_negative() {
+        result.set_sign_negative(true);
+    }
+    Some(result)
+}
+
+/// Raises x to an unsigned integral power by repeated squaring, returning `None` unless every
+/// multiplication is exact. Since the number of digits grows with each squaring, an inexact power
+/// is detected within a handful of steps. Used internally.
+fn exact_powu(x: &Decimal, exp: u64) -> Option<Decimal> {
+    let mut base = *x;
+    let mut exp = exp;
+    let mut result = Decimal::ONE;
+    loop {
+        if exp & 1 == 1 {
+            result = result.checked_mul_exact(base).ok()?;
         }
+        exp >>= 1;
+        if exp == 0 {
+            return Some(result);
+        }
+        base = base.checked_mul_exact(base).ok()?;
     }
+}
 
-    /// The Cumulative distribution function for a Normal distribution
-    fn norm_cdf(&self) -> Decimal {
-        (Decimal::ONE + (self / Decimal::from_parts(2318911239, 3292722, 0, false, 16)).erf()) / TWO
+/// The logarithm of a positive number with respect to `base`, given the natural logarithm of the
+/// base. If the result is close to an integer then it is checked against the corresponding power
+/// of the base so that exact powers give an exact result. Returns `None` if the result is too
+/// large to represent, which happens when the base is very close to one. Used internally.
+fn log_series(x: &Decimal, base: &Decimal, ln_base: &Fixed, dp: u32) -> Option<Decimal> {
+    let result = (ln_fixed(x) / *ln_base).to_decimal(0, dp)?;
+    let rounded = result.round();
+    if is_exact_power(x, base, &rounded) {
+        Some(rounded)
+    } else {
+        Some(result)
     }
+}
 
-    /// The Probability density function for a Normal distribution
-    fn norm_pdf(&self) -> Decimal {
-        let sqrt2pi = Decimal::from_parts_raw(2133383024, 2079885984, 1358845910, 1835008);
-        (-self.powi(2) / TWO).exp() / sqrt2pi
+/// The logarithm with respect to an arbitrary base rounded to `dp` decimal places, returning
+/// `None` if x is zero or negative, if the base is zero, negative or one, or if the result is too
+/// large to represent. Used internally.
+fn log_rounded(x: &Decimal, base: &Decimal, dp: u32) -> Option<Decimal> {
+    if x.is_sign_negative() || x.is_zero() || base.is_sign_negative() || base.is_zero() {
+        return None;
+    }
+    let ln_base = ln_fixed(base);
+    if ln_base.is_zero() {
+        return None;
+    }
+    log_series(x, base, &ln_base, dp)
+}
+
+/// Returns true if `x` is exactly `base` raised to the integral power `exp`. No exact power can
+/// have an exponent larger than 96 in magnitude since the mantissa is 96 bits. Used internally.
+fn is_exact_power(x: &Decimal, base: &Decimal, exp: &Decimal) -> bool {
+    let n = match exp.abs().to_u32() {
+        Some(n) if n <= 96 => n,
+        _ => return false,
+    };
+    let mut power = Decimal::ONE;
+    for _ in 0..n {
+        power = match power.checked_mul_exact(*base) {
+            Ok(power) => power,
+            Err(_) => return false,
+        };
+    }
+    if exp.is_sign_negative() {
+        // x = base^-n exactly when x * base^n = 1 exactly
+        matches!(x.checked_mul_exact(power), Ok(one) if one == Decimal::ONE)
+    } else {
+        power == *x
+    }
+}
+
+/// Returns true if `x` raised to the power `exp` is exactly `result`, which is when
+/// result<sup>b</sup> = x<sup>a</sup> for the exponent in lowest terms a / b. Both terms are at most
+/// 96 for an exact power, for the same reason as `is_exact_power`. Used internally.
+fn is_exact_rational_power(x: &Decimal, result: &Decimal, exp: &Decimal) -> bool {
+    let mut numerator = exp.mantissa().unsigned_abs();
+    let mut denominator = 10u128.pow(exp.scale());
+    let (mut gcd, mut remainder) = (numerator, denominator);
+    while remainder != 0 {
+        (gcd, remainder) = (remainder, gcd % remainder);
+    }
+    numerator /= gcd;
+    denominator /= gcd;
+    if numerator > 96 || denominator > 96 {
+        return false;
+    }
+    let power = match exact_powu(x, numerator as u64) {
+        Some(power) => power,
+        None => return false,
+    };
+    let root = Decimal::from(denominator as u32);
+    is_exact_power(&power, result, &if exp.is_sign_negative() { -root } else { root })
+}
+
+/// The exponential function rounded to `dp` decimal places, returning `None` on overflow. Used
+/// internally.
+fn exp_rounded(x: &Decimal, dp: u32) -> Option<Decimal> {
+    // e^70 exceeds Decimal::MAX and e^-70 rounds to zero, so this also keeps the argument well
+    // within the range of a fixed point number.
+    const LIMIT: Decimal = Decimal::from_parts(70, 0, 0, false, 0);
+    if x > &LIMIT {
+        return None;
+    }
+    if x < &-LIMIT {
+        return Some(Decimal::ZERO);
+    }
+    let (mantissa, exponent) = exp_fixed(&Fixed::from_decimal(x));
+    mantissa.to_decimal(exponent, dp)
+}
+
+/// The exponential function for an argument of moderate size, returned as a mantissa between 0.3
+/// and 3.2 along with the power of 10 it must be multiplied by. The argument is reduced by a
+/// multiple of ln(10) to lie within [-1.16, 1.16] before calculating the Taylor series. Used
+/// internally.
+fn exp_fixed(x: &Fixed) -> (Fixed, i32) {
+    // The multiple only needs to be roughly right, so the rounded argument is good enough here
+    let exponent = (x.to_decimal(0, MAX_PRECISION).unwrap() / LN_10).round();
+    let x = *x - LN10_FIXED * Fixed::from_decimal(&exponent);
+
+    let mut term = Fixed::ONE;
+    let mut result = Fixed::ONE;
+    let mut n = 1;
+    loop {
+        term = (term * x).div_u32(n);
+        if term.is_zero() {
+            break;
+        }
+        result = result + term;
+        n += 1;
+    }
+    (result, exponent.to_i32().unwrap())
+}
+
+/// Both e^x and e^-x for a non-negative argument, returning `None` if e^x is too large for the
+/// hyperbolic functions to represent. Used internally.
+fn exp_pair(x: &Decimal) -> Option<(Fixed, Fixed)> {
+    // Both sinh(x) and cosh(x) exceed Decimal::MAX well before this
+    const LIMIT: Decimal = Decimal::from_parts(70, 0, 0, false, 0);
+    if x > &LIMIT {
+        return None;
+    }
+    let (mantissa, exponent) = exp_fixed(&Fixed::from_decimal(x));
+    let exp = mantissa.mul_pow10(exponent as u32);
+    Some((exp, Fixed::ONE / exp))
+}
+
+/// The hyperbolic sine rounded to `dp` decimal places, returning `None` if the result is too large
+/// to represent. Used internally.
+fn sinh_rounded(x: &Decimal, dp: u32) -> Option<Decimal> {
+    let (exp, inverse) = exp_pair(&x.abs())?;
+    let result = (exp - inverse).div_u32(2).to_decimal(0, dp)?;
+    Some(if x.is_sign_negative() { -result } else { result })
+}
+
+/// The hyperbolic cosine rounded to `dp` decimal places, returning `None` if the result is too
+/// large to represent. Used internally.
+fn cosh_rounded(x: &Decimal, dp: u32) -> Option<Decimal> {
+    let (exp, inverse) = exp_pair(&x.abs())?;
+    (exp + inverse).div_u32(2).to_decimal(0, dp)
+}
+
+/// The error function, using the Taylor series for arguments below 3 and the continued fraction
+/// for the complementary error function otherwise. Used internally.
+fn erf_fixed(x: &Fixed) -> Fixed {
+    let abs = x.abs();
+    let result = if abs < Fixed::from_i64(3) {
+        erf_series(&abs)
+    } else if abs > Fixed::from_i64(9) {
+        // The complementary error function is below the guard digits
+        Fixed::ONE
+    } else {
+        let (erfc, _, exponent) = erfc_scaled(&abs);
+        Fixed::ONE - erfc.div_pow10(-exponent as u32)
+    };
+    if x.is_sign_negative() {
+        -result
+    } else {
+        result
+    }
+}
+
+/// The Taylor series erf(x) = 2 / sqrt(π) * (x - x^3 / 3 + x^5 / 10 - ...) for an argument
+/// within 3 of zero. The terms grow to a few hundred before converging, which costs at most three
+/// of the guard digits. Used internally.
+fn erf_series(x: &Fixed) -> Fixed {
+    let x2 = *x * *x;
+    let mut power = *x;
+    let mut result = *x;
+    let mut n = 1;
+    loop {
+        power = -(power * x2).div_u32(n);
+        let term = power.div_u32(2 * n + 1);
+        if term.is_zero() {
+            break;
+        }
+        result = result + term;
+        n += 1;
+    }
+    result * FRAC_2_SQRT_PI
+}
+
+/// The complementary error function along with e^(-x^2), both scaled up by the power of 10 that
+/// e^(-x^2) is returned with so that tiny values keep their relative precision. For arguments of
+/// at least 3 this uses the continued fraction
+/// erfc(x) = e^(-x^2) / sqrt(π) / (x + (1/2) / (x + 1 / (x + (3/2) / ...))), evaluated from the
+/// bottom up with enough terms to converge beyond the guard digits. Used internally.
+fn erfc_scaled(x: &Fixed) -> (Fixed, Fixed, i32) {
+    let x2 = *x * *x;
+    let (exp, exponent) = exp_fixed(&-x2);
+    if *x < Fixed::from_i64(3) {
+        let erfc = (Fixed::ONE - erf_series(x)).mul_pow10(-exponent as u32);
+        return (erfc, exp, exponent);
+    }
+
+    // The fraction converges more quickly for larger arguments, and 150 terms give a relative
+    // error of around 1e-41 at 3.
+    const TERMS: u32 = 150;
+    let mut fraction = *x;
+    for n in (1..=TERMS).rev() {
+        fraction = *x + Fixed::from_i64(n.into()).div_u32(2) / fraction;
     }
+    (exp * FRAC_1_SQRT_PI / fraction, exp, exponent)
 }
 
-/// Returns the convergence of both the arithmetic and geometric mean.
+/// The inverse of the complementary error function for a value within (0, 1], which is exact so
+/// that the tails keep their precision. Starts from Winitzki's approximation, which is accurate
+/// to about 0.2%, and then applies Newton's method to the scaled complementary error function.
 /// Used internally.
-fn arithmetic_geo_mean_of_2(a: &Decimal, b: &Decimal) -> Decimal {
-    const TOLERANCE: Decimal = Decimal::from_parts(5, 0, 0, false, 7);
-    let diff = (a - b).abs();
+fn erfc_inv(q: &Decimal) -> Fixed {
+    if *q == Decimal::ONE {
+        return Fixed::ZERO;
+    }
+
+    // Close to zero the approximation below cancels out entirely, so use the Maclaurin series
+    // erf_inv(y) = sqrt(π) / 2 * (y + πy^3 / 12 + 7π^2y^5 / 480 + 127π^3y^7 / 40320 + ...) where
+    // y = 1 - q. Below 1e-4 the remaining terms are beneath the guard digits.
+    const SERIES_LIMIT: Decimal = Decimal::from_parts(1, 0, 0, false, 4);
+    let y = Decimal::ONE - q;
+    if y < SERIES_LIMIT {
+        let y = Fixed::from_decimal(&y);
+        let pi_y2 = Fixed::from_decimal(&PI) * y * y;
+        let series = pi_y2.div_u32(40320) * Fixed::from_i64(127);
+        let series = pi_y2 * (series + Fixed::from_i64(7).div_u32(480));
+        let series = pi_y2 * (series + Fixed::ONE.div_u32(12));
+        return y * (series + Fixed::ONE) / FRAC_2_SQRT_PI;
+    }
+
+    // x = sqrt(sqrt(b^2 - ln(1 - y^2) / a) - b) where b = 2 / (πa) + ln(1 - y^2) / 2, y = 1 - q
+    // and a = 0.147
+    const A: Decimal = Decimal::from_parts(147, 0, 0, false, 3);
+    const TWO_OVER_PI_A: Decimal = Decimal::from_parts(433074675, 0, 0, false, 8);
+    let ln = ln_fixed(&(q * (TWO - q))).to_decimal(0, MAX_PRECISION).unwrap();
+    let b = TWO_OVER_PI_A + ln / TWO;
+    let estimate = sqrt_estimate(&(sqrt_estimate(&(b * b - ln / A)) - b));
+
+    // Newton's method converges quadratically, so a handful of steps is plenty
+    let tolerance = Fixed::ONE.div_pow10(32);
+    let mut x = Fixed::from_decimal(&estimate);
+    for _ in 0..8 {
+        let (erfc, exp, exponent) = erfc_scaled(&x);
+        let q = Fixed::from_decimal(q).mul_pow10(-exponent as u32);
+        let step = (erfc - q) / (exp * FRAC_2_SQRT_PI);
+        x = x + step;
+        if step.abs() < tolerance {
+            break;
+        }
+    }
+    x
+}
+
+/// The square root of a positive number using a standard Babylonian method, which is within a
+/// unit or so of the last place. Used internally.
+fn sqrt_estimate(x: &Decimal) -> Decimal {
+    if x.is_zero() {
+        return Decimal::ZERO;
+    }
+    // Start with an arbitrary number as the first guess
+    let mut result = x / TWO;
+    // Too small to represent, so we start with self
+    // Future iterations could actually avoid using a decimal altogether and use a buffered
+    // vector, only combining back into a decimal on return
+    if result.is_zero() {
+        result = *x;
+    }
+    let mut last = result + Decimal::ONE;
+
+    // Keep going while the difference is larger than the tolerance
+    let mut circuit_breaker = 0;
+    while last != result {
+        circuit_breaker += 1;
+        assert!(circuit_breaker < 1000, "geo mean circuit breaker");
+
+        last = result;
+        result = (result + x / result) / TWO;
+    }
+
+    result
+}
+
+/// Reduces an angle to within π/4 of zero, returning the number of quarter turns (modulo 4) that
+/// were removed along with the remaining angle multiplied by 10<sup>exponent</sup>, which is
+/// non-zero when the angle is small so that it keeps its relative precision. The multiple of π/2
+/// is removed in two parts (Cody and Waite's method) so that it is exact to 72 decimal places,
+/// which keeps the full precision of the remaining angle for any `Decimal`. Used internally.
+fn reduce_to_quadrant(x: &Decimal) -> (u32, Fixed, u32) {
+    let angle = Fixed::from_decimal(x);
+    if x.abs() <= FRAC_PI_4 {
+        return (0, angle, 0);
+    }
+    let frac_pi_4 = FRAC_PI_2_HIGH.div_u32(2);
+    let tenth = Fixed::ONE.div_u32(10);
+    let mut quarter_turns = (x / FRAC_PI_2).round();
+    loop {
+        let turns = Fixed::from_decimal(&quarter_turns);
+        let high = angle - turns * FRAC_PI_2_HIGH;
+        let low = turns * FRAC_PI_2_LOW;
+        let reduced = high - low.div_pow10(36);
+
+        // The quotient only has 28 significant digits, so the largest angles can be a turn or so out
+        if reduced > frac_pi_4 {
+            quarter_turns += Decimal::ONE;
+            continue;
+        }
+        if reduced < -frac_pi_4 {
+            quarter_turns -= Decimal::ONE;
+            continue;
+        }
+
+        let quadrant = (quarter_turns % Decimal::from(4)).to_i32().unwrap().rem_euclid(4) as u32;
+        let mut exponent = 0;
+        let mut scaled = reduced;
+        while exponent < 36 && scaled.abs() < tenth {
+            scaled = scaled.mul_pow10(1);
+            exponent += 1;
+        }
+        if exponent == 0 {
+            return (quadrant, reduced, 0);
+        }
+        return (
+            quadrant,
+            high.mul_pow10(exponent) - low.div_pow10(36 - exponent),
+            exponent,
+        );
+    }
+}
 
-    if diff < TOLERANCE {
-        *a
+/// Both the sine and the cosine of an angle, using the Taylor series of whichever suits the
+/// quadrant. Used internally.
+fn sin_cos(x: &Decimal) -> (Fixed, Fixed) {
+    let (quadrant, x, exponent) = reduce_to_quadrant(x);
+    let x = x.div_pow10(exponent);
+    let (sin, cos) = (x * sinc_series(&x), cos_series(&x));
+    match quadrant {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// The tangent rounded to `dp` decimal places, returning `None` if the result overflows. Close to
+/// an odd multiple of π/2 this is the reciprocal of a tiny sine, so the sine is calculated from
+/// the scaled angle to keep its relative precision. Used internally.
+fn tan_rounded(x: &Decimal, dp: u32) -> Option<Decimal> {
+    let (quadrant, x, exponent) = reduce_to_quadrant(x);
+    let reduced = x.div_pow10(exponent);
+    let sin = x * sinc_series(&reduced);
+    let cos = cos_series(&reduced);
+    if quadrant & 1 == 0 {
+        // The cosine is at least 1/sqrt(2)
+        (sin.div_pow10(exponent) / cos).to_decimal(0, dp)
+    } else if sin.is_zero() {
+        None
     } else {
-        arithmetic_geo_mean_of_2(&mean_of_2(a, b), &geo_mean_of_2(a, b))
+        (-(cos / sin)).to_decimal(exponent as i32, dp)
     }
 }
 
-/// The Arithmetic mean. Used internally.
-fn mean_of_2(a: &Decimal, b: &Decimal) -> Decimal {
-    (a + b) / TWO
+/// The Taylor series for sin(x) / x for an angle within π/4 of zero, which is close to one so
+/// that multiplying by the angle keeps its relative precision. Used internally.
+fn sinc_series(x: &Fixed) -> Fixed {
+    let x2 = *x * *x;
+    let mut term = Fixed::ONE;
+    let mut result = Fixed::ONE;
+    let mut n = 1;
+    loop {
+        term = -(term * x2).div_u32((n + 1) * (n + 2));
+        if term.is_zero() {
+            return result;
+        }
+        result = result + term;
+        n += 2;
+    }
 }
 
-/// The geometric mean. Used internally.
-fn geo_mean_of_2(a: &Decimal, b: &Decimal) -> Decimal {
-    (a * b).sqrt().unwrap()
+/// The Taylor series for the cosine of an angle within π/4 of zero. Used internally.
+fn cos_series(x: &Fixed) -> Fixed {
+    let x2 = *x * *x;
+    let mut term = Fixed::ONE;
+    let mut result = Fixed::ONE;
+    let mut n = 0;
+    loop {
+        term = -(term * x2).div_u32((n + 1) * (n + 2));
+        if term.is_zero() {
+            return result;
+        }
+        result = result + term;
+        n += 2;
+    }
+}
+
+/// The arctangent of a value between -1 and 1. The angle is halved using
+/// atan(x) = 2 * atan(x / (1 + sqrt(1 + x^2))) until the Taylor series converges quickly.
+/// Used internally.
+fn atan_series(x: &Fixed) -> Fixed {
+    let threshold = Fixed::ONE.div_u32(10);
+
+    let mut x = *x;
+    let mut doublings = 0;
+    while x.abs() > threshold {
+        x = x / (Fixed::ONE + sqrt_fixed(&(Fixed::ONE + x * x)));
+        doublings += 1;
+    }
+
+    let x2 = x * x;
+    let mut power = x;
+    let mut result = x;
+    let mut n = 1;
+    loop {
+        power = -(power * x2);
+        let term = power.div_u32(2 * n + 1);
+        if term.is_zero() {
+            break;
+        }
+        result = result + term;
+        n += 1;
+    }
+    result * Fixed::from_i64(1 << doublings)
+}
+
+/// The arcsine and the arccosine of a value between -1 and 1. Close to ±1 these are found from
+/// acos(|x|) = 2 * atan(sqrt((1 - |x|) / (1 + |x|))), where 1 - |x| is exact and is scaled by an
+/// even power of 10 so that the square root keeps its precision. Used internally.
+fn asin_acos(x: &Decimal) -> (Fixed, Fixed) {
+    let abs = x.abs();
+    if abs <= Decimal::new(5, 1) {
+        // The ratio is at most 1/sqrt(3)
+        let y = Fixed::from_decimal(x);
+        let asin = atan_series(&(y / sqrt_fixed(&(Fixed::ONE - y * y))));
+        return (asin, FRAC_PI_2_HIGH - asin);
+    }
+
+    let hundredth = Fixed::ONE.div_u32(100);
+    let mut numerator = Fixed::from_decimal(&(Decimal::ONE - abs));
+    let mut exponent = 0;
+    while !numerator.is_zero() && numerator < hundredth {
+        numerator = numerator.mul_pow10(2);
+        exponent += 1;
+    }
+    let ratio = numerator / Fixed::from_decimal(&(Decimal::ONE + abs));
+    let acos = atan_series(&sqrt_fixed(&ratio).div_pow10(exponent)) * Fixed::from_i64(2);
+    let asin = FRAC_PI_2_HIGH - acos;
+    if x.is_sign_negative() {
+        (-asin, PI_FIXED - acos)
+    } else {
+        (asin, acos)
+    }
+}
+
+/// The square root of a non-negative fixed point number that isn't too small, refining the
+/// estimate with a single Newton step to fill the guard digits. Used internally.
+fn sqrt_fixed(x: &Fixed) -> Fixed {
+    if x.is_zero() {
+        return Fixed::ZERO;
+    }
+    let estimate = Fixed::from_decimal(&sqrt_estimate(&x.to_decimal(0, MAX_PRECISION).unwrap()));
+    (estimate + *x / estimate).div_u32(2)
 }
 
 #[cfg(test)]
@@ -236,52 +1669,42 @@ mod test {
     use std::str::FromStr;
 
     #[test]
-    fn test_geo_mean_of_2() {
+    fn test_exp_rounded() {
         let test_cases = &[
-            (
-                Decimal::from_str("2").unwrap(),
-                Decimal::from_str("2").unwrap(),
-                Decimal::from_str("2").unwrap(),
-            ),
-            (
-                Decimal::from_str("4").unwrap(),
-                Decimal::from_str("3").unwrap(),
-                Decimal::from_str("3.4641016151377545870548926830").unwrap(),
-            ),
-            (
-                Decimal::from_str("12").unwrap(),
-                Decimal::from_str("3").unwrap(),
-                Decimal::from_str("6.000000000000000000000000000").unwrap(),
-            ),
+            ("0", 28, Some("1")),
+            ("1", 28, Some("2.7182818284590452353602874714")),
+            ("1", 5, Some("2.71828")),
+            ("-66", 28, Some("0.0000000000000000000000000000")),
+            ("-70.1", 28, Some("0")),
+            // Wolfram Alpha gives e^66.5 = 75959666021073336334634473276.098
+            ("66.5", 28, Some("75959666021073336334634473276")),
+            ("66.5", 2, Some("75959666021073336334634473276")),
+            ("66.6", 28, None),
+            ("70.1", 28, None),
         ];
-
-        for case in test_cases {
-            assert_eq!(case.2, geo_mean_of_2(&case.0, &case.1));
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let result = exp_rounded(&x, dp).map(|r| r.to_string());
+            assert_eq!(expected.map(String::from), result, "exp({})", x);
         }
     }
 
     #[test]
-    fn test_mean_of_2() {
+    fn test_is_exact_power() {
         let test_cases = &[
-            (
-                Decimal::from_str("2").unwrap(),
-                Decimal::from_str("2").unwrap(),
-                Decimal::from_str("2").unwrap(),
-            ),
-            (
-                Decimal::from_str("4").unwrap(),
-                Decimal::from_str("3").unwrap(),
-                Decimal::from_str("3.5").unwrap(),
-            ),
-            (
-                Decimal::from_str("12").unwrap(),
-                Decimal::from_str("3").unwrap(),
-                Decimal::from_str("7.5").unwrap(),
-            ),
+            ("1024", "2", "10", true),
+            ("1023", "2", "10", false),
+            ("0.0009765625", "2", "-10", true),
+            ("0.0000000000000000000000000001", "2", "-93", false),
+            ("0.0000000000000000000000000001", "10", "-28", true),
+            ("79228162514264337593543950335", "2", "96", false),
+            ("1", "3", "0", true),
         ];
-
-        for case in test_cases {
-            assert_eq!(case.2, mean_of_2(&case.0, &case.1));
+        for &(x, base, exp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let base = Decimal::from_str(base).unwrap();
+            let exp = Decimal::from_str(exp).unwrap();
+            assert_eq!(expected, is_exact_power(&x, &base, &exp), "{} = {}^{}", x, base, exp);
         }
     }
 }
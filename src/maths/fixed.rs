@@ -0,0 +1,291 @@
+use crate::decimal::{add_by_internal, add_one_internal, div_by_u32, is_all_zero, MAX_PRECISION};
+use crate::ops::wide::{
+    cmp_slices, div_by_pow10_with_strategy, div_rounded, mul_by_pow10, mul_internal, sub_slices, wide_to_decimal,
+    wide_to_decimal_rounded,
+};
+use crate::{Decimal, RoundingStrategy};
+
+use core::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+// The number of decimal places held by a `Fixed`, eight more than a `Decimal` can hold.
+const SCALE: u32 = 36;
+
+/// A signed fixed point number with 36 decimal places and a 256 bit magnitude, which is able to
+/// hold any `Decimal` exactly. The mathematical functions evaluate their series using these so
+/// that the eight extra digits absorb the rounding error of each step, leaving the result to be
+/// rounded only once when it is converted back into a `Decimal`.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Fixed {
+    bits: [u32; 8],
+    negative: bool,
+}
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed::from_raw([0, 0, 0, 0]);
+    pub const ONE: Fixed = Fixed::from_raw([0, 3008077584, 2076772117, 12621774]);
+
+    /// Creates a positive number from the low 128 bits of its magnitude, which is the value
+    /// multiplied by 10<sup>36</sup>.
+    pub const fn from_raw(low: [u32; 4]) -> Fixed {
+        Fixed {
+            bits: [low[0], low[1], low[2], low[3], 0, 0, 0, 0],
+            negative: false,
+        }
+    }
+
+    pub fn from_decimal(value: &Decimal) -> Fixed {
+        let mut bits = [0u32; 8];
+        bits[..3].copy_from_slice(&value.mantissa_array3());
+        // A Decimal has at most 96 bits, so this always fits
+        mul_by_pow10(&mut bits, SCALE - value.scale());
+        Fixed::new(bits, value.is_sign_negative())
+    }
+
+    pub fn from_i64(value: i64) -> Fixed {
+        Fixed::from_decimal(&Decimal::from(value))
+    }
+
+    fn new(bits: [u32; 8], negative: bool) -> Fixed {
+        Fixed {
+            bits,
+            negative: negative && !is_all_zero(&bits),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        is_all_zero(&self.bits)
+    }
+
+    pub fn is_sign_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> Fixed {
+        Fixed::new(self.bits, false)
+    }
+
+    /// Divides by a small integer, rounding to the nearest representable value.
+    pub fn div_u32(&self, divisor: u32) -> Fixed {
+        let mut bits = self.bits;
+        let remainder = div_by_u32(&mut bits, divisor);
+        if u64::from(remainder) * 2 >= u64::from(divisor) {
+            add_one_internal(&mut bits);
+        }
+        Fixed::new(bits, self.negative)
+    }
+
+    /// Multiplies by 10<sup>power</sup>, which must not overflow.
+    pub fn mul_pow10(&self, power: u32) -> Fixed {
+        let mut bits = self.bits;
+        let overflow = mul_by_pow10(&mut bits, power);
+        debug_assert_eq!(overflow, 0, "Fixed point overflow");
+        Fixed::new(bits, self.negative)
+    }
+
+    /// Divides by 10<sup>power</sup>, rounding to the nearest representable value.
+    pub fn div_pow10(&self, power: u32) -> Fixed {
+        let mut bits = self.bits;
+        div_by_pow10_with_strategy(&mut bits, power, self.negative, RoundingStrategy::MidpointNearestEven);
+        Fixed::new(bits, self.negative)
+    }
+
+    /// Rounds the value multiplied by 10<sup>exponent</sup> to a `Decimal` with at most `dp`
+    /// decimal places using "Bankers Rounding". If the integral portion requires more than
+    /// `28 - dp` digits then as few decimal places as possible are discarded instead. The value is
+    /// only ever rounded once, and exact results have any trailing zeros removed.
+    ///
+    /// Returns `None` if the integral portion is out of range.
+    pub fn to_decimal(self, exponent: i32, dp: u32) -> Option<Decimal> {
+        let mut bits = [0u32; 10];
+        bits[..8].copy_from_slice(&self.bits);
+        let mut scale = if exponent > SCALE as i32 {
+            if mul_by_pow10(&mut bits, exponent as u32 - SCALE) > 0 {
+                return None;
+            }
+            0
+        } else {
+            (SCALE as i32 - exponent) as u32
+        };
+        while scale > 0 {
+            let mut reduced = bits;
+            if div_by_u32(&mut reduced, 10) != 0 {
+                break;
+            }
+            bits = reduced;
+            scale -= 1;
+        }
+
+        let dp = dp.min(MAX_PRECISION);
+        let mut rounded = bits;
+        if scale > dp {
+            div_by_pow10_with_strategy(
+                &mut rounded,
+                scale - dp,
+                self.negative,
+                RoundingStrategy::MidpointNearestEven,
+            );
+        }
+        match wide_to_decimal(&rounded, self.negative && !is_all_zero(&rounded), scale.min(dp)) {
+            Some(result) => Some(result),
+            // There are too many integral digits for `dp` decimal places
+            None => wide_to_decimal_rounded(&bits, self.negative, scale, RoundingStrategy::MidpointNearestEven),
+        }
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, other: Fixed) -> Fixed {
+        if self.negative == other.negative {
+            let mut bits = self.bits;
+            let overflow = add_by_internal(&mut bits, &other.bits);
+            debug_assert_eq!(overflow, 0, "Fixed point overflow");
+            return Fixed::new(bits, self.negative);
+        }
+        if cmp_slices(&self.bits, &other.bits) == Ordering::Less {
+            let mut bits = other.bits;
+            sub_slices(&mut bits, &self.bits);
+            Fixed::new(bits, other.negative)
+        } else {
+            let mut bits = self.bits;
+            sub_slices(&mut bits, &other.bits);
+            Fixed::new(bits, self.negative)
+        }
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, other: Fixed) -> Fixed {
+        self + -other
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        Fixed::new(self.bits, !self.negative)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, other: Fixed) -> Fixed {
+        let negative = self.negative != other.negative;
+        let mut product = [0u32; 16];
+        mul_internal(&self.bits, &other.bits, &mut product);
+        div_by_pow10_with_strategy(&mut product, SCALE, negative, RoundingStrategy::MidpointNearestEven);
+        debug_assert!(is_all_zero(&product[8..]), "Fixed point overflow");
+        let mut bits = [0u32; 8];
+        bits.copy_from_slice(&product[..8]);
+        Fixed::new(bits, negative)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    fn div(self, other: Fixed) -> Fixed {
+        let mut quotient = [0u32; 16];
+        quotient[..8].copy_from_slice(&self.bits);
+        mul_by_pow10(&mut quotient, SCALE);
+        let negative = self.negative != other.negative;
+        div_rounded(
+            &mut quotient,
+            &other.bits,
+            negative,
+            RoundingStrategy::MidpointAwayFromZero,
+        );
+        debug_assert!(is_all_zero(&quotient[8..]), "Fixed point overflow");
+        let mut bits = [0u32; 8];
+        bits.copy_from_slice(&quotient[..8]);
+        Fixed::new(bits, negative)
+    }
+}
+
+impl PartialEq for Fixed {
+    fn eq(&self, other: &Fixed) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Fixed {}
+
+impl PartialOrd for Fixed {
+    fn partial_cmp(&self, other: &Fixed) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fixed {
+    fn cmp(&self, other: &Fixed) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_slices(&self.bits, &other.bits),
+            (true, true) => cmp_slices(&other.bits, &self.bits),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::str::FromStr;
+
+    #[test]
+    fn it_converts_to_decimal() {
+        let test_cases = &[
+            ("1.5", 0, 28, Some("1.5")),
+            ("0.125", 0, 2, Some("0.12")),
+            ("-0.135", 0, 2, Some("-0.14")),
+            ("-0.0001", 0, 3, Some("0.000")),
+            ("1.2345", 2, 28, Some("123.45")),
+            ("1.2345", -27, 28, Some("0.0000000000000000000000000012")),
+            ("1.2345", -30, 28, Some("0.0000000000000000000000000000")),
+            (
+                "7.9228162514264337593543950335",
+                28,
+                28,
+                Some("79228162514264337593543950335"),
+            ),
+            ("0.7922816251426433759354395034", 29, 28, None),
+        ];
+        for &(value, exponent, dp, expected) in test_cases {
+            let value = Decimal::from_str(value).unwrap();
+            let result = Fixed::from_decimal(&value).to_decimal(exponent, dp);
+            assert_eq!(
+                expected.map(String::from),
+                result.map(|d| d.to_string()),
+                "{} * 10^{} to {} dp",
+                value,
+                exponent,
+                dp
+            );
+        }
+
+        // Digits are only discarded once when there are too many to fit
+        let third = Fixed::ONE.div_u32(3);
+        assert_eq!(
+            "0.3333333333333333333333333333",
+            third.to_decimal(0, 28).unwrap().to_string()
+        );
+        assert_eq!(
+            "3333333333333333333333333333.3",
+            third.to_decimal(28, 28).unwrap().to_string()
+        );
+        let two_thirds = third + third + Fixed::ONE.div_pow10(36);
+        assert_eq!(
+            "6666666666666666666666666666.7",
+            two_thirds.to_decimal(28, 5).unwrap().to_string()
+        );
+    }
+}
@@ -0,0 +1,436 @@
+// Arithmetic on unsigned integers of arbitrary length, stored as little endian slices of `u32`
+// words. These are used wherever an exact intermediate result may not fit within the fixed size
+// buffers used by the other operations, e.g. before rounding with an arbitrary strategy.
+use crate::decimal::{
+    add_by_internal, add_one_internal, div_by_u32, is_all_zero, mul_by_u32, round_increment_required, shl1_internal,
+    MAX_PRECISION, POWERS_10, U32_MASK,
+};
+use crate::{Decimal, RoundingStrategy};
+
+use core::cmp::Ordering;
+
+// The maximum number of words in a dividend or divisor
+const MAX_WORDS: usize = 16;
+
+// Multiplies two arbitrary length integers. The product must be zero initialized and be at least
+// as long as both integers combined.
+pub(crate) fn mul_internal(left: &[u32], right: &[u32], product: &mut [u32]) {
+    for (i, l) in left.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, r) in right.iter().enumerate() {
+            let tmp = u64::from(*l) * u64::from(*r) + u64::from(product[i + j]) + carry;
+            product[i + j] = (tmp & U32_MASK) as u32;
+            carry = tmp >> 32;
+        }
+        product[i + right.len()] = carry as u32;
+    }
+}
+
+// Multiplies an arbitrary length integer by 10^power. Returns overflow.
+pub(crate) fn mul_by_pow10(bits: &mut [u32], power: u32) -> u32 {
+    let mut power = power;
+    let mut overflow = 0;
+    while power > 0 {
+        let step = power.min(9);
+        overflow |= mul_by_u32(bits, POWERS_10[step as usize]);
+        power -= step;
+    }
+    overflow
+}
+
+// Divides an arbitrary length integer by 10^power, rounding the quotient using `strategy`.
+// Returns true if the division discarded a non-zero remainder (i.e. the result is inexact).
+pub(crate) fn div_by_pow10_with_strategy(
+    bits: &mut [u32],
+    power: u32,
+    negative: bool,
+    strategy: RoundingStrategy,
+) -> bool {
+    if power == 0 {
+        return false;
+    }
+    // Any digits below the final power of 10 only matter in that they break a midpoint tie
+    let mut power = power;
+    let mut sticky = false;
+    while power > 9 {
+        sticky |= div_by_u32(bits, POWERS_10[9]) != 0;
+        power -= 9;
+    }
+    let remainder = div_by_u32(bits, POWERS_10[power as usize]);
+    let midpoint = match remainder.cmp(&(POWERS_10[power as usize] / 2)) {
+        Ordering::Equal if sticky => Ordering::Greater,
+        order => order,
+    };
+    let inexact = sticky || remainder != 0;
+    if round_increment_required(strategy, negative, bits, midpoint, inexact) {
+        add_one_internal(bits);
+    }
+    inexact
+}
+
+// Long division of arbitrary length integers, calculating one word of the quotient at a time
+// (Knuth's Algorithm D). `bits` is overwritten with the quotient and the remainder is written to
+// `remainder`, which must be at least as long as the significant words of `divisor`. Both `bits`
+// and `divisor` can be at most 16 words long.
+pub(crate) fn div_rem_internal(bits: &mut [u32], divisor: &[u32], remainder: &mut [u32]) {
+    for part in remainder.iter_mut() {
+        *part = 0;
+    }
+    let n = significant_words(divisor);
+    debug_assert!(n > 0, "Division by zero");
+    if n == 1 {
+        remainder[0] = div_by_u32(bits, divisor[0]);
+        return;
+    }
+    let m = significant_words(bits);
+    if m < n {
+        remainder[..m].copy_from_slice(&bits[..m]);
+        for part in bits.iter_mut() {
+            *part = 0;
+        }
+        return;
+    }
+
+    // Shift both values so that the most significant bit of the divisor is set. This keeps each
+    // estimated word of the quotient within two of its true value.
+    let shift = divisor[n - 1].leading_zeros();
+    let mut v = [0u32; MAX_WORDS];
+    let mut u = [0u32; MAX_WORDS + 1];
+    shl_words(&divisor[..n], shift, &mut v);
+    u[m] = shl_words(&bits[..m], shift, &mut u);
+    for part in bits.iter_mut() {
+        *part = 0;
+    }
+
+    let v_hi = u64::from(v[n - 1]);
+    let v_next = u64::from(v[n - 2]);
+    for j in (0..=m - n).rev() {
+        // Estimate the quotient word from the top words of the remainder, correcting it using
+        // the next word of the divisor
+        let top = (u64::from(u[j + n]) << 32) | u64::from(u[j + n - 1]);
+        let mut q = top / v_hi;
+        let mut r = top % v_hi;
+        while q > U32_MASK || q * v_next > ((r << 32) | u64::from(u[j + n - 2])) {
+            q -= 1;
+            r += v_hi;
+            if r > U32_MASK {
+                break;
+            }
+        }
+
+        // Subtract q * v from the current window of the remainder
+        let mut carry = 0u64;
+        let mut borrow = false;
+        for i in 0..n {
+            let product = q * u64::from(v[i]) + carry;
+            carry = product >> 32;
+            let (diff, b1) = u[i + j].overflowing_sub(product as u32);
+            let (diff, b2) = diff.overflowing_sub(borrow as u32);
+            u[i + j] = diff;
+            borrow = b1 || b2;
+        }
+        let (diff, b1) = u[j + n].overflowing_sub(carry as u32);
+        let (diff, b2) = diff.overflowing_sub(borrow as u32);
+        u[j + n] = diff;
+
+        // The estimate can still be one too large, in which case we add the divisor back
+        if b1 || b2 {
+            q -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = u64::from(u[i + j]) + u64::from(v[i]) + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        bits[j] = q as u32;
+    }
+
+    // Undo the shift to recover the remainder
+    for i in 0..n {
+        remainder[i] = if shift == 0 {
+            u[i]
+        } else {
+            (u[i] >> shift) | (u[i + 1] << (32 - shift))
+        };
+    }
+}
+
+// Divides an arbitrary length integer by another, rounding the quotient using `strategy`. Returns
+// true if the division discarded a non-zero remainder (i.e. the result is inexact).
+pub(crate) fn div_rounded(bits: &mut [u32], divisor: &[u32], negative: bool, strategy: RoundingStrategy) -> bool {
+    let mut remainder = [0u32; MAX_WORDS + 1];
+    div_rem_internal(bits, divisor, &mut remainder);
+    // Compare twice the remainder against the divisor to figure out the midpoint
+    let inexact = !is_all_zero(&remainder);
+    shl1_internal(&mut remainder, 0);
+    let midpoint = cmp_slices(&remainder, divisor);
+    if round_increment_required(strategy, negative, bits, midpoint, inexact) {
+        add_one_internal(bits);
+    }
+    inexact
+}
+
+// Compares two arbitrary length integers, treating any missing high order words as zero.
+pub(crate) fn cmp_slices(left: &[u32], right: &[u32]) -> Ordering {
+    for i in (0..left.len().max(right.len())).rev() {
+        let l = left.get(i).copied().unwrap_or(0);
+        let r = right.get(i).copied().unwrap_or(0);
+        match l.cmp(&r) {
+            Ordering::Equal => {}
+            order => return order,
+        }
+    }
+    Ordering::Equal
+}
+
+// Subtracts `by` from `bits`, propagating the borrow through any higher order words. `by` must not
+// be greater than `bits`.
+pub(crate) fn sub_slices(bits: &mut [u32], by: &[u32]) {
+    let mut borrow = 0u64;
+    for (i, part) in bits.iter_mut().enumerate() {
+        let sub = u64::from(by.get(i).copied().unwrap_or(0)) + borrow;
+        let value = u64::from(*part);
+        if value >= sub {
+            *part = (value - sub) as u32;
+            borrow = 0;
+        } else {
+            *part = (value + (1 << 32) - sub) as u32;
+            borrow = 1;
+        }
+    }
+}
+
+// Rounds an arbitrary length integer of up to 320 bits with the given scale to the nearest
+// `Decimal` using `strategy`, discarding as few digits as possible. Returns `None` if the integral
+// portion cannot be represented.
+pub(crate) fn wide_to_decimal_rounded(
+    bits: &[u32],
+    negative: bool,
+    scale: u32,
+    strategy: RoundingStrategy,
+) -> Option<Decimal> {
+    wide_to_decimal_rounded_dp(bits, negative, scale, MAX_PRECISION, strategy)
+}
+
+// Rounds an arbitrary length integer of up to 320 bits with the given scale to a `Decimal` with at
+// most `dp` decimal places using `strategy`, discarding more digits only if they don't fit. The
+// value is only ever rounded once. Returns `None` if the integral portion cannot be represented.
+pub(crate) fn wide_to_decimal_rounded_dp(
+    bits: &[u32],
+    negative: bool,
+    scale: u32,
+    dp: u32,
+    strategy: RoundingStrategy,
+) -> Option<Decimal> {
+    let mut value = [0u32; 10];
+    value[..bits.len()].copy_from_slice(bits);
+
+    // Figure out how many digits need to be discarded for the truncated value to fit
+    let mut discard = scale.saturating_sub(dp.min(MAX_PRECISION));
+    let mut truncated = value;
+    let mut power = discard;
+    while power > 0 {
+        let step = power.min(9);
+        div_by_u32(&mut truncated, POWERS_10[step as usize]);
+        power -= step;
+    }
+    while !is_all_zero(&truncated[3..]) {
+        div_by_u32(&mut truncated, 10);
+        discard += 1;
+    }
+
+    // Rounding may carry into a value that no longer fits, in which case we need to discard
+    // one more digit.
+    while discard <= scale {
+        let mut rounded = value;
+        div_by_pow10_with_strategy(&mut rounded, discard, negative, strategy);
+        if let Some(result) = wide_to_decimal(&rounded, negative, scale - discard) {
+            return Some(result);
+        }
+        discard += 1;
+    }
+    None
+}
+
+// Changes the scale of a decimal to `scale`, rounding if the scale is reduced and padding with zeros
+// if it is increased. Returns the resulting magnitude, which may require more than 96 bits, and
+// whether any non-zero digits were discarded.
+pub(crate) fn quantize_internal(value: &Decimal, scale: u32, strategy: RoundingStrategy) -> ([u32; 6], bool) {
+    let mut bits = [0u32; 6];
+    bits[..3].copy_from_slice(&value.mantissa_array3());
+    let inexact = if scale < value.scale() {
+        div_by_pow10_with_strategy(&mut bits, value.scale() - scale, value.is_sign_negative(), strategy)
+    } else {
+        mul_by_pow10(&mut bits, scale - value.scale());
+        false
+    };
+    (bits, inexact)
+}
+
+// Calculates the exact sum of two decimals at the larger of the two scales. Returns the magnitude,
+// whether the sum is negative and the scale of the sum.
+pub(crate) fn exact_sum(left: &Decimal, right: &Decimal) -> ([u32; 7], bool, u32) {
+    // The larger scale is at most 28, so each side needs at most 96 + 28 * log2(10) bits
+    let scale = left.scale().max(right.scale());
+    let mut l = [0u32; 7];
+    let mut r = [0u32; 7];
+    l[..3].copy_from_slice(&left.mantissa_array3());
+    r[..3].copy_from_slice(&right.mantissa_array3());
+    mul_by_pow10(&mut l, scale - left.scale());
+    mul_by_pow10(&mut r, scale - right.scale());
+    let negative = if left.is_sign_negative() == right.is_sign_negative() {
+        add_by_internal(&mut l, &r);
+        left.is_sign_negative()
+    } else if cmp_slices(&l, &r) == Ordering::Less {
+        sub_slices(&mut r, &l);
+        l = r;
+        right.is_sign_negative()
+    } else {
+        sub_slices(&mut l, &r);
+        left.is_sign_negative() && !is_all_zero(&l)
+    };
+    (l, negative, scale)
+}
+
+// Compares two arbitrary length integers with the given scales for equality.
+pub(crate) fn eq_scaled(left: &[u32], left_scale: u32, right: &[u32], right_scale: u32) -> bool {
+    // Both scales are at most 56, and each integer at most 224 bits long
+    let mut l = [0u32; 10];
+    let mut r = [0u32; 10];
+    l[..left.len()].copy_from_slice(left);
+    r[..right.len()].copy_from_slice(right);
+    if left_scale < right_scale {
+        mul_by_pow10(&mut l, right_scale - left_scale);
+    } else {
+        mul_by_pow10(&mut r, left_scale - right_scale);
+    }
+    cmp_slices(&l, &r) == Ordering::Equal
+}
+
+// Counts the number of decimal digits in an arbitrary length integer of up to 320 bits.
+pub(crate) fn count_digits(bits: &[u32]) -> u32 {
+    let mut tmp = [0u32; 10];
+    tmp[..bits.len()].copy_from_slice(bits);
+    let mut digits = 0;
+    while !is_all_zero(&tmp) {
+        div_by_u32(&mut tmp, 10);
+        digits += 1;
+    }
+    digits
+}
+
+// Converts an arbitrary length integer into a `Decimal`, returning `None` if it doesn't fit
+// within 96 bits.
+pub(crate) fn wide_to_decimal(bits: &[u32], negative: bool, scale: u32) -> Option<Decimal> {
+    if !is_all_zero(&bits[3..]) {
+        return None;
+    }
+    Some(Decimal::from_parts(bits[0], bits[1], bits[2], negative, scale))
+}
+
+// The number of words up to and including the most significant non-zero word.
+#[inline]
+fn significant_words(bits: &[u32]) -> usize {
+    bits.iter().rposition(|part| *part != 0).map_or(0, |i| i + 1)
+}
+
+// Shifts an arbitrary length integer left by less than 32 bits into `shifted`, returning the bits
+// shifted out of the most significant word.
+#[inline]
+fn shl_words(bits: &[u32], shift: u32, shifted: &mut [u32]) -> u32 {
+    if shift == 0 {
+        shifted[..bits.len()].copy_from_slice(bits);
+        return 0;
+    }
+    let mut carry = 0;
+    for (part, out) in bits.iter().zip(shifted.iter_mut()) {
+        *out = (*part << shift) | carry;
+        carry = *part >> (32 - shift);
+    }
+    carry
+}
+
+#[cfg(test)]
+mod test {
+    // Tests on private methods.
+    //
+    // All public tests should go under `tests/`.
+
+    use super::*;
+
+    // Checks that quotient * divisor + remainder == dividend and that remainder < divisor
+    fn check_div_rem(dividend: &[u32], divisor: &[u32]) {
+        let mut quotient = [0u32; MAX_WORDS];
+        quotient[..dividend.len()].copy_from_slice(dividend);
+        let mut remainder = [0u32; MAX_WORDS];
+        div_rem_internal(&mut quotient[..dividend.len()], divisor, &mut remainder);
+        assert_eq!(
+            Ordering::Less,
+            cmp_slices(&remainder, divisor),
+            "{:?} / {:?}",
+            dividend,
+            divisor
+        );
+
+        let mut product = [0u32; MAX_WORDS * 2];
+        mul_internal(&quotient, divisor, &mut product);
+        add_by_internal(&mut product, &remainder);
+        assert_eq!(
+            Ordering::Equal,
+            cmp_slices(&product, dividend),
+            "{:?} / {:?}",
+            dividend,
+            divisor
+        );
+    }
+
+    #[test]
+    fn it_can_div_rem_internal() {
+        let tests: &[(&[u32], &[u32])] = &[
+            (&[7], &[2]),
+            (&[0, 0, 1], &[0, 1]),
+            (&[5, 0, 0], &[6, 0, 0, 0]),
+            (&[u32::MAX; 8], &[u32::MAX; 4]),
+            (&[u32::MAX; 8], &[1, 0, 0, 0, 0, 0, 0, 0x8000_0000]),
+            // Requires the estimated quotient word to be corrected after subtracting
+            (&[0, 0, 0x8000_0000, 0x7fff_ffff], &[1, 0, 0x8000_0000]),
+            (&[0, 0xffff_fffe, 0, 0x8000_0000], &[0xffff_ffff, 0, 0x8000_0000]),
+            (&[3, 0, 0x8000_0000], &[1, 0, 0x2000_0000]),
+        ];
+        for &(dividend, divisor) in tests {
+            check_div_rem(dividend, divisor);
+        }
+
+        // A simple linear congruential generator gives a reproducible spread of values and lengths
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (seed >> 32) as u32
+        };
+        for _ in 0..2000 {
+            let mut dividend = [0u32; MAX_WORDS];
+            let mut divisor = [0u32; 8];
+            let dividend_len = 1 + next() as usize % MAX_WORDS;
+            let divisor_len = 1 + next() as usize % 8;
+            for part in dividend[..dividend_len].iter_mut() {
+                *part = next();
+            }
+            for part in divisor[..divisor_len].iter_mut() {
+                // Runs of zero and saturated words exercise the correction steps
+                *part = match next() % 4 {
+                    0 => 0,
+                    1 => u32::MAX,
+                    _ => next(),
+                };
+            }
+            if is_all_zero(&divisor) {
+                continue;
+            }
+            check_div_rem(&dividend[..dividend_len], &divisor[..divisor_len]);
+        }
+    }
+}
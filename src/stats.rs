@@ -0,0 +1,238 @@
+//! Descriptive statistics over collections of `Decimal` values.
+//!
+//! Every function accepts anything which can be iterated over by value, and returns `None` if
+//! there are not enough values or if the result overflows. Sums are accumulated exactly using a
+//! [`DecimalAccumulator`](crate::DecimalAccumulator), so the order of the values never affects the
+//! result.
+//!
+//! ```rust
+//! use rust_decimal::prelude::*;
+//! use rust_decimal::stats::{self, VarianceKind};
+//!
+//! let values = ["2", "4", "4", "4", "5", "5", "7", "9"]
+//!     .iter()
+//!     .map(|v| Decimal::from_str(v).unwrap())
+//!     .collect::<Vec<_>>();
+//! assert_eq!(stats::mean(values.iter().copied()), Some(Decimal::from(5)));
+//! assert_eq!(stats::median(values.iter().copied()), Some(Decimal::new(45, 1)));
+//! assert_eq!(stats::std_dev(values, VarianceKind::Population), Some(Decimal::from(2)));
+//! ```
+use crate::decimal::{add_by_internal, is_all_zero};
+use crate::ops::wide::{cmp_slices, div_rem_internal, mul_by_pow10, mul_internal, wide_to_decimal_rounded};
+use crate::prelude::*;
+use crate::DecimalAccumulator;
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+const HUNDRED: Decimal = Decimal::from_parts(100, 0, 0, false, 0);
+const FIFTY: Decimal = Decimal::from_parts(50, 0, 0, false, 0);
+const HALF: Decimal = Decimal::from_parts(5, 0, 0, false, 1);
+
+/// Whether a variance describes an entire population or is estimated from a sample of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VarianceKind {
+    /// The values are the entire population, so the sum of squared deviations is divided by the
+    /// number of values.
+    Population,
+    /// The values are a sample of the population, so the sum of squared deviations is divided by
+    /// one less than the number of values (Bessel's correction).
+    Sample,
+}
+
+/// How a percentile is calculated when it lies between two of the values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linearly interpolates between the two values.
+    Linear,
+    /// Uses the lower of the two values.
+    Lower,
+    /// Uses the higher of the two values.
+    Higher,
+    /// Uses whichever value is nearest, preferring the one with an even index when the percentile
+    /// lies exactly halfway between them.
+    Nearest,
+    /// Uses the value halfway between the two values.
+    Midpoint,
+}
+
+/// The arithmetic mean of the values.
+///
+/// Returns `None` if there are no values. The sum is divided before it is rounded, so the mean
+/// of any values is always within range.
+pub fn mean<I>(values: I) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    let mut sum = DecimalAccumulator::new();
+    let mut count = 0u64;
+    for value in values {
+        sum.add(value);
+        count += 1;
+    }
+    sum.finish_div(count)
+}
+
+/// The mean of the values weighted by the second element of each pair, i.e. the sum of each value
+/// multiplied by its weight, divided by the sum of the weights.
+///
+/// Returns `None` if the weights sum to zero or if the result overflows.
+pub fn weighted_mean<I>(values: I) -> Option<Decimal>
+where
+    I: IntoIterator<Item = (Decimal, Decimal)>,
+{
+    let mut sum = DecimalAccumulator::new();
+    let mut weights = DecimalAccumulator::new();
+    for (value, weight) in values {
+        sum.add(value.checked_mul(weight)?);
+        weights.add(weight);
+    }
+    let (sum, _) = sum.finish().ok()?;
+    let (weights, _) = weights.finish().ok()?;
+    sum.checked_div(weights)
+}
+
+/// The median of the values. When there is an even number of values this is the midpoint of the
+/// two middle values.
+///
+/// Returns `None` if there are no values.
+pub fn median<I>(values: I) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    percentile(values, FIFTY, Interpolation::Midpoint)
+}
+
+/// The variance of the values, which is the mean of their squared deviations from the mean.
+///
+/// Returns `None` if there are no values, or only a single value for the sample variance, or if
+/// the result overflows.
+pub fn variance<I>(values: I, kind: VarianceKind) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    let values = values.into_iter().collect::<Vec<_>>();
+    let count = match kind {
+        VarianceKind::Population => values.len(),
+        VarianceKind::Sample => values.len().checked_sub(1)?,
+    };
+    if count == 0 {
+        return None;
+    }
+    let mean = mean(values.iter().copied())?;
+    let mut sum = DecimalAccumulator::new();
+    for value in values {
+        let deviation = value.checked_sub(mean)?;
+        sum.add(deviation.checked_mul(deviation)?);
+    }
+    sum.finish_div(count as u64)
+}
+
+/// The standard deviation of the values, which is the square root of their [`variance`].
+///
+/// Returns `None` for the same reasons as `variance`.
+pub fn std_dev<I>(values: I, kind: VarianceKind) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    variance(values, kind)?.sqrt()
+}
+
+/// The value below which `percentile` percent of the values lie, where `percentile` is between 0
+/// and 100 inclusive. The values are sorted and the percentile is found at the fractional index
+/// `percentile / 100 * (n - 1)`, using the `interpolation` method when that lies between two
+/// values.
+///
+/// Returns `None` if there are no values, if `percentile` is out of range, or if the interpolated
+/// value overflows.
+pub fn percentile<I>(values: I, percentile: Decimal, interpolation: Interpolation) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    if percentile < Decimal::ZERO || percentile > HUNDRED {
+        return None;
+    }
+    let mut values = values.into_iter().collect::<Vec<_>>();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+
+    // Rounding the rank can snap it onto a neighbouring index, so the index and the remainder of
+    // percentile * (n - 1) / 100 are calculated exactly
+    let count = (values.len() - 1) as u64;
+    let mut numerator = [0u32; 5];
+    mul_internal(
+        &percentile.mantissa_array3(),
+        &[count as u32, (count >> 32) as u32],
+        &mut numerator,
+    );
+    let mut divisor = [100, 0, 0, 0];
+    mul_by_pow10(&mut divisor, percentile.scale());
+    let mut remainder = [0u32; 4];
+    div_rem_internal(&mut numerator, &divisor, &mut remainder);
+    // The index is at most n - 1
+    let index = ((u64::from(numerator[1]) << 32) | u64::from(numerator[0])) as usize;
+    if is_all_zero(&remainder) {
+        return Some(values[index]);
+    }
+    let (lower, upper) = (values[index], values[index + 1]);
+    match interpolation {
+        Interpolation::Linear => {
+            // The fraction carries the scale of the divisor, which would otherwise leak into the
+            // result as trailing zeros
+            let fraction = wide_to_decimal_rounded(
+                &remainder,
+                false,
+                percentile.scale() + 2,
+                RoundingStrategy::MidpointNearestEven,
+            )?
+            .normalize();
+            interpolate(lower, upper, fraction)
+        }
+        Interpolation::Lower => Some(lower),
+        Interpolation::Higher => Some(upper),
+        Interpolation::Nearest => Some(match compare_half(&remainder, &divisor) {
+            Ordering::Less => lower,
+            Ordering::Greater => upper,
+            Ordering::Equal if index & 1 == 0 => lower,
+            Ordering::Equal => upper,
+        }),
+        Interpolation::Midpoint => interpolate(lower, upper, HALF),
+    }
+}
+
+/// The smallest of the values, or `None` if there are no values.
+pub fn min<I>(values: I) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    values.into_iter().min()
+}
+
+/// The largest of the values, or `None` if there are no values.
+pub fn max<I>(values: I) -> Option<Decimal>
+where
+    I: IntoIterator<Item = Decimal>,
+{
+    values.into_iter().max()
+}
+
+/// Compares the fraction `numerator / denominator` against one half. Used internally.
+fn compare_half(numerator: &[u32; 4], denominator: &[u32; 4]) -> Ordering {
+    let mut twice = *numerator;
+    add_by_internal(&mut twice, numerator);
+    cmp_slices(&twice, denominator)
+}
+
+/// The value `fraction` of the way from `lower` to `upper`. Used internally.
+fn interpolate(lower: Decimal, upper: Decimal, fraction: Decimal) -> Option<Decimal> {
+    // The difference only overflows when the values have opposite signs, in which case their
+    // weighted sum can't
+    match upper.checked_sub(lower) {
+        Some(difference) => lower.checked_add(difference.checked_mul(fraction)?),
+        None => lower
+            .checked_mul(Decimal::ONE - fraction)?
+            .checked_add(upper.checked_mul(fraction)?),
+    }
+}
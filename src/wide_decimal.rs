@@ -0,0 +1,191 @@
+use crate::decimal::{div_by_u32, is_all_zero, overflow_error};
+use crate::ops::wide::{cmp_slices, mul_by_pow10, mul_internal, wide_to_decimal_rounded};
+use crate::{Decimal, Error, RoundingStrategy};
+
+use arrayvec::{ArrayString, ArrayVec};
+use core::{cmp::Ordering, convert::TryFrom, fmt};
+
+// The largest 192 bit number has 58 digits, and the largest scale is 56. This is enough to hold
+// either of these along with a decimal point and leading zero.
+const MAX_WIDE_STR_BUFFER_SIZE: usize = 64;
+
+/// A decimal number with a 192 bit mantissa and a scale of up to 56, which is able to represent
+/// the exact product of any two `Decimal` numbers.
+///
+/// A `WideDecimal` is created using [`Decimal::widening_mul`] and can be converted back into a
+/// `Decimal` using [`to_decimal`](WideDecimal::to_decimal) with a chosen [`RoundingStrategy`], or
+/// via `TryFrom` which uses "Bankers Rounding" like the arithmetic operators of `Decimal`.
+///
+/// # Example
+///
+/// ```
+/// use rust_decimal::{Decimal, RoundingStrategy};
+/// use core::str::FromStr;
+///
+/// let a = Decimal::from_str("0.0000000000000000000000000003").unwrap();
+/// let b = Decimal::from_str("0.5").unwrap();
+/// let product = a.widening_mul(b);
+/// assert_eq!(product.to_string(), "0.00000000000000000000000000015");
+/// let rounded = product.to_decimal(RoundingStrategy::MidpointAwayFromZero).unwrap();
+/// assert_eq!(rounded.to_string(), "0.0000000000000000000000000002");
+/// ```
+#[derive(Clone, Copy)]
+pub struct WideDecimal {
+    mantissa: [u32; 6],
+    scale: u32,
+    negative: bool,
+}
+
+impl WideDecimal {
+    /// Returns the scale of the number, which is between 0 and 56 inclusive.
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns the 192 bit mantissa as six 32 bit words, least significant first.
+    pub const fn mantissa(&self) -> [u32; 6] {
+        self.mantissa
+    }
+
+    /// Returns `true` if the number is negative.
+    pub const fn is_sign_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if the number is zero.
+    pub fn is_zero(&self) -> bool {
+        is_all_zero(&self.mantissa)
+    }
+
+    /// Converts the number to a `Decimal`, rounding using the provided [`RoundingStrategy`] if it
+    /// cannot be represented exactly. As few digits as possible are discarded.
+    ///
+    /// Returns an error if the integral portion of the number is out of range.
+    pub fn to_decimal(&self, strategy: RoundingStrategy) -> Result<Decimal, Error> {
+        match wide_to_decimal_rounded(&self.mantissa, self.negative, self.scale, strategy) {
+            Some(result) => Ok(result),
+            None => Err(overflow_error(
+                self.negative,
+                "Wide decimal exceeds the range of a Decimal",
+            )),
+        }
+    }
+}
+
+impl Decimal {
+    /// Multiplies `self` by `other`, returning the exact product as a [`WideDecimal`] without any
+    /// rounding or possibility of overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use core::convert::TryFrom;
+    ///
+    /// let product = Decimal::MAX.widening_mul(Decimal::MAX);
+    /// assert_eq!(product.to_string(), "6277101735386680763835789423049210091073826769276946612225");
+    /// assert!(Decimal::try_from(product).is_err());
+    /// ```
+    pub fn widening_mul(self, other: Decimal) -> WideDecimal {
+        let mut mantissa = [0u32; 6];
+        mul_internal(&self.mantissa_array3(), &other.mantissa_array3(), &mut mantissa);
+        WideDecimal {
+            mantissa,
+            scale: self.scale() + other.scale(),
+            negative: (self.is_sign_negative() ^ other.is_sign_negative()) && !is_all_zero(&mantissa),
+        }
+    }
+}
+
+impl From<Decimal> for WideDecimal {
+    fn from(value: Decimal) -> Self {
+        let mut mantissa = [0u32; 6];
+        mantissa[..3].copy_from_slice(&value.mantissa_array3());
+        WideDecimal {
+            mantissa,
+            scale: value.scale(),
+            negative: value.is_sign_negative() && !value.is_zero(),
+        }
+    }
+}
+
+impl TryFrom<WideDecimal> for Decimal {
+    type Error = Error;
+
+    fn try_from(value: WideDecimal) -> Result<Self, Error> {
+        value.to_decimal(RoundingStrategy::MidpointNearestEven)
+    }
+}
+
+impl PartialEq for WideDecimal {
+    fn eq(&self, other: &WideDecimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for WideDecimal {}
+
+impl PartialOrd for WideDecimal {
+    fn partial_cmp(&self, other: &WideDecimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WideDecimal {
+    fn cmp(&self, other: &WideDecimal) -> Ordering {
+        if self.negative != other.negative {
+            return if self.negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        // Bring both magnitudes to the same scale. This requires at most 192 + 56 * log2(10) bits.
+        let mut left = [0u32; 12];
+        let mut right = [0u32; 12];
+        left[..6].copy_from_slice(&self.mantissa);
+        right[..6].copy_from_slice(&other.mantissa);
+        if self.scale < other.scale {
+            mul_by_pow10(&mut left, other.scale - self.scale);
+        } else {
+            mul_by_pow10(&mut right, self.scale - other.scale);
+        }
+        let order = cmp_slices(&left, &right);
+        if self.negative {
+            order.reverse()
+        } else {
+            order
+        }
+    }
+}
+
+impl fmt::Display for WideDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let scale = self.scale as usize;
+        let mut chars = ArrayVec::<[_; MAX_WIDE_STR_BUFFER_SIZE]>::new();
+        let mut working = self.mantissa;
+        while !is_all_zero(&working) {
+            let remainder = div_by_u32(&mut working, 10u32);
+            chars.push(char::from(b'0' + remainder as u8));
+        }
+        while scale >= chars.len() {
+            chars.push('0');
+        }
+
+        let mut rep = ArrayString::<[u8; MAX_WIDE_STR_BUFFER_SIZE]>::new();
+        for (i, c) in chars.iter().rev().enumerate() {
+            if scale > 0 && i == chars.len() - scale {
+                rep.push('.');
+            }
+            rep.push(*c);
+        }
+        f.pad_integral(!self.negative, "", rep.as_str())
+    }
+}
+
+impl fmt::Debug for WideDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -3,8 +3,8 @@ use core::{
     convert::{TryFrom, TryInto},
     str::FromStr,
 };
-use num_traits::{Signed, ToPrimitive};
-use rust_decimal::{Decimal, RoundingStrategy};
+use num_traits::{Bounded, CheckedNeg, Inv, Num, Signed, ToPrimitive};
+use rust_decimal::{Context, Decimal, DecimalAccumulator, ErrorKind, RoundingStrategy, Signal, WideDecimal};
 
 macro_rules! either {
     ($result:expr, $legacy_result:expr) => {
@@ -216,6 +216,8 @@ fn it_formats_lower_exp() {
         ("42.123", "4.2123e1"),
         ("-42.123", "-4.2123e1"),
         ("100", "1e2"),
+        ("-79228162514264337593543950335", "-7.9228162514264337593543950335e28"),
+        ("0.0000000000000000000000000001", "1e-28"),
     ];
     for (value, expected) in &tests {
         let a = Decimal::from_str(value).unwrap();
@@ -223,6 +225,15 @@ fn it_formats_lower_exp() {
     }
 }
 
+#[test]
+fn it_formats_upper_exp() {
+    let tests = [("0.00001", "1E-5"), ("-42.123", "-4.2123E1"), ("100", "1E2")];
+    for (value, expected) in &tests {
+        let a = Decimal::from_str(value).unwrap();
+        assert_eq!(&format!("{:E}", a), *expected, "format!(\"{{:E}}\", {})", a);
+    }
+}
+
 #[test]
 fn it_formats_lower_exp_padding() {
     let tests = [
@@ -679,736 +690,1710 @@ fn it_can_remassign() {
     assert_eq!("1", a.to_string());
 }
 
+// Fused multiply-add
+
 #[test]
-fn it_eqs_decimals() {
-    fn eq(a: &str, b: &str, c: bool) {
+fn it_can_mul_add() {
+    let max = "79228162514264337593543950335";
+    let tests = &[
+        ("1.5", "2", "0.25", Some("3.25")),
+        ("1000.00", "1.015", "-2.50", Some("1012.50000")),
+        ("-2", "3", "6", Some("0")),
+        ("2", "-3", "1", Some("-5")),
+        ("-2", "-3", "-7", Some("-1")),
+        ("0", "5", "-1.5", Some("-1.5")),
+        // The product is 10000000000000000000000000002.0000000000000000000000000001 which would be
+        // rounded to an integer before adding if the operations were performed separately.
+        (
+            "100000000000000.00000000000001",
+            "100000000000000.00000000000001",
+            "-10000000000000000000000000002",
+            Some("0.0000000000000000000000000001"),
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.5",
+            "0",
+            Some("0.0000000000000000000000000000"),
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.6",
+            "1",
+            Some("1.0000000000000000000000000001"),
+        ),
+        (max, "0.5", "0", Some("39614081257132168796771975168")),
+        (max, "0.5", "0.5", Some("39614081257132168796771975168")),
+        (max, "2", "-79228162514264337593543950335", Some(max)),
+        (max, "1", "0.4", Some(max)),
+        (max, "1", "0.5", None),
+        (max, "2", "0", None),
+        (max, "-2", "0", None),
+    ];
+    for &(a, b, c, expected) in tests {
         let a = Decimal::from_str(a).unwrap();
         let b = Decimal::from_str(b).unwrap();
-        assert_eq!(c, a.eq(&b), "{} == {}", a.to_string(), b.to_string());
-        assert_eq!(c, b.eq(&a), "{} == {}", b.to_string(), a.to_string());
+        let c = Decimal::from_str(c).unwrap();
+        let result = a.checked_mul_add(b, c);
+        assert_eq!(
+            expected,
+            result.map(|d| d.to_string()).as_deref(),
+            "{} * {} + {}",
+            a,
+            b,
+            c
+        );
+        if let Some(expected) = expected {
+            assert_eq!(expected, a.mul_add(b, c).to_string());
+        }
     }
+}
+
+#[test]
+#[should_panic(expected = "Multiplication overflowed")]
+fn it_panics_when_mul_add_overflows() {
+    let _ = Decimal::MAX.mul_add(Decimal::from(2), Decimal::ONE);
+}
+
+#[test]
+fn it_can_mul_add_using_num_traits() {
+    use num_traits::{MulAdd, MulAddAssign};
+
+    let mut a = Decimal::from_str("1.5").unwrap();
+    let b = Decimal::from(2);
+    let c = Decimal::from_str("0.25").unwrap();
+    assert_eq!(Decimal::from_str("3.25").unwrap(), MulAdd::mul_add(a, b, c));
+    MulAddAssign::mul_add_assign(&mut a, b, c);
+    assert_eq!(Decimal::from_str("3.25").unwrap(), a);
+}
 
+// Widening multiplication
+
+#[test]
+fn it_can_widening_mul() {
+    let max = "79228162514264337593543950335";
     let tests = &[
-        ("1", "1", true),
-        ("1", "-1", false),
-        ("1", "1.00", true),
-        ("1.2345000000000", "1.2345", true),
-        ("1.0000000000000000000000000000", "1.0000000000000000000000000000", true),
+        ("1.5", "-2", "-3.0"),
+        ("0", "-1.5", "0.0"),
+        ("-0.5", "-0.5", "0.25"),
+        (max, max, "6277101735386680763835789423049210091073826769276946612225"),
         (
-            "1.0000000000000000000000000001",
-            "1.0000000000000000000000000000",
-            false,
+            max,
+            "-0.0000000000000000000000000001",
+            "-7.9228162514264337593543950335",
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.0000000000000000000000000001",
+            "0.00000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "7.9228162514264337593543950335",
+            "7.9228162514264337593543950335",
+            "62.77101735386680763835789423049210091073826769276946612225",
         ),
     ];
-    for &(a, b, c) in tests {
-        eq(a, b, c);
+    for &(a, b, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let product = a.widening_mul(b);
+        assert_eq!(expected, product.to_string(), "{} * {}", a, b);
+        assert_eq!(expected, format!("{:?}", product));
     }
+    assert_eq!(
+        "  -0.3",
+        format!("{:>6}", Decimal::from(-3).widening_mul(Decimal::new(1, 1)))
+    );
 }
 
 #[test]
-fn it_cmps_decimals() {
-    fn cmp(a: &str, b: &str, c: core::cmp::Ordering) {
+fn it_can_convert_wide_decimals() {
+    use core::convert::TryFrom;
+
+    let max = "79228162514264337593543950335";
+    let tests = &[
+        ("1.5", "-2", RoundingStrategy::ToZero, Ok("-3.0")),
+        (
+            max,
+            "0.5",
+            RoundingStrategy::MidpointNearestEven,
+            Ok("39614081257132168796771975168"),
+        ),
+        (
+            max,
+            "0.5",
+            RoundingStrategy::ToZero,
+            Ok("39614081257132168796771975167"),
+        ),
+        (
+            "7.9228162514264337593543950335",
+            "7.9228162514264337593543950335",
+            RoundingStrategy::MidpointNearestEven,
+            Ok("62.771017353866807638357894230"),
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.0000000000000000000000000001",
+            RoundingStrategy::MidpointNearestEven,
+            Ok("0.0000000000000000000000000000"),
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.0000000000000000000000000001",
+            RoundingStrategy::ToPositiveInfinity,
+            Ok("0.0000000000000000000000000001"),
+        ),
+        (
+            max,
+            max,
+            RoundingStrategy::ToZero,
+            Err(ErrorKind::ExceedsMaximumPossibleValue),
+        ),
+        (
+            max,
+            "-2",
+            RoundingStrategy::ToZero,
+            Err(ErrorKind::LessThanMinimumPossibleValue),
+        ),
+    ];
+    for &(a, b, strategy, expected) in tests {
         let a = Decimal::from_str(a).unwrap();
         let b = Decimal::from_str(b).unwrap();
+        let product = a.widening_mul(b);
+        let result = product
+            .to_decimal(strategy)
+            .map(|d| d.to_string())
+            .map_err(|e| e.kind());
+        assert_eq!(expected.map(String::from), result, "{} * {} using {:?}", a, b, strategy);
+        if strategy == RoundingStrategy::MidpointNearestEven {
+            let result = Decimal::try_from(product).map(|d| d.to_string()).map_err(|e| e.kind());
+            assert_eq!(expected.map(String::from), result, "{} * {}", a, b);
+        }
+    }
+}
+
+#[test]
+fn it_can_compare_wide_decimals() {
+    let wide = |a: &str, b: &str| {
+        Decimal::from_str(a)
+            .unwrap()
+            .widening_mul(Decimal::from_str(b).unwrap())
+    };
+    assert_eq!(wide("1.5", "2"), wide("3", "1"));
+    assert_eq!(wide("1.5", "2"), WideDecimal::from(Decimal::from(3)));
+    assert_eq!(wide("0", "-1"), wide("0", "1"));
+    assert!(wide("1", "-1") < wide("0", "1"));
+    assert!(wide("-2", "1") < wide("-1", "1"));
+    assert!(wide("0.5", "0.5") < wide("0.3", "1"));
+    assert!(wide("79228162514264337593543950335", "2") > WideDecimal::from(Decimal::MAX));
+    assert!(
+        wide("0.0000000000000000000000000001", "0.0000000000000000000000000001") > WideDecimal::from(Decimal::ZERO)
+    );
+    assert!(
+        wide("-0.0000000000000000000000000001", "0.0000000000000000000000000001") < WideDecimal::from(Decimal::ZERO)
+    );
+}
+
+// Integer and Euclidean division
+
+#[test]
+fn it_can_divide_into_integer_quotients() {
+    let max = "79228162514264337593543950335";
+    let tests = &[
+        ("7.5", "2", Some("3"), Some("3"), Some("3"), "1.5", "1.5"),
+        ("-7.5", "2", Some("-3"), Some("-4"), Some("-4"), "-1.5", "0.5"),
+        ("7.5", "-2", Some("-3"), Some("-4"), Some("-3"), "1.5", "1.5"),
+        ("-7.5", "-2", Some("3"), Some("3"), Some("4"), "-1.5", "0.5"),
+        ("6", "3", Some("2"), Some("2"), Some("2"), "0", "0"),
+        ("-6", "3", Some("-2"), Some("-2"), Some("-2"), "0", "0"),
+        ("0.3", "0.1", Some("3"), Some("3"), Some("3"), "0.0", "0.0"),
+        ("1", "0.3", Some("3"), Some("3"), Some("3"), "0.1", "0.1"),
+        ("-1", "0.3", Some("-3"), Some("-4"), Some("-4"), "-0.1", "0.2"),
+        ("0.5", max, Some("0"), Some("0"), Some("0"), "0.5", "0.5"),
+        ("-0.5", "3", Some("0"), Some("-1"), Some("-1"), "-0.5", "2.5"),
+        (max, "1", Some(max), Some(max), Some(max), "0", "0"),
+        (max, "0.1", None, None, None, "0.0", "0.0"),
+        (max, "-0.11", None, None, None, "0.08", "0.08"),
+    ];
+    for &(a, b, trunc, floor, euclid, rem, rem_euclid) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let to_string = |d: Option<Decimal>| d.map(|d| d.to_string());
+        let expected = |s: Option<&str>| s.map(String::from);
         assert_eq!(
-            c,
-            a.cmp(&b),
-            "{} {} {}",
-            a.to_string(),
-            match c {
-                Less => "<",
-                Equal => "==",
-                Greater => ">",
-            },
-            b.to_string()
+            expected(trunc),
+            to_string(a.checked_div_trunc(b)),
+            "{} div_trunc {}",
+            a,
+            b
+        );
+        assert_eq!(
+            expected(floor),
+            to_string(a.checked_div_floor(b)),
+            "{} div_floor {}",
+            a,
+            b
+        );
+        assert_eq!(
+            expected(euclid),
+            to_string(a.checked_div_euclid(b)),
+            "{} div_euclid {}",
+            a,
+            b
         );
+        assert_eq!(rem_euclid, a.rem_euclid(b).to_string(), "{} rem_euclid {}", a, b);
+        match a.checked_div_rem(b) {
+            Some((quotient, remainder)) => {
+                assert_eq!(trunc.unwrap(), quotient.to_string(), "{} div_rem {}", a, b);
+                assert_eq!(rem, remainder.to_string(), "{} div_rem {}", a, b);
+                assert_eq!(a, quotient * b + remainder);
+            }
+            None => assert!(trunc.is_none(), "{} div_rem {}", a, b),
+        }
+    }
+}
+
+#[test]
+fn it_keeps_the_euclidean_remainder_below_the_divisor() {
+    // -1e-28 + 1e20 can't be represented, and would otherwise round to the divisor itself
+    let tiny = Decimal::new(-1, 28);
+    for &divisor in &[
+        "100000000000000000000",
+        "-100000000000000000000",
+        "79228162514264337593543950335",
+    ] {
+        let divisor = Decimal::from_str(divisor).unwrap();
+        assert_eq!(Decimal::ZERO, tiny.rem_euclid(divisor), "-1e-28 rem_euclid {}", divisor);
     }
+    let a = Decimal::from_str("-0.0000000001").unwrap();
+    let b = Decimal::from_str("1000000000000000000").unwrap();
+    assert_eq!("999999999999999999.9999999999", a.rem_euclid(b).to_string());
+}
+
+#[test]
+fn it_returns_none_for_integer_division_by_zero() {
+    let one = Decimal::ONE;
+    assert_eq!(None, one.checked_div_rem(Decimal::ZERO));
+    assert_eq!(None, one.checked_div_trunc(Decimal::ZERO));
+    assert_eq!(None, one.checked_div_floor(Decimal::ZERO));
+    assert_eq!(None, one.checked_div_euclid(Decimal::ZERO));
+    assert_eq!(None, one.checked_rem_euclid(Decimal::ZERO));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_div_euclid_by_zero() {
+    let _ = Decimal::ONE.div_euclid(Decimal::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "Division overflowed")]
+fn it_panics_when_div_floor_overflows() {
+    let _ = Decimal::MAX.div_floor(Decimal::from_str("0.5").unwrap());
+}
+
+#[test]
+fn it_can_divide_using_num_traits_euclid() {
+    use num_traits::{CheckedEuclid, Euclid};
+
+    let a = Decimal::from_str("-7.5").unwrap();
+    let b = Decimal::from(2);
+    assert_eq!(Decimal::from(-4), Euclid::div_euclid(&a, &b));
+    assert_eq!(Decimal::from_str("0.5").unwrap(), Euclid::rem_euclid(&a, &b));
+    assert_eq!(Some(Decimal::from(-4)), CheckedEuclid::checked_div_euclid(&a, &b));
+    assert_eq!(None, CheckedEuclid::checked_rem_euclid(&a, &Decimal::ZERO));
+}
 
+// Arithmetic with an explicit rounding strategy
+
+#[test]
+fn it_can_mul_with_strategy() {
+    let max = "79228162514264337593543950335";
     let tests = &[
-        ("1", "1", Equal),
-        ("1", "-1", Greater),
-        ("1", "1.00", Equal),
-        ("1.2345000000000", "1.2345", Equal),
         (
-            "1.0000000000000000000000000001",
-            "1.0000000000000000000000000000",
-            Greater,
+            "1.2345",
+            "0.5",
+            3,
+            RoundingStrategy::MidpointAwayFromZero,
+            Some("0.617"),
+        ),
+        ("1.235", "0.5", 3, RoundingStrategy::MidpointNearestEven, Some("0.618")),
+        ("1.235", "0.5", 3, RoundingStrategy::MidpointTowardZero, Some("0.617")),
+        ("1.235", "0.5", 3, RoundingStrategy::MidpointAwayFromZero, Some("0.618")),
+        ("-1.235", "0.5", 3, RoundingStrategy::ToNegativeInfinity, Some("-0.618")),
+        ("-1.235", "0.5", 3, RoundingStrategy::ToPositiveInfinity, Some("-0.617")),
+        ("2.5", "1.5", 4, RoundingStrategy::MidpointNearestEven, Some("3.75")),
+        // The exact product is 0.49999999999999999999999999995 which would round up to 0.5 if first
+        // rounded to 28 decimal places.
+        (
+            "0.9999999999999999999999999999",
+            "0.5",
+            0,
+            RoundingStrategy::MidpointAwayFromZero,
+            Some("0"),
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.0000000000000000000000000001",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("0.0000000000000000000000000000"),
+        ),
+        (
+            "0.0000000000000000000000000001",
+            "0.0000000000000000000000000001",
+            28,
+            RoundingStrategy::ToPositiveInfinity,
+            Some("0.0000000000000000000000000001"),
+        ),
+        (
+            max,
+            "0.5",
+            0,
+            RoundingStrategy::MidpointNearestEven,
+            Some("39614081257132168796771975168"),
+        ),
+        (
+            max,
+            "0.5",
+            0,
+            RoundingStrategy::ToZero,
+            Some("39614081257132168796771975167"),
+        ),
+        (max, "1", 0, RoundingStrategy::ToZero, Some(max)),
+        (max, "1", 28, RoundingStrategy::ToZero, Some(max)),
+        (
+            "1000000",
+            "2",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("2000000"),
+        ),
+        // Fewer decimal places are kept when the integral portion doesn't leave room for them
+        (
+            max,
+            "0.5",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("39614081257132168796771975168"),
+        ),
+        (
+            "100000000000000000000",
+            "1.0000000001234",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("100000000012340000000.00000000"),
+        ),
+        (max, "1.1", 0, RoundingStrategy::ToZero, None),
+        (max, "1.1", 28, RoundingStrategy::ToZero, None),
+    ];
+    for &(a, b, dp, strategy, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = a.checked_mul_with_strategy(b, dp, strategy);
+        assert_eq!(
+            expected,
+            result.map(|d| d.to_string()).as_deref(),
+            "{} * {} ({} dp, {:?})",
+            a,
+            b,
+            dp,
+            strategy
+        );
+        if let Some(expected) = expected {
+            assert_eq!(expected, a.mul_with_strategy(b, dp, strategy).to_string());
+        }
+    }
+}
+
+#[test]
+fn it_can_div_with_strategy() {
+    let tests = &[
+        ("100", "3", 2, RoundingStrategy::ToPositiveInfinity, Some("33.34")),
+        ("100", "3", 2, RoundingStrategy::MidpointNearestEven, Some("33.33")),
+        ("-100", "3", 2, RoundingStrategy::ToNegativeInfinity, Some("-33.34")),
+        ("-100", "-3", 2, RoundingStrategy::ToNegativeInfinity, Some("33.33")),
+        ("1", "8", 2, RoundingStrategy::MidpointNearestEven, Some("0.12")),
+        ("1", "8", 2, RoundingStrategy::MidpointAwayFromZero, Some("0.13")),
+        ("1", "8", 2, RoundingStrategy::ToZero, Some("0.12")),
+        ("1", "2", 4, RoundingStrategy::MidpointNearestEven, Some("0.5")),
+        ("1.00", "2", 4, RoundingStrategy::MidpointNearestEven, Some("0.50")),
+        (
+            "1.5000000000000000000000000000",
+            "1",
+            0,
+            RoundingStrategy::MidpointNearestEven,
+            Some("2"),
         ),
-        ("1.0000000000000000000000000000", "1.0000000000000000000000000001", Less),
-        ("-1", "100", Less),
-        ("-100", "1", Less),
-        ("0", "0.5", Less),
-        ("0.5", "0", Greater),
-        ("100", "0.0098", Greater),
-        ("1000000000000000", "999000000000000.0001", Greater),
-        ("2.0001", "2.0001", Equal),
+        ("0", "3", 2, RoundingStrategy::AwayFromZero, Some("0")),
+        // The exact quotient is 0.49999999999999999999999999997500... which would round up to 0.5
+        // if first rounded to 28 decimal places.
         (
-            "11.815126050420168067226890757",
-            "0.6386554621848739495798319328",
-            Greater,
+            "1",
+            "2.0000000000000000000000000001",
+            0,
+            RoundingStrategy::MidpointAwayFromZero,
+            Some("0"),
         ),
-        ("0.6386554621848739495798319328", "11.815126050420168067226890757", Less),
-        ("-0.5", "-0.01", Less),
-        ("-0.5", "-0.1", Less),
-        ("-0.01", "-0.5", Greater),
-        ("-0.1", "-0.5", Greater),
-        // 000 equality
-        ("0.00000000", "0.00000000", Equal),
-        // 000 000 same scale
-        ("0.00000000", "0.00000000", Equal),
-        ("-0.00000000", "0.00000000", Equal),
-        ("0.00000000", "-0.00000000", Equal),
-        ("-0.00000000", "-0.00000000", Equal),
-        // 000 000 different scale
-        ("0.000000000", "0.00000000000000000000000", Equal),
-        ("-0.000000000", "0.00000000000000000000000", Equal),
-        ("0.000000000", "-0.00000000000000000000000", Equal),
-        ("-0.000000000", "-0.00000000000000000000000", Equal),
-        // 000 100 same scale
-        ("0.00000000", "6.56792910", Less),
-        ("-0.00000000", "6.56792910", Less),
-        ("0.00000000", "-6.56792910", Greater),
-        ("-0.00000000", "-6.56792910", Greater),
-        // 000 100 different scale
-        ("0.0000000000000000000", "0.00000000000000001916236746", Less),
-        ("-0.0000000000000000000", "0.00000000000000001916236746", Less),
-        ("0.0000000000000000000", "-0.00000000000000001916236746", Greater),
-        ("-0.0000000000000000000", "-0.00000000000000001916236746", Greater),
-        // 000 010 same scale
-        ("0.00000000", "49037796231.72571136", Less),
-        ("-0.00000000", "49037796231.72571136", Less),
-        ("0.00000000", "-49037796231.72571136", Greater),
-        ("-0.00000000", "-49037796231.72571136", Greater),
-        // 000 010 different scale
-        ("0", "14459264155.12895488", Less),
-        ("-0", "14459264155.12895488", Less),
-        ("0", "-14459264155.12895488", Greater),
-        ("-0", "-14459264155.12895488", Greater),
-        // 000 110 same scale
-        ("0.00000000", "38675108055.09052783", Less),
-        ("-0.00000000", "38675108055.09052783", Less),
-        ("0.00000000", "-38675108055.09052783", Greater),
-        ("-0.00000000", "-38675108055.09052783", Greater),
-        // 000 110 different scale
-        ("0.00", "1495767034080324868", Less),
-        ("-0.00", "1495767034080324868", Less),
-        ("0.00", "-1495767034080324868", Greater),
-        ("-0.00", "-1495767034080324868", Greater),
-        // 000 001 same scale
-        ("0.00000000", "359299289270893106016.81305600", Less),
-        ("-0.00000000", "359299289270893106016.81305600", Less),
-        ("0.00000000", "-359299289270893106016.81305600", Greater),
-        ("-0.00000000", "-359299289270893106016.81305600", Greater),
-        // 000 001 different scale
-        ("0.00000000000000000000000000", "261631091689.9518486763536384", Less),
-        ("-0.00000000000000000000000000", "261631091689.9518486763536384", Less),
         (
-            "0.00000000000000000000000000",
-            "-261631091689.9518486763536384",
-            Greater,
+            "0.0000000000000000000000000001",
+            "7.9228162514264337593543950335",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("0.0000000000000000000000000000"),
         ),
         (
-            "-0.00000000000000000000000000",
-            "-261631091689.9518486763536384",
-            Greater,
+            "0.0000000000000000000000000001",
+            "7.9228162514264337593543950335",
+            28,
+            RoundingStrategy::ToPositiveInfinity,
+            Some("0.0000000000000000000000000001"),
         ),
-        // 000 101 same scale
-        ("0.00000000", "184137107696737410476.63166815", Less),
-        ("-0.00000000", "184137107696737410476.63166815", Less),
-        ("0.00000000", "-184137107696737410476.63166815", Greater),
-        ("-0.00000000", "-184137107696737410476.63166815", Greater),
-        // 000 101 different scale
-        ("0.000000000", "2286857871088.7514840434334478", Less),
-        ("-0.000000000", "2286857871088.7514840434334478", Less),
-        ("0.000000000", "-2286857871088.7514840434334478", Greater),
-        ("-0.000000000", "-2286857871088.7514840434334478", Greater),
-        // 000 011 same scale
-        ("0.00000000", "169194696640288819908.07715840", Less),
-        ("-0.00000000", "169194696640288819908.07715840", Less),
-        ("0.00000000", "-169194696640288819908.07715840", Greater),
-        ("-0.00000000", "-169194696640288819908.07715840", Greater),
-        // 000 011 different scale
-        ("0.00000000", "2757550691.7650076909569048576", Less),
-        ("-0.00000000", "2757550691.7650076909569048576", Less),
-        ("0.00000000", "-2757550691.7650076909569048576", Greater),
-        ("-0.00000000", "-2757550691.7650076909569048576", Greater),
-        // 000 111 same scale
-        ("0.00000000", "133610725292915899001.10059212", Less),
-        ("-0.00000000", "133610725292915899001.10059212", Less),
-        ("0.00000000", "-133610725292915899001.10059212", Greater),
-        ("-0.00000000", "-133610725292915899001.10059212", Greater),
-        // 000 111 different scale
-        ("0.00000000000000000000", "86.25432767926620368822165265", Less),
-        ("-0.00000000000000000000", "86.25432767926620368822165265", Less),
-        ("0.00000000000000000000", "-86.25432767926620368822165265", Greater),
-        ("-0.00000000000000000000", "-86.25432767926620368822165265", Greater),
-        // 100 equality
-        ("0.0000000000598992228", "0.0000000000598992228", Equal),
-        // 100 000 same scale
-        ("0.0000000000598992228", "0.0000000000000000000", Greater),
-        ("-0.0000000000598992228", "0.0000000000000000000", Less),
-        ("0.0000000000598992228", "-0.0000000000000000000", Greater),
-        ("-0.0000000000598992228", "-0.0000000000000000000", Less),
-        // 100 000 different scale
-        ("0.1797407597", "0.0000000000000000000", Greater),
-        ("-0.1797407597", "0.0000000000000000000", Less),
-        ("0.1797407597", "-0.0000000000000000000", Greater),
-        ("-0.1797407597", "-0.0000000000000000000", Less),
-        // 100 100 same scale
-        ("0.0000000000598992228", "0.0000000000064510789", Greater),
-        ("-0.0000000000598992228", "0.0000000000064510789", Less),
-        ("0.0000000000598992228", "-0.0000000000064510789", Greater),
-        ("-0.0000000000598992228", "-0.0000000000064510789", Less),
-        // 100 100 different scale
-        ("0.000000000000011217354", "0.0000000000217735186", Less),
-        ("-0.000000000000011217354", "0.0000000000217735186", Less),
-        ("0.000000000000011217354", "-0.0000000000217735186", Greater),
-        ("-0.000000000000011217354", "-0.0000000000217735186", Greater),
-        // 100 010 same scale
-        ("0.0000000000598992228", "0.0659116848159129600", Less),
-        ("-0.0000000000598992228", "0.0659116848159129600", Less),
-        ("0.0000000000598992228", "-0.0659116848159129600", Greater),
-        ("-0.0000000000598992228", "-0.0659116848159129600", Greater),
-        // 100 010 different scale
-        ("0.00042035421", "0.004709460588143575040", Less),
-        ("-0.00042035421", "0.004709460588143575040", Less),
-        ("0.00042035421", "-0.004709460588143575040", Greater),
-        ("-0.00042035421", "-0.004709460588143575040", Greater),
-        // 100 110 same scale
-        ("0.0000000000598992228", "0.0755686585127091375", Less),
-        ("-0.0000000000598992228", "0.0755686585127091375", Less),
-        ("0.0000000000598992228", "-0.0755686585127091375", Greater),
-        ("-0.0000000000598992228", "-0.0755686585127091375", Greater),
-        // 100 110 different scale
-        ("14872.94465", "1284707831905.854085", Less),
-        ("-14872.94465", "1284707831905.854085", Less),
-        ("14872.94465", "-1284707831905.854085", Greater),
-        ("-14872.94465", "-1284707831905.854085", Greater),
-        // 100 001 same scale
-        ("0.0000000000598992228", "888767595.6145376468836286464", Less),
-        ("-0.0000000000598992228", "888767595.6145376468836286464", Less),
-        ("0.0000000000598992228", "-888767595.6145376468836286464", Greater),
-        ("-0.0000000000598992228", "-888767595.6145376468836286464", Greater),
-        // 100 001 different scale
-        ("0.0002108155975", "36.07555527243968476014968832", Less),
-        ("-0.0002108155975", "36.07555527243968476014968832", Less),
-        ("0.0002108155975", "-36.07555527243968476014968832", Greater),
-        ("-0.0002108155975", "-36.07555527243968476014968832", Greater),
-        // 100 101 same scale
-        ("0.0000000000598992228", "125730345.7412344676309569911", Less),
-        ("-0.0000000000598992228", "125730345.7412344676309569911", Less),
-        ("0.0000000000598992228", "-125730345.7412344676309569911", Greater),
-        ("-0.0000000000598992228", "-125730345.7412344676309569911", Greater),
-        // 100 101 different scale
-        ("0.0000000000871576741", "1.7283925558865766140690239662", Less),
-        ("-0.0000000000871576741", "1.7283925558865766140690239662", Less),
-        ("0.0000000000871576741", "-1.7283925558865766140690239662", Greater),
-        ("-0.0000000000871576741", "-1.7283925558865766140690239662", Greater),
-        // 100 011 same scale
-        ("0.0000000000598992228", "645513262.9193254090737451008", Less),
-        ("-0.0000000000598992228", "645513262.9193254090737451008", Less),
-        ("0.0000000000598992228", "-645513262.9193254090737451008", Greater),
-        ("-0.0000000000598992228", "-645513262.9193254090737451008", Greater),
-        // 100 011 different scale
-        ("0.000000000000000760885021", "3718370638.2004059326675681280", Less),
-        ("-0.000000000000000760885021", "3718370638.2004059326675681280", Less),
-        ("0.000000000000000760885021", "-3718370638.2004059326675681280", Greater),
         (
-            "-0.000000000000000760885021",
-            "-3718370638.2004059326675681280",
-            Greater,
+            "79228162514264337593543950335",
+            "3",
+            0,
+            RoundingStrategy::MidpointNearestEven,
+            Some("26409387504754779197847983445"),
         ),
-        // 100 111 same scale
-        ("0.0000000000598992228", "422482675.5515775479939306437", Less),
-        ("-0.0000000000598992228", "422482675.5515775479939306437", Less),
-        ("0.0000000000598992228", "-422482675.5515775479939306437", Greater),
-        ("-0.0000000000598992228", "-422482675.5515775479939306437", Greater),
-        // 100 111 different scale
-        ("0.000000044182898", "25.452953262109919998605674045", Less),
-        ("-0.000000044182898", "25.452953262109919998605674045", Less),
-        ("0.000000044182898", "-25.452953262109919998605674045", Greater),
-        ("-0.000000044182898", "-25.452953262109919998605674045", Greater),
-        // 010 equality
-        ("423.1744746042687488", "423.1744746042687488", Equal),
-        // 010 000 same scale
-        ("423.1744746042687488", "0.0000000000000000", Greater),
-        ("-423.1744746042687488", "0.0000000000000000", Less),
-        ("423.1744746042687488", "-0.0000000000000000", Greater),
-        ("-423.1744746042687488", "-0.0000000000000000", Less),
-        // 010 000 different scale
-        ("9002354991.192604672", "0.00000000000000000000000000", Greater),
-        ("-9002354991.192604672", "0.00000000000000000000000000", Less),
-        ("9002354991.192604672", "-0.00000000000000000000000000", Greater),
-        ("-9002354991.192604672", "-0.00000000000000000000000000", Less),
-        // 010 100 same scale
-        ("423.1744746042687488", "0.0000000981820809", Greater),
-        ("-423.1744746042687488", "0.0000000981820809", Less),
-        ("423.1744746042687488", "-0.0000000981820809", Greater),
-        ("-423.1744746042687488", "-0.0000000981820809", Less),
-        // 010 100 different scale
-        ("4327019125101559.808", "0.00484846050", Greater),
-        ("-4327019125101559.808", "0.00484846050", Less),
-        ("4327019125101559.808", "-0.00484846050", Greater),
-        ("-4327019125101559.808", "-0.00484846050", Less),
-        // 010 010 same scale
-        ("423.1744746042687488", "786.9082854590775296", Less),
-        ("-423.1744746042687488", "786.9082854590775296", Less),
-        ("423.1744746042687488", "-786.9082854590775296", Greater),
-        ("-423.1744746042687488", "-786.9082854590775296", Greater),
-        // 010 010 different scale
-        ("793.0067125291450368", "0.0001587297248335626240", Greater),
-        ("-793.0067125291450368", "0.0001587297248335626240", Less),
-        ("793.0067125291450368", "-0.0001587297248335626240", Greater),
-        ("-793.0067125291450368", "-0.0001587297248335626240", Less),
-        // 010 110 same scale
-        ("423.1744746042687488", "300.0541360230572049", Greater),
-        ("-423.1744746042687488", "300.0541360230572049", Less),
-        ("423.1744746042687488", "-300.0541360230572049", Greater),
-        ("-423.1744746042687488", "-300.0541360230572049", Less),
-        // 010 110 different scale
-        ("90627.2042582540288", "4472414566654924.741", Less),
-        ("-90627.2042582540288", "4472414566654924.741", Less),
-        ("90627.2042582540288", "-4472414566654924.741", Greater),
-        ("-90627.2042582540288", "-4472414566654924.741", Greater),
-        // 010 001 same scale
-        ("423.1744746042687488", "3960577151543.5796707636412416", Less),
-        ("-423.1744746042687488", "3960577151543.5796707636412416", Less),
-        ("423.1744746042687488", "-3960577151543.5796707636412416", Greater),
-        ("-423.1744746042687488", "-3960577151543.5796707636412416", Greater),
-        // 010 001 different scale
-        ("0.000008867461591822499840", "185286.04378228713249986052096", Less),
-        ("-0.000008867461591822499840", "185286.04378228713249986052096", Less),
-        ("0.000008867461591822499840", "-185286.04378228713249986052096", Greater),
+        // Fewer decimal places are kept when the integral portion doesn't leave room for them
         (
-            "-0.000008867461591822499840",
-            "-185286.04378228713249986052096",
-            Greater,
+            "1",
+            "0.0000000000000000000000000001",
+            28,
+            RoundingStrategy::ToZero,
+            Some("10000000000000000000000000000"),
         ),
-        // 010 101 same scale
-        ("423.1744746042687488", "2825958416017.6213507229869501", Less),
-        ("-423.1744746042687488", "2825958416017.6213507229869501", Less),
-        ("423.1744746042687488", "-2825958416017.6213507229869501", Greater),
-        ("-423.1744746042687488", "-2825958416017.6213507229869501", Greater),
-        // 010 101 different scale
-        ("0.01901870767742648320", "37662082383021542232.529651212", Less),
-        ("-0.01901870767742648320", "37662082383021542232.529651212", Less),
-        ("0.01901870767742648320", "-37662082383021542232.529651212", Greater),
-        ("-0.01901870767742648320", "-37662082383021542232.529651212", Greater),
-        // 010 011 same scale
-        ("423.1744746042687488", "3628063966991.6759417059016704", Less),
-        ("-423.1744746042687488", "3628063966991.6759417059016704", Less),
-        ("423.1744746042687488", "-3628063966991.6759417059016704", Greater),
-        ("-423.1744746042687488", "-3628063966991.6759417059016704", Greater),
-        // 010 011 different scale
-        ("45359904.32470925312", "2.4203452488052342918570049536", Greater),
-        ("-45359904.32470925312", "2.4203452488052342918570049536", Less),
-        ("45359904.32470925312", "-2.4203452488052342918570049536", Greater),
-        ("-45359904.32470925312", "-2.4203452488052342918570049536", Less),
-        // 010 111 same scale
-        ("423.1744746042687488", "2629665172331.9610693820109120", Less),
-        ("-423.1744746042687488", "2629665172331.9610693820109120", Less),
-        ("423.1744746042687488", "-2629665172331.9610693820109120", Greater),
-        ("-423.1744746042687488", "-2629665172331.9610693820109120", Greater),
-        // 010 111 different scale
-        ("0.0006420803252266205184", "8172.032417576265900588945489", Less),
-        ("-0.0006420803252266205184", "8172.032417576265900588945489", Less),
-        ("0.0006420803252266205184", "-8172.032417576265900588945489", Greater),
-        ("-0.0006420803252266205184", "-8172.032417576265900588945489", Greater),
-        // 110 equality
-        ("844.5530620517286511", "844.5530620517286511", Equal),
-        // 110 000 same scale
-        ("844.5530620517286511", "0.0000000000000000", Greater),
-        ("-844.5530620517286511", "0.0000000000000000", Less),
-        ("844.5530620517286511", "-0.0000000000000000", Greater),
-        ("-844.5530620517286511", "-0.0000000000000000", Less),
-        // 110 000 different scale
-        ("3285.530033386074797", "0.00000000000000000000", Greater),
-        ("-3285.530033386074797", "0.00000000000000000000", Less),
-        ("3285.530033386074797", "-0.00000000000000000000", Greater),
-        ("-3285.530033386074797", "-0.00000000000000000000", Less),
-        // 110 100 same scale
-        ("844.5530620517286511", "0.0000001953470063", Greater),
-        ("-844.5530620517286511", "0.0000001953470063", Less),
-        ("844.5530620517286511", "-0.0000001953470063", Greater),
-        ("-844.5530620517286511", "-0.0000001953470063", Less),
-        // 110 100 different scale
-        ("371284.0210972493371", "0.000000000000001307794657", Greater),
-        ("-371284.0210972493371", "0.000000000000001307794657", Less),
-        ("371284.0210972493371", "-0.000000000000001307794657", Greater),
-        ("-371284.0210972493371", "-0.000000000000001307794657", Less),
-        // 110 010 same scale
-        ("844.5530620517286511", "612.1542773033140224", Greater),
-        ("-844.5530620517286511", "612.1542773033140224", Less),
-        ("844.5530620517286511", "-612.1542773033140224", Greater),
-        ("-844.5530620517286511", "-612.1542773033140224", Less),
-        // 110 010 different scale
-        ("0.00004869219159821525572", "23341676485159.15776", Less),
-        ("-0.00004869219159821525572", "23341676485159.15776", Less),
-        ("0.00004869219159821525572", "-23341676485159.15776", Greater),
-        ("-0.00004869219159821525572", "-23341676485159.15776", Greater),
-        // 110 110 same scale
-        ("844.5530620517286511", "326.6132818317622015", Greater),
-        ("-844.5530620517286511", "326.6132818317622015", Less),
-        ("844.5530620517286511", "-326.6132818317622015", Greater),
-        ("-844.5530620517286511", "-326.6132818317622015", Less),
-        // 110 110 different scale
-        ("4752139369958.820619", "1330851022882027.972", Less),
-        ("-4752139369958.820619", "1330851022882027.972", Less),
-        ("4752139369958.820619", "-1330851022882027.972", Greater),
-        ("-4752139369958.820619", "-1330851022882027.972", Greater),
-        // 110 001 same scale
-        ("844.5530620517286511", "3585610241942.1922435648192512", Less),
-        ("-844.5530620517286511", "3585610241942.1922435648192512", Less),
-        ("844.5530620517286511", "-3585610241942.1922435648192512", Greater),
-        ("-844.5530620517286511", "-3585610241942.1922435648192512", Greater),
-        // 110 001 different scale
-        ("539313715923.4424678", "12410950080603997079634706432", Less),
-        ("-539313715923.4424678", "12410950080603997079634706432", Less),
-        ("539313715923.4424678", "-12410950080603997079634706432", Greater),
-        ("-539313715923.4424678", "-12410950080603997079634706432", Greater),
-        // 110 101 same scale
-        ("844.5530620517286511", "1947825396031.6933708908343230", Less),
-        ("-844.5530620517286511", "1947825396031.6933708908343230", Less),
-        ("844.5530620517286511", "-1947825396031.6933708908343230", Greater),
-        ("-844.5530620517286511", "-1947825396031.6933708908343230", Greater),
-        // 110 101 different scale
-        ("0.2301405445512525317", "245433629587.71206426704897154", Less),
-        ("-0.2301405445512525317", "245433629587.71206426704897154", Less),
-        ("0.2301405445512525317", "-245433629587.71206426704897154", Greater),
-        ("-0.2301405445512525317", "-245433629587.71206426704897154", Greater),
-        // 110 011 same scale
-        ("844.5530620517286511", "3637850451015.0843464291450880", Less),
-        ("-844.5530620517286511", "3637850451015.0843464291450880", Less),
-        ("844.5530620517286511", "-3637850451015.0843464291450880", Greater),
-        ("-844.5530620517286511", "-3637850451015.0843464291450880", Greater),
-        // 110 011 different scale
-        ("0.00000000717944802566514691", "18143443615.480512395717115904", Less),
-        ("-0.00000000717944802566514691", "18143443615.480512395717115904", Less),
         (
-            "0.00000000717944802566514691",
-            "-18143443615.480512395717115904",
-            Greater,
+            "79228162514264337593543950335",
+            "1",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("79228162514264337593543950335"),
         ),
         (
-            "-0.00000000717944802566514691",
-            "-18143443615.480512395717115904",
-            Greater,
+            "1000000",
+            "2",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("500000"),
         ),
-        // 110 111 same scale
-        ("844.5530620517286511", "2738424264600.4917875777303163", Less),
-        ("-844.5530620517286511", "2738424264600.4917875777303163", Less),
-        ("844.5530620517286511", "-2738424264600.4917875777303163", Greater),
-        ("-844.5530620517286511", "-2738424264600.4917875777303163", Greater),
-        // 110 111 different scale
-        ("0.0000000007762706076409491335", "2489879185787497651.6458518595", Less),
         (
-            "-0.0000000007762706076409491335",
-            "2489879185787497651.6458518595",
-            Less,
+            "2000000",
+            "3",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("666666.66666666666666666666667"),
         ),
         (
-            "0.0000000007762706076409491335",
-            "-2489879185787497651.6458518595",
-            Greater,
+            "2000000",
+            "3",
+            28,
+            RoundingStrategy::ToZero,
+            Some("666666.66666666666666666666666"),
         ),
         (
-            "-0.0000000007762706076409491335",
-            "-2489879185787497651.6458518595",
-            Greater,
+            "79228162514264337593543950335",
+            "1.5",
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            Some("52818775009509558395695966890"),
         ),
-        // 001 equality
-        ("316007568232.9263258873102336", "316007568232.9263258873102336", Equal),
-        // 001 000 same scale
-        ("316007568232.9263258873102336", "0.0000000000000000", Greater),
-        ("-316007568232.9263258873102336", "0.0000000000000000", Less),
-        ("316007568232.9263258873102336", "-0.0000000000000000", Greater),
-        ("-316007568232.9263258873102336", "-0.0000000000000000", Less),
-        // 001 000 different scale
-        ("3522055990024364385815547084.8", "0.000000000000", Greater),
-        ("-3522055990024364385815547084.8", "0.000000000000", Less),
-        ("3522055990024364385815547084.8", "-0.000000000000", Greater),
-        ("-3522055990024364385815547084.8", "-0.000000000000", Less),
-        // 001 100 same scale
-        ("316007568232.9263258873102336", "0.0000001073412971", Greater),
-        ("-316007568232.9263258873102336", "0.0000001073412971", Less),
-        ("316007568232.9263258873102336", "-0.0000001073412971", Greater),
-        ("-316007568232.9263258873102336", "-0.0000001073412971", Less),
-        // 001 100 different scale
-        ("1319006.0491408208640440532992", "0.00000000000000611866432", Greater),
-        ("-1319006.0491408208640440532992", "0.00000000000000611866432", Less),
-        ("1319006.0491408208640440532992", "-0.00000000000000611866432", Greater),
-        ("-1319006.0491408208640440532992", "-0.00000000000000611866432", Less),
-        // 001 010 same scale
-        ("316007568232.9263258873102336", "159.1054215143227392", Greater),
-        ("-316007568232.9263258873102336", "159.1054215143227392", Less),
-        ("316007568232.9263258873102336", "-159.1054215143227392", Greater),
-        ("-316007568232.9263258873102336", "-159.1054215143227392", Less),
-        // 001 010 different scale
-        ("2470144.7146711063666704252928", "211.0186916505714688", Greater),
-        ("-2470144.7146711063666704252928", "211.0186916505714688", Less),
-        ("2470144.7146711063666704252928", "-211.0186916505714688", Greater),
-        ("-2470144.7146711063666704252928", "-211.0186916505714688", Less),
-        // 001 110 same scale
-        ("316007568232.9263258873102336", "15.1186658969096112", Greater),
-        ("-316007568232.9263258873102336", "15.1186658969096112", Less),
-        ("316007568232.9263258873102336", "-15.1186658969096112", Greater),
-        ("-316007568232.9263258873102336", "-15.1186658969096112", Less),
-        // 001 110 different scale
-        ("3840504199004148630832.3360768", "7581138850996748864", Greater),
-        ("-3840504199004148630832.3360768", "7581138850996748864", Less),
-        ("3840504199004148630832.3360768", "-7581138850996748864", Greater),
-        ("-3840504199004148630832.3360768", "-7581138850996748864", Less),
-        // 001 001 same scale
-        ("316007568232.9263258873102336", "810157633226.6053390856880128", Less),
-        ("-316007568232.9263258873102336", "810157633226.6053390856880128", Less),
         (
-            "316007568232.9263258873102336",
-            "-810157633226.6053390856880128",
-            Greater,
+            "79228162514264337593543950335",
+            "0.1",
+            0,
+            RoundingStrategy::ToZero,
+            None,
         ),
+        ("1", "0", 2, RoundingStrategy::ToZero, None),
+    ];
+    for &(a, b, dp, strategy, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = a.checked_div_with_strategy(b, dp, strategy);
+        assert_eq!(
+            expected,
+            result.map(|d| d.to_string()).as_deref(),
+            "{} / {} ({} dp, {:?})",
+            a,
+            b,
+            dp,
+            strategy
+        );
+        if let Some(expected) = expected {
+            assert_eq!(expected, a.div_with_strategy(b, dp, strategy).to_string());
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn it_panics_when_div_with_strategy_by_zero() {
+    let _ = Decimal::ONE.div_with_strategy(Decimal::ZERO, 2, RoundingStrategy::MidpointNearestEven);
+}
+
+// Exact arithmetic
+
+#[test]
+fn it_can_add_and_sub_exactly() {
+    let max = "79228162514264337593543950335";
+    let tests = &[
+        ("1", "2", Ok("3")),
+        ("0.1", "0.2", Ok("0.3")),
+        ("-0.1", "0.2", Ok("0.1")),
         (
-            "-316007568232.9263258873102336",
-            "-810157633226.6053390856880128",
-            Greater,
+            "5.0000000000000000000000000000",
+            "5",
+            Ok("10.000000000000000000000000000"),
         ),
-        // 001 001 different scale
-        ("1951046382014.4037956952260608", "3626102772868740412010083.1232", Less),
         (
-            "-1951046382014.4037956952260608",
-            "3626102772868740412010083.1232",
-            Less,
+            "1",
+            "-0.0000000000000000000000000001",
+            Ok("0.9999999999999999999999999999"),
         ),
         (
-            "1951046382014.4037956952260608",
-            "-3626102772868740412010083.1232",
-            Greater,
+            "1",
+            "0.0000000000000000000000000001",
+            Ok("1.0000000000000000000000000001"),
         ),
+        ("10", "0.0000000000000000000000000001", Err(ErrorKind::Inexact)),
+        (max, "-0.5", Err(ErrorKind::Inexact)),
+        (max, "1", Err(ErrorKind::ExceedsMaximumPossibleValue)),
         (
-            "-1951046382014.4037956952260608",
-            "-3626102772868740412010083.1232",
-            Greater,
+            "-79228162514264337593543950335",
+            "-1",
+            Err(ErrorKind::LessThanMinimumPossibleValue),
         ),
-        // 001 101 same scale
-        ("316007568232.9263258873102336", "3258394380359.1965879291312453", Less),
-        ("-316007568232.9263258873102336", "3258394380359.1965879291312453", Less),
+    ];
+    for &(a, b, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = a.checked_add_exact(b).map(|d| d.to_string()).map_err(|e| e.kind());
+        assert_eq!(expected.map(String::from), result, "{} + {}", a, b);
+        let result = a.checked_sub_exact(-b).map(|d| d.to_string()).map_err(|e| e.kind());
+        assert_eq!(expected.map(String::from), result, "{} - {}", a, -b);
+    }
+}
+
+#[test]
+fn it_can_mul_exactly() {
+    let max = "79228162514264337593543950335";
+    let tests = &[
+        ("1.5", "1.5", Ok("2.25")),
+        ("-0.5", "0.5", Ok("-0.25")),
         (
-            "316007568232.9263258873102336",
-            "-3258394380359.1965879291312453",
-            Greater,
+            "10000000000000000000000000000",
+            "0.0000000000000000000000000001",
+            Ok("1.0000000000000000000000000000"),
         ),
+        ("0.0000000000001", "0.0000000000000001", Err(ErrorKind::Inexact)),
+        (max, "0.5", Err(ErrorKind::Inexact)),
+        (max, "2", Err(ErrorKind::ExceedsMaximumPossibleValue)),
+        (max, "-2", Err(ErrorKind::LessThanMinimumPossibleValue)),
+    ];
+    for &(a, b, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = a.checked_mul_exact(b).map(|d| d.to_string()).map_err(|e| e.kind());
+        assert_eq!(expected.map(String::from), result, "{} * {}", a, b);
+    }
+}
+
+#[test]
+fn it_can_div_exactly() {
+    let tests = &[
+        ("100", "4", Ok("25")),
+        ("1", "8", Ok("0.125")),
         (
-            "-316007568232.9263258873102336",
-            "-3258394380359.1965879291312453",
-            Greater,
+            "-1",
+            "0.0000000000000000000000000001",
+            Ok("-10000000000000000000000000000"),
         ),
-        // 001 101 different scale
+        ("100", "3", Err(ErrorKind::Inexact)),
+        ("1", "7.9228162514264337593543950335", Err(ErrorKind::Inexact)),
+        ("1", "0", Err(ErrorKind::DivisionByZero)),
         (
-            "17580513970289834.943527780352",
-            "3.7977957031395371036126086595",
-            Greater,
+            "79228162514264337593543950335",
+            "0.1",
+            Err(ErrorKind::ExceedsMaximumPossibleValue),
         ),
         (
-            "-17580513970289834.943527780352",
-            "3.7977957031395371036126086595",
-            Less,
+            "79228162514264337593543950335",
+            "-0.1",
+            Err(ErrorKind::LessThanMinimumPossibleValue),
         ),
+    ];
+    for &(a, b, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = a.checked_div_exact(b).map(|d| d.to_string()).map_err(|e| e.kind());
+        assert_eq!(expected.map(String::from), result, "{} / {}", a, b);
+    }
+}
+
+// Exact accumulation
+
+#[test]
+fn it_can_accumulate_exactly() {
+    let max = "79228162514264337593543950335";
+    type Expected = Result<(&'static str, bool), ErrorKind>;
+    let tests: &[(&[&str], Expected)] = &[
+        (&[], Ok(("0", true))),
+        (&["0.1", "0.2"], Ok(("0.3", true))),
+        (&["1.5", "-2.25", "0.75"], Ok(("0.00", true))),
         (
-            "17580513970289834.943527780352",
-            "-3.7977957031395371036126086595",
-            Greater,
+            &["10000000000000000000000000000", "0.4", "-10000000000000000000000000000"],
+            Ok(("0.4", true)),
         ),
         (
-            "-17580513970289834.943527780352",
-            "-3.7977957031395371036126086595",
-            Less,
+            &["10000000000000000000000000000", "0.4", "0.4"],
+            Ok(("10000000000000000000000000001", false)),
         ),
-        // 001 011 same scale
-        ("316007568232.9263258873102336", "1154574080460.9867510617997312", Less),
-        ("-316007568232.9263258873102336", "1154574080460.9867510617997312", Less),
         (
-            "316007568232.9263258873102336",
-            "-1154574080460.9867510617997312",
-            Greater,
+            &["1", "0.0000000000000000000000000001", "0.0000000000000000000000000001"],
+            Ok(("1.0000000000000000000000000002", true)),
         ),
         (
-            "-316007568232.9263258873102336",
-            "-1154574080460.9867510617997312",
-            Greater,
+            &["10", "0.0000000000000000000000000005"],
+            Ok(("10.000000000000000000000000000", false)),
         ),
-        // 001 011 different scale
-        ("2008379587.5525351789031325696", "32824109460554.341800487157760", Less),
+        (&[max, "1"], Err(ErrorKind::ExceedsMaximumPossibleValue)),
+        (&[max, "1", "-1"], Ok((max, true))),
         (
-            "-2008379587.5525351789031325696",
-            "32824109460554.341800487157760",
-            Less,
+            &["-79228162514264337593543950335", "-1"],
+            Err(ErrorKind::LessThanMinimumPossibleValue),
         ),
-        (
-            "2008379587.5525351789031325696",
-            "-32824109460554.341800487157760",
-            Greater,
+    ];
+    for &(values, expected) in tests {
+        let values = values.iter().map(|v| Decimal::from_str(v).unwrap()).collect::<Vec<_>>();
+        let accumulator = values.iter().collect::<DecimalAccumulator>();
+        let result = accumulator
+            .finish()
+            .map(|(sum, exact)| (sum.to_string(), exact))
+            .map_err(|e| e.kind());
+        assert_eq!(
+            expected.map(|(sum, exact)| (sum.to_string(), exact)),
+            result,
+            "{:?}",
+            values
+        );
+    }
+}
+
+#[test]
+fn it_can_accumulate_subtractions() {
+    let mut accumulator = DecimalAccumulator::new();
+    accumulator.sub(Decimal::from_str("-10000000000000000000000000000").unwrap());
+    accumulator -= Decimal::from_str("0.05").unwrap();
+    accumulator += Decimal::from_str("0.1").unwrap();
+    accumulator -= &Decimal::from_str("0.02").unwrap();
+    assert_eq!(2, accumulator.scale());
+    assert!(!accumulator.is_zero());
+
+    let (sum, exact) = accumulator.finish().unwrap();
+    assert_eq!("10000000000000000000000000000", sum.to_string());
+    assert!(!exact);
+    let (sum, exact) = accumulator
+        .finish_with_strategy(RoundingStrategy::AwayFromZero)
+        .unwrap();
+    assert_eq!("10000000000000000000000000001", sum.to_string());
+    assert!(!exact);
+
+    accumulator.extend(vec![Decimal::from_str("-10000000000000000000000000000").unwrap()]);
+    assert_eq!(
+        ("0.03".to_string(), true),
+        accumulator.finish().map(|(s, e)| (s.to_string(), e)).unwrap()
+    );
+    accumulator.sub(Decimal::from_str("0.03").unwrap());
+    assert!(accumulator.is_zero());
+}
+
+// Arithmetic within a context
+
+#[test]
+fn it_can_round_to_the_precision_of_a_context() {
+    #[derive(Clone, Copy)]
+    enum Op {
+        Add,
+        Sub,
+        Mul,
+        Div,
+    }
+    use Op::*;
+    let tests = &[
+        (28, Add, "1", "2", "3", &[][..]),
+        (5, Add, "12345.6", "0", "12346", &[Signal::Rounded, Signal::Inexact][..]),
+        (
+            5,
+            Add,
+            "12345678",
+            "0",
+            "12346000",
+            &[Signal::Rounded, Signal::Inexact][..],
+        ),
+        (
+            5,
+            Sub,
+            "1",
+            "0.000011",
+            "0.99999",
+            &[Signal::Rounded, Signal::Inexact][..],
+        ),
+        (5, Sub, "99999.7", "0.7", "99999", &[Signal::Rounded][..]),
+        (
+            5,
+            Add,
+            "99999.7",
+            "0",
+            "100000",
+            &[Signal::Rounded, Signal::Inexact][..],
+        ),
+        (2, Mul, "1.5", "1.5", "2.2", &[Signal::Rounded, Signal::Inexact][..]),
+        (2, Mul, "1.0", "1.0", "1.0", &[Signal::Rounded][..]),
+        (
+            28,
+            Mul,
+            "0.00000000000001",
+            "0.000000000000001",
+            "0.0000000000000000000000000000",
+            &[Signal::Clamped, Signal::Rounded, Signal::Inexact][..],
+        ),
+        (28, Div, "1", "4", "0.25", &[][..]),
+        (28, Div, "1.00", "4", "0.25", &[][..]),
+        (28, Div, "1.000", "4", "0.250", &[][..]),
+        (28, Div, "10", "4", "2.5", &[][..]),
+        (28, Div, "6", "2", "3", &[][..]),
+        (
+            28,
+            Div,
+            "1",
+            "3",
+            "0.3333333333333333333333333333",
+            &[Signal::Rounded, Signal::Inexact][..],
+        ),
+        (
+            28,
+            Div,
+            "-2",
+            "3",
+            "-0.6666666666666666666666666667",
+            &[Signal::Rounded, Signal::Inexact][..],
+        ),
+        (
+            28,
+            Div,
+            "0.0001",
+            "3",
+            "0.0000333333333333333333333333",
+            &[Signal::Clamped, Signal::Rounded, Signal::Inexact][..],
+        ),
+        (3, Div, "200", "3", "66.7", &[Signal::Rounded, Signal::Inexact][..]),
+        (3, Div, "200000", "3", "66700", &[Signal::Rounded, Signal::Inexact][..]),
+    ];
+    for &(precision, op, a, b, expected, signals) in tests {
+        let mut ctx = Context::new(precision, RoundingStrategy::MidpointNearestEven).unwrap();
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let result = match op {
+            Add => ctx.add(a, b),
+            Sub => ctx.sub(a, b),
+            Mul => ctx.mul(a, b),
+            Div => ctx.div(a, b),
+        };
+        assert_eq!(expected, result.unwrap().to_string(), "{} and {}", a, b);
+        for &signal in &[
+            Signal::Inexact,
+            Signal::Rounded,
+            Signal::Overflow,
+            Signal::DivisionByZero,
+            Signal::Clamped,
+            Signal::InvalidOperation,
+        ] {
+            assert_eq!(
+                signals.contains(&signal),
+                ctx.is_flagged(signal),
+                "{} and {} flagged {:?}",
+                a,
+                b,
+                signal
+            );
+        }
+    }
+}
+
+#[test]
+fn it_can_trap_signals_in_a_context() {
+    let mut ctx = Context::default();
+    assert!(ctx.is_trapped(Signal::Overflow));
+    assert!(ctx.is_trapped(Signal::DivisionByZero));
+    assert!(!ctx.is_trapped(Signal::Inexact));
+
+    let err = ctx.add(Decimal::MAX, Decimal::MAX).unwrap_err();
+    assert_eq!(ErrorKind::ExceedsMaximumPossibleValue, err.kind());
+    assert!(ctx.is_flagged(Signal::Overflow));
+    let err = ctx.div(Decimal::ONE, Decimal::ZERO).unwrap_err();
+    assert_eq!(ErrorKind::DivisionByZero, err.kind());
+    assert!(ctx.is_flagged(Signal::DivisionByZero));
+
+    ctx.clear_flags();
+    ctx.set_trap(Signal::Overflow, false);
+    ctx.set_trap(Signal::DivisionByZero, false);
+    assert_eq!(Ok(Decimal::MIN), ctx.sub(Decimal::MIN, Decimal::MAX));
+    assert_eq!(Ok(Decimal::MIN), ctx.div(-Decimal::ONE, Decimal::ZERO));
+    assert!(ctx.is_flagged(Signal::Overflow));
+    assert!(ctx.is_flagged(Signal::DivisionByZero));
+    assert!(ctx.div(Decimal::ZERO, Decimal::ZERO).is_err());
+
+    ctx.clear_flags();
+    ctx.set_trap(Signal::Inexact, true);
+    let err = ctx.div(Decimal::ONE, Decimal::from(3)).unwrap_err();
+    assert_eq!(ErrorKind::Inexact, err.kind());
+    assert!(ctx.is_flagged(Signal::Inexact));
+    assert!(ctx.is_flagged(Signal::Rounded));
+
+    let mut ctx = Context::new(2, RoundingStrategy::MidpointNearestEven).unwrap();
+    ctx.set_trap(Signal::Rounded, true);
+    let one = Decimal::from_str("1.0").unwrap();
+    assert_eq!(ErrorKind::Rounded, ctx.mul(one, one).unwrap_err().kind());
+
+    assert_eq!(
+        ErrorKind::PrecisionOutOfRange(0),
+        Context::new(0, RoundingStrategy::ToZero).unwrap_err().kind()
+    );
+    assert_eq!(
+        ErrorKind::PrecisionOutOfRange(29),
+        Context::new(29, RoundingStrategy::ToZero).unwrap_err().kind()
+    );
+}
+
+#[test]
+fn it_can_quantize_within_a_context() {
+    let tests = &[
+        (28, RoundingStrategy::MidpointNearestEven, "1.255", "0.01", Ok("1.26")),
+        (28, RoundingStrategy::MidpointNearestEven, "1.245", "0.01", Ok("1.24")),
+        (28, RoundingStrategy::ToZero, "-1.259", "0.01", Ok("-1.25")),
+        (28, RoundingStrategy::ToNegativeInfinity, "-1.251", "0.1", Ok("-1.3")),
+        (28, RoundingStrategy::MidpointNearestEven, "7", "0.01", Ok("7.00")),
+        (28, RoundingStrategy::MidpointNearestEven, "7.5", "1", Ok("8")),
+        (3, RoundingStrategy::MidpointNearestEven, "12.5", "0.1", Ok("12.5")),
+        (
+            3,
+            RoundingStrategy::MidpointNearestEven,
+            "12.5",
+            "0.01",
+            Err(ErrorKind::InvalidOperation),
+        ),
+        (
+            28,
+            RoundingStrategy::MidpointNearestEven,
+            "-79228162514264337593543950335",
+            "0.1",
+            Err(ErrorKind::InvalidOperation),
         ),
+    ];
+    for &(precision, strategy, value, exp, expected) in tests {
+        let mut ctx = Context::new(precision, strategy).unwrap();
+        let value = Decimal::from_str(value).unwrap();
+        let exp = Decimal::from_str(exp).unwrap();
+        let result = ctx.quantize(value, exp).map(|d| d.to_string()).map_err(|e| e.kind());
+        assert_eq!(expected.map(String::from), result, "quantize({}, {})", value, exp);
+    }
+
+    // There is no value to return when the precision is exceeded, so this errors even if untrapped
+    let mut ctx = Context::new(3, RoundingStrategy::MidpointNearestEven).unwrap();
+    ctx.set_trap(Signal::InvalidOperation, false);
+    let value = Decimal::from_str("12.5").unwrap();
+    let exp = Decimal::from_str("0.01").unwrap();
+    assert_eq!(
+        ErrorKind::InvalidOperation,
+        ctx.quantize(value, exp).unwrap_err().kind()
+    );
+    assert!(ctx.is_flagged(Signal::InvalidOperation));
+    assert!(!ctx.is_flagged(Signal::Overflow));
+}
+
+// Saturating arithmetic
+
+#[test]
+fn it_can_saturate_arithmetic() {
+    let max = "79228162514264337593543950335";
+    let min = "-79228162514264337593543950335";
+    let tests = &[
         (
-            "-2008379587.5525351789031325696",
-            "-32824109460554.341800487157760",
-            Greater,
-        ),
-        // 001 111 same scale
-        ("316007568232.9263258873102336", "2816795479724.6069787794805311", Less),
-        ("-316007568232.9263258873102336", "2816795479724.6069787794805311", Less),
+            "1.5",
+            "2.25",
+            "3.75",
+            "-0.75",
+            "3.375",
+            "0.6666666666666666666666666667",
+        ),
+        (max, "1", max, "79228162514264337593543950334", max, max),
+        (max, "-1", "79228162514264337593543950334", max, min, min),
+        (min, "1", "-79228162514264337593543950334", min, min, min),
+        (min, "-1", min, "-79228162514264337593543950334", max, max),
+        (max, "0.1", max, max, "7922816251426433759354395033.5", max),
+        (min, "-0.1", min, min, "7922816251426433759354395033.5", max),
+        (min, "0.1", min, min, "-7922816251426433759354395033.5", min),
+    ];
+    for &(a, b, add, sub, mul, div) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        assert_eq!(add, a.saturating_add(b).to_string(), "{} + {}", a, b);
+        assert_eq!(sub, a.saturating_sub(b).to_string(), "{} - {}", a, b);
+        assert_eq!(mul, a.saturating_mul(b).to_string(), "{} * {}", a, b);
+        assert_eq!(div, a.saturating_div(b).to_string(), "{} / {}", a, b);
+    }
+}
+
+#[test]
+#[should_panic]
+fn it_panics_when_saturating_div_by_zero() {
+    let _ = Decimal::ONE.saturating_div(Decimal::ZERO);
+}
+
+#[test]
+fn it_can_saturate_using_num_traits() {
+    use num_traits::{Saturating, SaturatingAdd, SaturatingMul, SaturatingSub};
+
+    let two = Decimal::new(2, 0);
+    assert_eq!(Decimal::MAX, SaturatingAdd::saturating_add(&Decimal::MAX, &two));
+    assert_eq!(Decimal::MIN, SaturatingSub::saturating_sub(&Decimal::MIN, &two));
+    assert_eq!(Decimal::MIN, SaturatingMul::saturating_mul(&Decimal::MAX, &-two));
+    assert_eq!(Decimal::MAX, Saturating::saturating_add(Decimal::MAX, two));
+    assert_eq!(Decimal::MIN, Saturating::saturating_sub(Decimal::MIN, two));
+}
+
+#[test]
+fn it_eqs_decimals() {
+    fn eq(a: &str, b: &str, c: bool) {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        assert_eq!(c, a.eq(&b), "{} == {}", a.to_string(), b.to_string());
+        assert_eq!(c, b.eq(&a), "{} == {}", b.to_string(), a.to_string());
+    }
+
+    let tests = &[
+        ("1", "1", true),
+        ("1", "-1", false),
+        ("1", "1.00", true),
+        ("1.2345000000000", "1.2345", true),
+        ("1.0000000000000000000000000000", "1.0000000000000000000000000000", true),
         (
-            "316007568232.9263258873102336",
-            "-2816795479724.6069787794805311",
-            Greater,
+            "1.0000000000000000000000000001",
+            "1.0000000000000000000000000000",
+            false,
         ),
+    ];
+    for &(a, b, c) in tests {
+        eq(a, b, c);
+    }
+}
+
+#[test]
+fn it_cmps_decimals() {
+    fn cmp(a: &str, b: &str, c: core::cmp::Ordering) {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        assert_eq!(
+            c,
+            a.cmp(&b),
+            "{} {} {}",
+            a.to_string(),
+            match c {
+                Less => "<",
+                Equal => "==",
+                Greater => ">",
+            },
+            b.to_string()
+        );
+    }
+
+    let tests = &[
+        ("1", "1", Equal),
+        ("1", "-1", Greater),
+        ("1", "1.00", Equal),
+        ("1.2345000000000", "1.2345", Equal),
         (
-            "-316007568232.9263258873102336",
-            "-2816795479724.6069787794805311",
+            "1.0000000000000000000000000001",
+            "1.0000000000000000000000000000",
             Greater,
         ),
-        // 001 111 different scale
-        (
-            "3536806574745420013541890.4576",
-            "9793146.81730411145833529126",
-            Greater,
-        ),
-        ("-3536806574745420013541890.4576", "9793146.81730411145833529126", Less),
-        (
-            "3536806574745420013541890.4576",
-            "-9793146.81730411145833529126",
-            Greater,
-        ),
-        ("-3536806574745420013541890.4576", "-9793146.81730411145833529126", Less),
-        // 101 equality
-        (
-            "254208186622762823842.71629992",
-            "254208186622762823842.71629992",
-            Equal,
-        ),
-        // 101 000 same scale
-        ("254208186622762823842.71629992", "0.00000000", Greater),
-        ("-254208186622762823842.71629992", "0.00000000", Less),
-        ("254208186622762823842.71629992", "-0.00000000", Greater),
-        ("-254208186622762823842.71629992", "-0.00000000", Less),
-        // 101 000 different scale
-        ("975421950664039.3614304091804", "0.0000000000000000", Greater),
-        ("-975421950664039.3614304091804", "0.0000000000000000", Less),
-        ("975421950664039.3614304091804", "-0.0000000000000000", Greater),
-        ("-975421950664039.3614304091804", "-0.0000000000000000", Less),
-        // 101 100 same scale
-        ("254208186622762823842.71629992", "10.74141379", Greater),
-        ("-254208186622762823842.71629992", "10.74141379", Less),
-        ("254208186622762823842.71629992", "-10.74141379", Greater),
-        ("-254208186622762823842.71629992", "-10.74141379", Less),
-        // 101 100 different scale
-        ("2552221405032275.8358630506229", "0.000000000000000592656995", Greater),
-        ("-2552221405032275.8358630506229", "0.000000000000000592656995", Less),
-        ("2552221405032275.8358630506229", "-0.000000000000000592656995", Greater),
-        ("-2552221405032275.8358630506229", "-0.000000000000000592656995", Less),
-        // 101 010 same scale
-        ("254208186622762823842.71629992", "62767493748.48499712", Greater),
-        ("-254208186622762823842.71629992", "62767493748.48499712", Less),
-        ("254208186622762823842.71629992", "-62767493748.48499712", Greater),
-        ("-254208186622762823842.71629992", "-62767493748.48499712", Less),
-        // 101 010 different scale
-        ("197346074219.25327589999174264", "0.7623493575178715136", Greater),
-        ("-197346074219.25327589999174264", "0.7623493575178715136", Less),
-        ("197346074219.25327589999174264", "-0.7623493575178715136", Greater),
-        ("-197346074219.25327589999174264", "-0.7623493575178715136", Less),
-        // 101 110 same scale
-        ("254208186622762823842.71629992", "76597126194.51389094", Greater),
-        ("-254208186622762823842.71629992", "76597126194.51389094", Less),
-        ("254208186622762823842.71629992", "-76597126194.51389094", Greater),
-        ("-254208186622762823842.71629992", "-76597126194.51389094", Less),
-        // 101 110 different scale
-        ("25899773651648.380071130043467", "61306258142804903.38", Less),
-        ("-25899773651648.380071130043467", "61306258142804903.38", Less),
-        ("25899773651648.380071130043467", "-61306258142804903.38", Greater),
-        ("-25899773651648.380071130043467", "-61306258142804903.38", Greater),
-        // 101 001 same scale
-        (
-            "254208186622762823842.71629992",
-            "83547191565151621967.28086528",
-            Greater,
-        ),
-        ("-254208186622762823842.71629992", "83547191565151621967.28086528", Less),
+        ("1.0000000000000000000000000000", "1.0000000000000000000000000001", Less),
+        ("-1", "100", Less),
+        ("-100", "1", Less),
+        ("0", "0.5", Less),
+        ("0.5", "0", Greater),
+        ("100", "0.0098", Greater),
+        ("1000000000000000", "999000000000000.0001", Greater),
+        ("2.0001", "2.0001", Equal),
         (
-            "254208186622762823842.71629992",
-            "-83547191565151621967.28086528",
+            "11.815126050420168067226890757",
+            "0.6386554621848739495798319328",
             Greater,
         ),
+        ("0.6386554621848739495798319328", "11.815126050420168067226890757", Less),
+        ("-0.5", "-0.01", Less),
+        ("-0.5", "-0.1", Less),
+        ("-0.01", "-0.5", Greater),
+        ("-0.1", "-0.5", Greater),
+        // 000 equality
+        ("0.00000000", "0.00000000", Equal),
+        // 000 000 same scale
+        ("0.00000000", "0.00000000", Equal),
+        ("-0.00000000", "0.00000000", Equal),
+        ("0.00000000", "-0.00000000", Equal),
+        ("-0.00000000", "-0.00000000", Equal),
+        // 000 000 different scale
+        ("0.000000000", "0.00000000000000000000000", Equal),
+        ("-0.000000000", "0.00000000000000000000000", Equal),
+        ("0.000000000", "-0.00000000000000000000000", Equal),
+        ("-0.000000000", "-0.00000000000000000000000", Equal),
+        // 000 100 same scale
+        ("0.00000000", "6.56792910", Less),
+        ("-0.00000000", "6.56792910", Less),
+        ("0.00000000", "-6.56792910", Greater),
+        ("-0.00000000", "-6.56792910", Greater),
+        // 000 100 different scale
+        ("0.0000000000000000000", "0.00000000000000001916236746", Less),
+        ("-0.0000000000000000000", "0.00000000000000001916236746", Less),
+        ("0.0000000000000000000", "-0.00000000000000001916236746", Greater),
+        ("-0.0000000000000000000", "-0.00000000000000001916236746", Greater),
+        // 000 010 same scale
+        ("0.00000000", "49037796231.72571136", Less),
+        ("-0.00000000", "49037796231.72571136", Less),
+        ("0.00000000", "-49037796231.72571136", Greater),
+        ("-0.00000000", "-49037796231.72571136", Greater),
+        // 000 010 different scale
+        ("0", "14459264155.12895488", Less),
+        ("-0", "14459264155.12895488", Less),
+        ("0", "-14459264155.12895488", Greater),
+        ("-0", "-14459264155.12895488", Greater),
+        // 000 110 same scale
+        ("0.00000000", "38675108055.09052783", Less),
+        ("-0.00000000", "38675108055.09052783", Less),
+        ("0.00000000", "-38675108055.09052783", Greater),
+        ("-0.00000000", "-38675108055.09052783", Greater),
+        // 000 110 different scale
+        ("0.00", "1495767034080324868", Less),
+        ("-0.00", "1495767034080324868", Less),
+        ("0.00", "-1495767034080324868", Greater),
+        ("-0.00", "-1495767034080324868", Greater),
+        // 000 001 same scale
+        ("0.00000000", "359299289270893106016.81305600", Less),
+        ("-0.00000000", "359299289270893106016.81305600", Less),
+        ("0.00000000", "-359299289270893106016.81305600", Greater),
+        ("-0.00000000", "-359299289270893106016.81305600", Greater),
+        // 000 001 different scale
+        ("0.00000000000000000000000000", "261631091689.9518486763536384", Less),
+        ("-0.00000000000000000000000000", "261631091689.9518486763536384", Less),
         (
-            "-254208186622762823842.71629992",
-            "-83547191565151621967.28086528",
-            Less,
-        ),
-        // 101 001 different scale
-        ("244762.90302171293318719286219", "9964072.600255221193011888128", Less),
-        ("-244762.90302171293318719286219", "9964072.600255221193011888128", Less),
-        (
-            "244762.90302171293318719286219",
-            "-9964072.600255221193011888128",
+            "0.00000000000000000000000000",
+            "-261631091689.9518486763536384",
             Greater,
         ),
         (
-            "-244762.90302171293318719286219",
-            "-9964072.600255221193011888128",
+            "-0.00000000000000000000000000",
+            "-261631091689.9518486763536384",
             Greater,
         ),
-        // 101 101 same scale
+        // 000 101 same scale
+        ("0.00000000", "184137107696737410476.63166815", Less),
+        ("-0.00000000", "184137107696737410476.63166815", Less),
+        ("0.00000000", "-184137107696737410476.63166815", Greater),
+        ("-0.00000000", "-184137107696737410476.63166815", Greater),
+        // 000 101 different scale
+        ("0.000000000", "2286857871088.7514840434334478", Less),
+        ("-0.000000000", "2286857871088.7514840434334478", Less),
+        ("0.000000000", "-2286857871088.7514840434334478", Greater),
+        ("-0.000000000", "-2286857871088.7514840434334478", Greater),
+        // 000 011 same scale
+        ("0.00000000", "169194696640288819908.07715840", Less),
+        ("-0.00000000", "169194696640288819908.07715840", Less),
+        ("0.00000000", "-169194696640288819908.07715840", Greater),
+        ("-0.00000000", "-169194696640288819908.07715840", Greater),
+        // 000 011 different scale
+        ("0.00000000", "2757550691.7650076909569048576", Less),
+        ("-0.00000000", "2757550691.7650076909569048576", Less),
+        ("0.00000000", "-2757550691.7650076909569048576", Greater),
+        ("-0.00000000", "-2757550691.7650076909569048576", Greater),
+        // 000 111 same scale
+        ("0.00000000", "133610725292915899001.10059212", Less),
+        ("-0.00000000", "133610725292915899001.10059212", Less),
+        ("0.00000000", "-133610725292915899001.10059212", Greater),
+        ("-0.00000000", "-133610725292915899001.10059212", Greater),
+        // 000 111 different scale
+        ("0.00000000000000000000", "86.25432767926620368822165265", Less),
+        ("-0.00000000000000000000", "86.25432767926620368822165265", Less),
+        ("0.00000000000000000000", "-86.25432767926620368822165265", Greater),
+        ("-0.00000000000000000000", "-86.25432767926620368822165265", Greater),
+        // 100 equality
+        ("0.0000000000598992228", "0.0000000000598992228", Equal),
+        // 100 000 same scale
+        ("0.0000000000598992228", "0.0000000000000000000", Greater),
+        ("-0.0000000000598992228", "0.0000000000000000000", Less),
+        ("0.0000000000598992228", "-0.0000000000000000000", Greater),
+        ("-0.0000000000598992228", "-0.0000000000000000000", Less),
+        // 100 000 different scale
+        ("0.1797407597", "0.0000000000000000000", Greater),
+        ("-0.1797407597", "0.0000000000000000000", Less),
+        ("0.1797407597", "-0.0000000000000000000", Greater),
+        ("-0.1797407597", "-0.0000000000000000000", Less),
+        // 100 100 same scale
+        ("0.0000000000598992228", "0.0000000000064510789", Greater),
+        ("-0.0000000000598992228", "0.0000000000064510789", Less),
+        ("0.0000000000598992228", "-0.0000000000064510789", Greater),
+        ("-0.0000000000598992228", "-0.0000000000064510789", Less),
+        // 100 100 different scale
+        ("0.000000000000011217354", "0.0000000000217735186", Less),
+        ("-0.000000000000011217354", "0.0000000000217735186", Less),
+        ("0.000000000000011217354", "-0.0000000000217735186", Greater),
+        ("-0.000000000000011217354", "-0.0000000000217735186", Greater),
+        // 100 010 same scale
+        ("0.0000000000598992228", "0.0659116848159129600", Less),
+        ("-0.0000000000598992228", "0.0659116848159129600", Less),
+        ("0.0000000000598992228", "-0.0659116848159129600", Greater),
+        ("-0.0000000000598992228", "-0.0659116848159129600", Greater),
+        // 100 010 different scale
+        ("0.00042035421", "0.004709460588143575040", Less),
+        ("-0.00042035421", "0.004709460588143575040", Less),
+        ("0.00042035421", "-0.004709460588143575040", Greater),
+        ("-0.00042035421", "-0.004709460588143575040", Greater),
+        // 100 110 same scale
+        ("0.0000000000598992228", "0.0755686585127091375", Less),
+        ("-0.0000000000598992228", "0.0755686585127091375", Less),
+        ("0.0000000000598992228", "-0.0755686585127091375", Greater),
+        ("-0.0000000000598992228", "-0.0755686585127091375", Greater),
+        // 100 110 different scale
+        ("14872.94465", "1284707831905.854085", Less),
+        ("-14872.94465", "1284707831905.854085", Less),
+        ("14872.94465", "-1284707831905.854085", Greater),
+        ("-14872.94465", "-1284707831905.854085", Greater),
+        // 100 001 same scale
+        ("0.0000000000598992228", "888767595.6145376468836286464", Less),
+        ("-0.0000000000598992228", "888767595.6145376468836286464", Less),
+        ("0.0000000000598992228", "-888767595.6145376468836286464", Greater),
+        ("-0.0000000000598992228", "-888767595.6145376468836286464", Greater),
+        // 100 001 different scale
+        ("0.0002108155975", "36.07555527243968476014968832", Less),
+        ("-0.0002108155975", "36.07555527243968476014968832", Less),
+        ("0.0002108155975", "-36.07555527243968476014968832", Greater),
+        ("-0.0002108155975", "-36.07555527243968476014968832", Greater),
+        // 100 101 same scale
+        ("0.0000000000598992228", "125730345.7412344676309569911", Less),
+        ("-0.0000000000598992228", "125730345.7412344676309569911", Less),
+        ("0.0000000000598992228", "-125730345.7412344676309569911", Greater),
+        ("-0.0000000000598992228", "-125730345.7412344676309569911", Greater),
+        // 100 101 different scale
+        ("0.0000000000871576741", "1.7283925558865766140690239662", Less),
+        ("-0.0000000000871576741", "1.7283925558865766140690239662", Less),
+        ("0.0000000000871576741", "-1.7283925558865766140690239662", Greater),
+        ("-0.0000000000871576741", "-1.7283925558865766140690239662", Greater),
+        // 100 011 same scale
+        ("0.0000000000598992228", "645513262.9193254090737451008", Less),
+        ("-0.0000000000598992228", "645513262.9193254090737451008", Less),
+        ("0.0000000000598992228", "-645513262.9193254090737451008", Greater),
+        ("-0.0000000000598992228", "-645513262.9193254090737451008", Greater),
+        // 100 011 different scale
+        ("0.000000000000000760885021", "3718370638.2004059326675681280", Less),
+        ("-0.000000000000000760885021", "3718370638.2004059326675681280", Less),
+        ("0.000000000000000760885021", "-3718370638.2004059326675681280", Greater),
         (
-            "254208186622762823842.71629992",
-            "106541875981662806348.63716235",
+            "-0.000000000000000760885021",
+            "-3718370638.2004059326675681280",
             Greater,
         ),
+        // 100 111 same scale
+        ("0.0000000000598992228", "422482675.5515775479939306437", Less),
+        ("-0.0000000000598992228", "422482675.5515775479939306437", Less),
+        ("0.0000000000598992228", "-422482675.5515775479939306437", Greater),
+        ("-0.0000000000598992228", "-422482675.5515775479939306437", Greater),
+        // 100 111 different scale
+        ("0.000000044182898", "25.452953262109919998605674045", Less),
+        ("-0.000000044182898", "25.452953262109919998605674045", Less),
+        ("0.000000044182898", "-25.452953262109919998605674045", Greater),
+        ("-0.000000044182898", "-25.452953262109919998605674045", Greater),
+        // 010 equality
+        ("423.1744746042687488", "423.1744746042687488", Equal),
+        // 010 000 same scale
+        ("423.1744746042687488", "0.0000000000000000", Greater),
+        ("-423.1744746042687488", "0.0000000000000000", Less),
+        ("423.1744746042687488", "-0.0000000000000000", Greater),
+        ("-423.1744746042687488", "-0.0000000000000000", Less),
+        // 010 000 different scale
+        ("9002354991.192604672", "0.00000000000000000000000000", Greater),
+        ("-9002354991.192604672", "0.00000000000000000000000000", Less),
+        ("9002354991.192604672", "-0.00000000000000000000000000", Greater),
+        ("-9002354991.192604672", "-0.00000000000000000000000000", Less),
+        // 010 100 same scale
+        ("423.1744746042687488", "0.0000000981820809", Greater),
+        ("-423.1744746042687488", "0.0000000981820809", Less),
+        ("423.1744746042687488", "-0.0000000981820809", Greater),
+        ("-423.1744746042687488", "-0.0000000981820809", Less),
+        // 010 100 different scale
+        ("4327019125101559.808", "0.00484846050", Greater),
+        ("-4327019125101559.808", "0.00484846050", Less),
+        ("4327019125101559.808", "-0.00484846050", Greater),
+        ("-4327019125101559.808", "-0.00484846050", Less),
+        // 010 010 same scale
+        ("423.1744746042687488", "786.9082854590775296", Less),
+        ("-423.1744746042687488", "786.9082854590775296", Less),
+        ("423.1744746042687488", "-786.9082854590775296", Greater),
+        ("-423.1744746042687488", "-786.9082854590775296", Greater),
+        // 010 010 different scale
+        ("793.0067125291450368", "0.0001587297248335626240", Greater),
+        ("-793.0067125291450368", "0.0001587297248335626240", Less),
+        ("793.0067125291450368", "-0.0001587297248335626240", Greater),
+        ("-793.0067125291450368", "-0.0001587297248335626240", Less),
+        // 010 110 same scale
+        ("423.1744746042687488", "300.0541360230572049", Greater),
+        ("-423.1744746042687488", "300.0541360230572049", Less),
+        ("423.1744746042687488", "-300.0541360230572049", Greater),
+        ("-423.1744746042687488", "-300.0541360230572049", Less),
+        // 010 110 different scale
+        ("90627.2042582540288", "4472414566654924.741", Less),
+        ("-90627.2042582540288", "4472414566654924.741", Less),
+        ("90627.2042582540288", "-4472414566654924.741", Greater),
+        ("-90627.2042582540288", "-4472414566654924.741", Greater),
+        // 010 001 same scale
+        ("423.1744746042687488", "3960577151543.5796707636412416", Less),
+        ("-423.1744746042687488", "3960577151543.5796707636412416", Less),
+        ("423.1744746042687488", "-3960577151543.5796707636412416", Greater),
+        ("-423.1744746042687488", "-3960577151543.5796707636412416", Greater),
+        // 010 001 different scale
+        ("0.000008867461591822499840", "185286.04378228713249986052096", Less),
+        ("-0.000008867461591822499840", "185286.04378228713249986052096", Less),
+        ("0.000008867461591822499840", "-185286.04378228713249986052096", Greater),
         (
-            "-254208186622762823842.71629992",
-            "106541875981662806348.63716235",
-            Less,
-        ),
-        (
-            "254208186622762823842.71629992",
-            "-106541875981662806348.63716235",
+            "-0.000008867461591822499840",
+            "-185286.04378228713249986052096",
             Greater,
         ),
+        // 010 101 same scale
+        ("423.1744746042687488", "2825958416017.6213507229869501", Less),
+        ("-423.1744746042687488", "2825958416017.6213507229869501", Less),
+        ("423.1744746042687488", "-2825958416017.6213507229869501", Greater),
+        ("-423.1744746042687488", "-2825958416017.6213507229869501", Greater),
+        // 010 101 different scale
+        ("0.01901870767742648320", "37662082383021542232.529651212", Less),
+        ("-0.01901870767742648320", "37662082383021542232.529651212", Less),
+        ("0.01901870767742648320", "-37662082383021542232.529651212", Greater),
+        ("-0.01901870767742648320", "-37662082383021542232.529651212", Greater),
+        // 010 011 same scale
+        ("423.1744746042687488", "3628063966991.6759417059016704", Less),
+        ("-423.1744746042687488", "3628063966991.6759417059016704", Less),
+        ("423.1744746042687488", "-3628063966991.6759417059016704", Greater),
+        ("-423.1744746042687488", "-3628063966991.6759417059016704", Greater),
+        // 010 011 different scale
+        ("45359904.32470925312", "2.4203452488052342918570049536", Greater),
+        ("-45359904.32470925312", "2.4203452488052342918570049536", Less),
+        ("45359904.32470925312", "-2.4203452488052342918570049536", Greater),
+        ("-45359904.32470925312", "-2.4203452488052342918570049536", Less),
+        // 010 111 same scale
+        ("423.1744746042687488", "2629665172331.9610693820109120", Less),
+        ("-423.1744746042687488", "2629665172331.9610693820109120", Less),
+        ("423.1744746042687488", "-2629665172331.9610693820109120", Greater),
+        ("-423.1744746042687488", "-2629665172331.9610693820109120", Greater),
+        // 010 111 different scale
+        ("0.0006420803252266205184", "8172.032417576265900588945489", Less),
+        ("-0.0006420803252266205184", "8172.032417576265900588945489", Less),
+        ("0.0006420803252266205184", "-8172.032417576265900588945489", Greater),
+        ("-0.0006420803252266205184", "-8172.032417576265900588945489", Greater),
+        // 110 equality
+        ("844.5530620517286511", "844.5530620517286511", Equal),
+        // 110 000 same scale
+        ("844.5530620517286511", "0.0000000000000000", Greater),
+        ("-844.5530620517286511", "0.0000000000000000", Less),
+        ("844.5530620517286511", "-0.0000000000000000", Greater),
+        ("-844.5530620517286511", "-0.0000000000000000", Less),
+        // 110 000 different scale
+        ("3285.530033386074797", "0.00000000000000000000", Greater),
+        ("-3285.530033386074797", "0.00000000000000000000", Less),
+        ("3285.530033386074797", "-0.00000000000000000000", Greater),
+        ("-3285.530033386074797", "-0.00000000000000000000", Less),
+        // 110 100 same scale
+        ("844.5530620517286511", "0.0000001953470063", Greater),
+        ("-844.5530620517286511", "0.0000001953470063", Less),
+        ("844.5530620517286511", "-0.0000001953470063", Greater),
+        ("-844.5530620517286511", "-0.0000001953470063", Less),
+        // 110 100 different scale
+        ("371284.0210972493371", "0.000000000000001307794657", Greater),
+        ("-371284.0210972493371", "0.000000000000001307794657", Less),
+        ("371284.0210972493371", "-0.000000000000001307794657", Greater),
+        ("-371284.0210972493371", "-0.000000000000001307794657", Less),
+        // 110 010 same scale
+        ("844.5530620517286511", "612.1542773033140224", Greater),
+        ("-844.5530620517286511", "612.1542773033140224", Less),
+        ("844.5530620517286511", "-612.1542773033140224", Greater),
+        ("-844.5530620517286511", "-612.1542773033140224", Less),
+        // 110 010 different scale
+        ("0.00004869219159821525572", "23341676485159.15776", Less),
+        ("-0.00004869219159821525572", "23341676485159.15776", Less),
+        ("0.00004869219159821525572", "-23341676485159.15776", Greater),
+        ("-0.00004869219159821525572", "-23341676485159.15776", Greater),
+        // 110 110 same scale
+        ("844.5530620517286511", "326.6132818317622015", Greater),
+        ("-844.5530620517286511", "326.6132818317622015", Less),
+        ("844.5530620517286511", "-326.6132818317622015", Greater),
+        ("-844.5530620517286511", "-326.6132818317622015", Less),
+        // 110 110 different scale
+        ("4752139369958.820619", "1330851022882027.972", Less),
+        ("-4752139369958.820619", "1330851022882027.972", Less),
+        ("4752139369958.820619", "-1330851022882027.972", Greater),
+        ("-4752139369958.820619", "-1330851022882027.972", Greater),
+        // 110 001 same scale
+        ("844.5530620517286511", "3585610241942.1922435648192512", Less),
+        ("-844.5530620517286511", "3585610241942.1922435648192512", Less),
+        ("844.5530620517286511", "-3585610241942.1922435648192512", Greater),
+        ("-844.5530620517286511", "-3585610241942.1922435648192512", Greater),
+        // 110 001 different scale
+        ("539313715923.4424678", "12410950080603997079634706432", Less),
+        ("-539313715923.4424678", "12410950080603997079634706432", Less),
+        ("539313715923.4424678", "-12410950080603997079634706432", Greater),
+        ("-539313715923.4424678", "-12410950080603997079634706432", Greater),
+        // 110 101 same scale
+        ("844.5530620517286511", "1947825396031.6933708908343230", Less),
+        ("-844.5530620517286511", "1947825396031.6933708908343230", Less),
+        ("844.5530620517286511", "-1947825396031.6933708908343230", Greater),
+        ("-844.5530620517286511", "-1947825396031.6933708908343230", Greater),
+        // 110 101 different scale
+        ("0.2301405445512525317", "245433629587.71206426704897154", Less),
+        ("-0.2301405445512525317", "245433629587.71206426704897154", Less),
+        ("0.2301405445512525317", "-245433629587.71206426704897154", Greater),
+        ("-0.2301405445512525317", "-245433629587.71206426704897154", Greater),
+        // 110 011 same scale
+        ("844.5530620517286511", "3637850451015.0843464291450880", Less),
+        ("-844.5530620517286511", "3637850451015.0843464291450880", Less),
+        ("844.5530620517286511", "-3637850451015.0843464291450880", Greater),
+        ("-844.5530620517286511", "-3637850451015.0843464291450880", Greater),
+        // 110 011 different scale
+        ("0.00000000717944802566514691", "18143443615.480512395717115904", Less),
+        ("-0.00000000717944802566514691", "18143443615.480512395717115904", Less),
         (
-            "-254208186622762823842.71629992",
-            "-106541875981662806348.63716235",
-            Less,
+            "0.00000000717944802566514691",
+            "-18143443615.480512395717115904",
+            Greater,
         ),
-        // 101 101 different scale
-        ("362319.18250030256385507568342", "3619454249020577423546109236", Less),
-        ("-362319.18250030256385507568342", "3619454249020577423546109236", Less),
         (
-            "362319.18250030256385507568342",
-            "-3619454249020577423546109236",
+            "-0.00000000717944802566514691",
+            "-18143443615.480512395717115904",
             Greater,
         ),
+        // 110 111 same scale
+        ("844.5530620517286511", "2738424264600.4917875777303163", Less),
+        ("-844.5530620517286511", "2738424264600.4917875777303163", Less),
+        ("844.5530620517286511", "-2738424264600.4917875777303163", Greater),
+        ("-844.5530620517286511", "-2738424264600.4917875777303163", Greater),
+        // 110 111 different scale
+        ("0.0000000007762706076409491335", "2489879185787497651.6458518595", Less),
         (
-            "-362319.18250030256385507568342",
-            "-3619454249020577423546109236",
-            Greater,
+            "-0.0000000007762706076409491335",
+            "2489879185787497651.6458518595",
+            Less,
         ),
-        // 101 011 same scale
         (
-            "254208186622762823842.71629992",
-            "156781478378762688050.06557184",
+            "0.0000000007762706076409491335",
+            "-2489879185787497651.6458518595",
             Greater,
         ),
         (
-            "-254208186622762823842.71629992",
-            "156781478378762688050.06557184",
-            Less,
+            "-0.0000000007762706076409491335",
+            "-2489879185787497651.6458518595",
+            Greater,
         ),
+        // 001 equality
+        ("316007568232.9263258873102336", "316007568232.9263258873102336", Equal),
+        // 001 000 same scale
+        ("316007568232.9263258873102336", "0.0000000000000000", Greater),
+        ("-316007568232.9263258873102336", "0.0000000000000000", Less),
+        ("316007568232.9263258873102336", "-0.0000000000000000", Greater),
+        ("-316007568232.9263258873102336", "-0.0000000000000000", Less),
+        // 001 000 different scale
+        ("3522055990024364385815547084.8", "0.000000000000", Greater),
+        ("-3522055990024364385815547084.8", "0.000000000000", Less),
+        ("3522055990024364385815547084.8", "-0.000000000000", Greater),
+        ("-3522055990024364385815547084.8", "-0.000000000000", Less),
+        // 001 100 same scale
+        ("316007568232.9263258873102336", "0.0000001073412971", Greater),
+        ("-316007568232.9263258873102336", "0.0000001073412971", Less),
+        ("316007568232.9263258873102336", "-0.0000001073412971", Greater),
+        ("-316007568232.9263258873102336", "-0.0000001073412971", Less),
+        // 001 100 different scale
+        ("1319006.0491408208640440532992", "0.00000000000000611866432", Greater),
+        ("-1319006.0491408208640440532992", "0.00000000000000611866432", Less),
+        ("1319006.0491408208640440532992", "-0.00000000000000611866432", Greater),
+        ("-1319006.0491408208640440532992", "-0.00000000000000611866432", Less),
+        // 001 010 same scale
+        ("316007568232.9263258873102336", "159.1054215143227392", Greater),
+        ("-316007568232.9263258873102336", "159.1054215143227392", Less),
+        ("316007568232.9263258873102336", "-159.1054215143227392", Greater),
+        ("-316007568232.9263258873102336", "-159.1054215143227392", Less),
+        // 001 010 different scale
+        ("2470144.7146711063666704252928", "211.0186916505714688", Greater),
+        ("-2470144.7146711063666704252928", "211.0186916505714688", Less),
+        ("2470144.7146711063666704252928", "-211.0186916505714688", Greater),
+        ("-2470144.7146711063666704252928", "-211.0186916505714688", Less),
+        // 001 110 same scale
+        ("316007568232.9263258873102336", "15.1186658969096112", Greater),
+        ("-316007568232.9263258873102336", "15.1186658969096112", Less),
+        ("316007568232.9263258873102336", "-15.1186658969096112", Greater),
+        ("-316007568232.9263258873102336", "-15.1186658969096112", Less),
+        // 001 110 different scale
+        ("3840504199004148630832.3360768", "7581138850996748864", Greater),
+        ("-3840504199004148630832.3360768", "7581138850996748864", Less),
+        ("3840504199004148630832.3360768", "-7581138850996748864", Greater),
+        ("-3840504199004148630832.3360768", "-7581138850996748864", Less),
+        // 001 001 same scale
+        ("316007568232.9263258873102336", "810157633226.6053390856880128", Less),
+        ("-316007568232.9263258873102336", "810157633226.6053390856880128", Less),
         (
-            "254208186622762823842.71629992",
-            "-156781478378762688050.06557184",
+            "316007568232.9263258873102336",
+            "-810157633226.6053390856880128",
             Greater,
         ),
         (
-            "-254208186622762823842.71629992",
-            "-156781478378762688050.06557184",
-            Less,
-        ),
+            "-316007568232.9263258873102336",
+            "-810157633226.6053390856880128",
+            Greater,
+        ),
+        // 001 001 different scale
+        ("1951046382014.4037956952260608", "3626102772868740412010083.1232", Less),
+        (
+            "-1951046382014.4037956952260608",
+            "3626102772868740412010083.1232",
+            Less,
+        ),
+        (
+            "1951046382014.4037956952260608",
+            "-3626102772868740412010083.1232",
+            Greater,
+        ),
+        (
+            "-1951046382014.4037956952260608",
+            "-3626102772868740412010083.1232",
+            Greater,
+        ),
+        // 001 101 same scale
+        ("316007568232.9263258873102336", "3258394380359.1965879291312453", Less),
+        ("-316007568232.9263258873102336", "3258394380359.1965879291312453", Less),
+        (
+            "316007568232.9263258873102336",
+            "-3258394380359.1965879291312453",
+            Greater,
+        ),
+        (
+            "-316007568232.9263258873102336",
+            "-3258394380359.1965879291312453",
+            Greater,
+        ),
+        // 001 101 different scale
+        (
+            "17580513970289834.943527780352",
+            "3.7977957031395371036126086595",
+            Greater,
+        ),
+        (
+            "-17580513970289834.943527780352",
+            "3.7977957031395371036126086595",
+            Less,
+        ),
+        (
+            "17580513970289834.943527780352",
+            "-3.7977957031395371036126086595",
+            Greater,
+        ),
+        (
+            "-17580513970289834.943527780352",
+            "-3.7977957031395371036126086595",
+            Less,
+        ),
+        // 001 011 same scale
+        ("316007568232.9263258873102336", "1154574080460.9867510617997312", Less),
+        ("-316007568232.9263258873102336", "1154574080460.9867510617997312", Less),
+        (
+            "316007568232.9263258873102336",
+            "-1154574080460.9867510617997312",
+            Greater,
+        ),
+        (
+            "-316007568232.9263258873102336",
+            "-1154574080460.9867510617997312",
+            Greater,
+        ),
+        // 001 011 different scale
+        ("2008379587.5525351789031325696", "32824109460554.341800487157760", Less),
+        (
+            "-2008379587.5525351789031325696",
+            "32824109460554.341800487157760",
+            Less,
+        ),
+        (
+            "2008379587.5525351789031325696",
+            "-32824109460554.341800487157760",
+            Greater,
+        ),
+        (
+            "-2008379587.5525351789031325696",
+            "-32824109460554.341800487157760",
+            Greater,
+        ),
+        // 001 111 same scale
+        ("316007568232.9263258873102336", "2816795479724.6069787794805311", Less),
+        ("-316007568232.9263258873102336", "2816795479724.6069787794805311", Less),
+        (
+            "316007568232.9263258873102336",
+            "-2816795479724.6069787794805311",
+            Greater,
+        ),
+        (
+            "-316007568232.9263258873102336",
+            "-2816795479724.6069787794805311",
+            Greater,
+        ),
+        // 001 111 different scale
+        (
+            "3536806574745420013541890.4576",
+            "9793146.81730411145833529126",
+            Greater,
+        ),
+        ("-3536806574745420013541890.4576", "9793146.81730411145833529126", Less),
+        (
+            "3536806574745420013541890.4576",
+            "-9793146.81730411145833529126",
+            Greater,
+        ),
+        ("-3536806574745420013541890.4576", "-9793146.81730411145833529126", Less),
+        // 101 equality
+        (
+            "254208186622762823842.71629992",
+            "254208186622762823842.71629992",
+            Equal,
+        ),
+        // 101 000 same scale
+        ("254208186622762823842.71629992", "0.00000000", Greater),
+        ("-254208186622762823842.71629992", "0.00000000", Less),
+        ("254208186622762823842.71629992", "-0.00000000", Greater),
+        ("-254208186622762823842.71629992", "-0.00000000", Less),
+        // 101 000 different scale
+        ("975421950664039.3614304091804", "0.0000000000000000", Greater),
+        ("-975421950664039.3614304091804", "0.0000000000000000", Less),
+        ("975421950664039.3614304091804", "-0.0000000000000000", Greater),
+        ("-975421950664039.3614304091804", "-0.0000000000000000", Less),
+        // 101 100 same scale
+        ("254208186622762823842.71629992", "10.74141379", Greater),
+        ("-254208186622762823842.71629992", "10.74141379", Less),
+        ("254208186622762823842.71629992", "-10.74141379", Greater),
+        ("-254208186622762823842.71629992", "-10.74141379", Less),
+        // 101 100 different scale
+        ("2552221405032275.8358630506229", "0.000000000000000592656995", Greater),
+        ("-2552221405032275.8358630506229", "0.000000000000000592656995", Less),
+        ("2552221405032275.8358630506229", "-0.000000000000000592656995", Greater),
+        ("-2552221405032275.8358630506229", "-0.000000000000000592656995", Less),
+        // 101 010 same scale
+        ("254208186622762823842.71629992", "62767493748.48499712", Greater),
+        ("-254208186622762823842.71629992", "62767493748.48499712", Less),
+        ("254208186622762823842.71629992", "-62767493748.48499712", Greater),
+        ("-254208186622762823842.71629992", "-62767493748.48499712", Less),
+        // 101 010 different scale
+        ("197346074219.25327589999174264", "0.7623493575178715136", Greater),
+        ("-197346074219.25327589999174264", "0.7623493575178715136", Less),
+        ("197346074219.25327589999174264", "-0.7623493575178715136", Greater),
+        ("-197346074219.25327589999174264", "-0.7623493575178715136", Less),
+        // 101 110 same scale
+        ("254208186622762823842.71629992", "76597126194.51389094", Greater),
+        ("-254208186622762823842.71629992", "76597126194.51389094", Less),
+        ("254208186622762823842.71629992", "-76597126194.51389094", Greater),
+        ("-254208186622762823842.71629992", "-76597126194.51389094", Less),
+        // 101 110 different scale
+        ("25899773651648.380071130043467", "61306258142804903.38", Less),
+        ("-25899773651648.380071130043467", "61306258142804903.38", Less),
+        ("25899773651648.380071130043467", "-61306258142804903.38", Greater),
+        ("-25899773651648.380071130043467", "-61306258142804903.38", Greater),
+        // 101 001 same scale
+        (
+            "254208186622762823842.71629992",
+            "83547191565151621967.28086528",
+            Greater,
+        ),
+        ("-254208186622762823842.71629992", "83547191565151621967.28086528", Less),
+        (
+            "254208186622762823842.71629992",
+            "-83547191565151621967.28086528",
+            Greater,
+        ),
+        (
+            "-254208186622762823842.71629992",
+            "-83547191565151621967.28086528",
+            Less,
+        ),
+        // 101 001 different scale
+        ("244762.90302171293318719286219", "9964072.600255221193011888128", Less),
+        ("-244762.90302171293318719286219", "9964072.600255221193011888128", Less),
+        (
+            "244762.90302171293318719286219",
+            "-9964072.600255221193011888128",
+            Greater,
+        ),
+        (
+            "-244762.90302171293318719286219",
+            "-9964072.600255221193011888128",
+            Greater,
+        ),
+        // 101 101 same scale
+        (
+            "254208186622762823842.71629992",
+            "106541875981662806348.63716235",
+            Greater,
+        ),
+        (
+            "-254208186622762823842.71629992",
+            "106541875981662806348.63716235",
+            Less,
+        ),
+        (
+            "254208186622762823842.71629992",
+            "-106541875981662806348.63716235",
+            Greater,
+        ),
+        (
+            "-254208186622762823842.71629992",
+            "-106541875981662806348.63716235",
+            Less,
+        ),
+        // 101 101 different scale
+        ("362319.18250030256385507568342", "3619454249020577423546109236", Less),
+        ("-362319.18250030256385507568342", "3619454249020577423546109236", Less),
+        (
+            "362319.18250030256385507568342",
+            "-3619454249020577423546109236",
+            Greater,
+        ),
+        (
+            "-362319.18250030256385507568342",
+            "-3619454249020577423546109236",
+            Greater,
+        ),
+        // 101 011 same scale
+        (
+            "254208186622762823842.71629992",
+            "156781478378762688050.06557184",
+            Greater,
+        ),
+        (
+            "-254208186622762823842.71629992",
+            "156781478378762688050.06557184",
+            Less,
+        ),
+        (
+            "254208186622762823842.71629992",
+            "-156781478378762688050.06557184",
+            Greater,
+        ),
+        (
+            "-254208186622762823842.71629992",
+            "-156781478378762688050.06557184",
+            Less,
+        ),
         // 101 011 different scale
         ("2486073465266.0337130589931876", "153874906950888902858.19691008", Less),
         (
@@ -1829,1592 +2814,3638 @@ fn it_cmps_decimals() {
         ),
         // 111 111 different scale
         (
-            "3879592276836332218003.2886500",
-            "35612499407667292.686490959658",
-            Greater,
+            "3879592276836332218003.2886500",
+            "35612499407667292.686490959658",
+            Greater,
+        ),
+        (
+            "-3879592276836332218003.2886500",
+            "35612499407667292.686490959658",
+            Less,
+        ),
+        (
+            "3879592276836332218003.2886500",
+            "-35612499407667292.686490959658",
+            Greater,
+        ),
+        (
+            "-3879592276836332218003.2886500",
+            "-35612499407667292.686490959658",
+            Less,
+        ),
+    ];
+    for &(a, b, c) in tests {
+        cmp(a, b, c);
+    }
+}
+
+#[test]
+fn it_floors_decimals() {
+    let tests = &[
+        ("1", "1"),
+        ("1.00", "1"),
+        ("1.2345", "1"),
+        ("-1", "-1"),
+        ("-1.00", "-1"),
+        ("-1.2345", "-2"),
+    ];
+    for &(a, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        assert_eq!(expected, a.floor().to_string(), "Failed flooring {}", a);
+    }
+}
+
+#[test]
+fn it_ceils_decimals() {
+    let tests = &[
+        ("1", "1"),
+        ("1.00", "1"),
+        ("1.2345", "2"),
+        ("-1", "-1"),
+        ("-1.00", "-1"),
+        ("-1.2345", "-1"),
+    ];
+    for &(a, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        assert_eq!(expected, a.ceil().to_string(), "Failed ceiling {}", a);
+    }
+}
+
+#[test]
+fn it_finds_max_of_two() {
+    let tests = &[("1", "1", "1"), ("2", "1", "2"), ("1", "2", "2")];
+    for &(a, b, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        assert_eq!(expected, a.max(b).to_string());
+    }
+}
+
+#[test]
+fn it_finds_min_of_two() {
+    let tests = &[("1", "1", "1"), ("2", "1", "1"), ("1", "2", "1")];
+    for &(a, b, expected) in tests {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        assert_eq!(expected, a.min(b).to_string());
+    }
+}
+
+#[test]
+fn test_max_compares() {
+    let x = "225.33543601344182".parse::<Decimal>().unwrap();
+    let y = Decimal::max_value();
+    assert!(x < y);
+    assert!(y > x);
+    assert_ne!(y, x);
+}
+
+#[test]
+fn test_min_compares() {
+    let x = "225.33543601344182".parse::<Decimal>().unwrap();
+    let y = Decimal::min_value();
+    assert!(x > y);
+    assert!(y < x);
+    assert_ne!(y, x);
+}
+
+#[test]
+fn it_can_parse_from_i32() {
+    use num_traits::FromPrimitive;
+
+    let tests = &[
+        (0i32, "0"),
+        (1i32, "1"),
+        (-1i32, "-1"),
+        (i32::MAX, "2147483647"),
+        (i32::MIN, "-2147483648"),
+    ];
+    for &(input, expected) in tests {
+        let parsed = Decimal::from_i32(input).unwrap();
+        assert_eq!(
+            expected,
+            parsed.to_string(),
+            "expected {} does not match parsed {}",
+            expected,
+            parsed
+        );
+        assert_eq!(
+            input.to_string(),
+            parsed.to_string(),
+            "i32 to_string {} does not match parsed {}",
+            input,
+            parsed
+        );
+    }
+}
+
+#[test]
+fn it_can_parse_from_i64() {
+    use num_traits::FromPrimitive;
+
+    let tests = &[
+        (0i64, "0"),
+        (1i64, "1"),
+        (-1i64, "-1"),
+        (i64::MAX, "9223372036854775807"),
+        (i64::MIN, "-9223372036854775808"),
+    ];
+    for &(input, expected) in tests {
+        let parsed = Decimal::from_i64(input).unwrap();
+        assert_eq!(
+            expected,
+            parsed.to_string(),
+            "expected {} does not match parsed {}",
+            expected,
+            parsed
+        );
+        assert_eq!(
+            input.to_string(),
+            parsed.to_string(),
+            "i64 to_string {} does not match parsed {}",
+            input,
+            parsed
+        );
+    }
+}
+
+#[test]
+fn it_can_round_to_2dp() {
+    let a = Decimal::from_str("6.12345").unwrap();
+    let b = (Decimal::from_str("100").unwrap() * a).round() / Decimal::from_str("100").unwrap();
+    assert_eq!("6.12", b.to_string());
+}
+
+#[test]
+fn it_can_round_using_basic_midpoint_rules() {
+    let tests = &[
+        ("3.5", RoundingStrategy::MidpointAwayFromZero, "4"),
+        ("2.8", RoundingStrategy::MidpointAwayFromZero, "3"),
+        ("2.5", RoundingStrategy::MidpointAwayFromZero, "3"),
+        ("2.1", RoundingStrategy::MidpointAwayFromZero, "2"),
+        ("-2.1", RoundingStrategy::MidpointAwayFromZero, "-2"),
+        ("-2.5", RoundingStrategy::MidpointAwayFromZero, "-3"),
+        ("-2.8", RoundingStrategy::MidpointAwayFromZero, "-3"),
+        ("-3.5", RoundingStrategy::MidpointAwayFromZero, "-4"),
+        ("3.5", RoundingStrategy::MidpointNearestEven, "4"),
+        ("2.8", RoundingStrategy::MidpointNearestEven, "3"),
+        ("2.5", RoundingStrategy::MidpointNearestEven, "2"),
+        ("2.1", RoundingStrategy::MidpointNearestEven, "2"),
+        ("-2.1", RoundingStrategy::MidpointNearestEven, "-2"),
+        ("-2.5", RoundingStrategy::MidpointNearestEven, "-2"),
+        ("-2.8", RoundingStrategy::MidpointNearestEven, "-3"),
+        ("-3.5", RoundingStrategy::MidpointNearestEven, "-4"),
+        ("3.5", RoundingStrategy::MidpointTowardZero, "3"),
+        ("2.8", RoundingStrategy::MidpointTowardZero, "3"),
+        ("2.5", RoundingStrategy::MidpointTowardZero, "2"),
+        ("2.1", RoundingStrategy::MidpointTowardZero, "2"),
+        ("-2.1", RoundingStrategy::MidpointTowardZero, "-2"),
+        ("-2.5", RoundingStrategy::MidpointTowardZero, "-2"),
+        ("-2.8", RoundingStrategy::MidpointTowardZero, "-3"),
+        ("-3.5", RoundingStrategy::MidpointTowardZero, "-3"),
+        ("2.8", RoundingStrategy::ToNegativeInfinity, "2"),
+        ("2.5", RoundingStrategy::ToNegativeInfinity, "2"),
+        ("2.1", RoundingStrategy::ToNegativeInfinity, "2"),
+        ("-2.1", RoundingStrategy::ToNegativeInfinity, "-3"),
+        ("-2.5", RoundingStrategy::ToNegativeInfinity, "-3"),
+        ("-2.8", RoundingStrategy::ToNegativeInfinity, "-3"),
+        ("2.8", RoundingStrategy::ToPositiveInfinity, "3"),
+        ("2.5", RoundingStrategy::ToPositiveInfinity, "3"),
+        ("2.1", RoundingStrategy::ToPositiveInfinity, "3"),
+        ("-2.1", RoundingStrategy::ToPositiveInfinity, "-2"),
+        ("-2.5", RoundingStrategy::ToPositiveInfinity, "-2"),
+        ("-2.8", RoundingStrategy::ToPositiveInfinity, "-2"),
+        ("2.8", RoundingStrategy::ToZero, "2"),
+        ("2.5", RoundingStrategy::ToZero, "2"),
+        ("2.1", RoundingStrategy::ToZero, "2"),
+        ("-2.1", RoundingStrategy::ToZero, "-2"),
+        ("-2.5", RoundingStrategy::ToZero, "-2"),
+        ("-2.8", RoundingStrategy::ToZero, "-2"),
+        ("2.8", RoundingStrategy::AwayFromZero, "3"),
+        ("2.5", RoundingStrategy::AwayFromZero, "3"),
+        ("2.1", RoundingStrategy::AwayFromZero, "3"),
+        ("-2.1", RoundingStrategy::AwayFromZero, "-3"),
+        ("-2.5", RoundingStrategy::AwayFromZero, "-3"),
+        ("-2.8", RoundingStrategy::AwayFromZero, "-3"),
+        ("3.5", RoundingStrategy::MidpointNearestOdd, "3"),
+        ("2.8", RoundingStrategy::MidpointNearestOdd, "3"),
+        ("2.5", RoundingStrategy::MidpointNearestOdd, "3"),
+        ("2.1", RoundingStrategy::MidpointNearestOdd, "2"),
+        ("-2.1", RoundingStrategy::MidpointNearestOdd, "-2"),
+        ("-2.5", RoundingStrategy::MidpointNearestOdd, "-3"),
+        ("-2.8", RoundingStrategy::MidpointNearestOdd, "-3"),
+        ("-3.5", RoundingStrategy::MidpointNearestOdd, "-3"),
+        ("2.8", RoundingStrategy::ZeroFiveUp, "2"),
+        ("2.5", RoundingStrategy::ZeroFiveUp, "2"),
+        ("2.1", RoundingStrategy::ZeroFiveUp, "2"),
+        ("-2.1", RoundingStrategy::ZeroFiveUp, "-2"),
+        ("-2.8", RoundingStrategy::ZeroFiveUp, "-2"),
+        ("0.2", RoundingStrategy::ZeroFiveUp, "1"),
+        ("-0.2", RoundingStrategy::ZeroFiveUp, "-1"),
+        ("5.1", RoundingStrategy::ZeroFiveUp, "6"),
+        ("-5.9", RoundingStrategy::ZeroFiveUp, "-6"),
+        ("10.3", RoundingStrategy::ZeroFiveUp, "11"),
+        ("5.0", RoundingStrategy::ZeroFiveUp, "5"),
+    ];
+
+    for &(input, strategy, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        let b = a.round_dp_with_strategy(0, strategy);
+        assert_eq!(expected, b.to_string(), "{} > {} for {:?}", input, expected, strategy);
+    }
+}
+
+#[test]
+fn it_can_round_to_odd_and_zero_five_up_everywhere() {
+    let odd = RoundingStrategy::MidpointNearestOdd;
+    let zero_five_up = RoundingStrategy::ZeroFiveUp;
+    let d = |s: &str| Decimal::from_str(s).unwrap();
+
+    assert_eq!("1.23", d("1.225").round_dp_with_strategy(2, odd).to_string());
+    assert_eq!("1.23", d("1.235").round_dp_with_strategy(2, odd).to_string());
+    assert_eq!("1.21", d("1.2001").round_dp_with_strategy(2, zero_five_up).to_string());
+    assert_eq!("1.22", d("1.2201").round_dp_with_strategy(2, zero_five_up).to_string());
+    assert_eq!(
+        "0.601",
+        d("1.2001").mul_with_strategy(d("0.5"), 3, zero_five_up).to_string()
+    );
+    assert_eq!(
+        "0.617",
+        d("1.235").mul_with_strategy(d("0.5"), 3, zero_five_up).to_string()
+    );
+    assert_eq!("0.617", d("1.235").mul_with_strategy(d("0.5"), 3, odd).to_string());
+    assert_eq!("0.13", d("1").div_with_strategy(d("8"), 2, odd).to_string());
+    assert_eq!("0.33", d("1").div_with_strategy(d("3"), 2, zero_five_up).to_string());
+    assert_eq!("0.06", d("1").div_with_strategy(d("19.9"), 2, zero_five_up).to_string());
+    assert_eq!(Some(d("123000")), d("122500").round_sf_with_strategy(3, odd));
+    assert_eq!(Some(d("126000")), d("125001").round_sf_with_strategy(3, zero_five_up));
+    assert_eq!(Some(d("1.25")), d("1.275").round_to_increment(d("0.05"), odd));
+
+    let mut ctx = Context::new(2, zero_five_up).unwrap();
+    assert_eq!("51", ctx.add(d("50"), d("0.1")).unwrap().to_string());
+    assert_eq!("52", ctx.add(d("52"), d("0.1")).unwrap().to_string());
+}
+
+#[test]
+fn it_can_round_using_bankers_rounding() {
+    let tests = &[
+        ("6.12345", 2, "6.12"),
+        ("6.126", 2, "6.13"),
+        ("-6.126", 2, "-6.13"),
+        ("6.5", 0, "6"),
+        ("7.5", 0, "8"),
+        ("1.2250", 2, "1.22"),
+        ("1.2252", 2, "1.23"),
+        ("1.2249", 2, "1.22"),
+        ("6.1", 2, "6.1"),
+        ("0.0000", 2, "0.00"),
+        ("0.6666666666666666666666666666", 2, "0.67"),
+        ("1.40", 0, "1"),
+        ("2.60", 0, "3"),
+        ("2.1234567890123456789012345678", 27, "2.123456789012345678901234568"),
+    ];
+    for &(input, dp, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        #[allow(deprecated)]
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::BankersRounding);
+        assert_eq!(expected, b.to_string(), "BankersRounding");
+
+        // Recommended replacement
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven);
+        assert_eq!(expected, b.to_string(), "MidpointNearestEven");
+    }
+}
+
+#[test]
+fn it_can_round_complex_numbers_using_bankers_rounding() {
+    // Issue #71
+    let rate = Decimal::new(19, 2); // 0.19
+    let one = Decimal::new(1, 0); // 1
+    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
+
+    #[allow(deprecated)]
+    let part = part.round_dp_with_strategy(2, RoundingStrategy::BankersRounding); // 0.16
+    assert_eq!("0.16", part.to_string(), "BankersRounding");
+
+    // Recommended replacement
+    let part = part.round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven); // 0.16
+    assert_eq!("0.16", part.to_string(), "MidpointNearestEven");
+}
+
+#[test]
+fn it_can_round_using_round_half_up() {
+    let tests = &[
+        ("0", 0, "0"),
+        ("1.234", 3, "1.234"),
+        ("1.12", 5, "1.12"),
+        ("6.34567", 2, "6.35"),
+        ("6.5", 0, "7"),
+        ("12.49", 0, "12"),
+        ("0.6666666666666666666666666666", 2, "0.67"),
+        ("1.40", 0, "1"),
+        ("2.60", 0, "3"),
+        ("2.1234567890123456789012345678", 27, "2.123456789012345678901234568"),
+    ];
+    for &(input, dp, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        #[allow(deprecated)]
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundHalfUp);
+        assert_eq!(expected, b.to_string(), "RoundHalfUp");
+
+        // Recommended replacement
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero);
+        assert_eq!(expected, b.to_string(), "MidpointAwayFromZero");
+    }
+}
+
+#[test]
+fn it_can_round_complex_numbers_using_round_half_up() {
+    // Issue #71
+    let rate = Decimal::new(19, 2); // 0.19
+    let one = Decimal::new(1, 0); // 1
+    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
+    #[allow(deprecated)]
+    let part = part.round_dp_with_strategy(2, RoundingStrategy::RoundHalfUp); // 0.16
+    assert_eq!("0.16", part.to_string(), "RoundHalfUp");
+
+    // Recommended replacement
+    let part = part.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero); // 0.16
+    assert_eq!("0.16", part.to_string(), "MidpointAwayFromZero");
+}
+
+#[test]
+fn it_can_round_using_round_half_down() {
+    let tests = &[
+        ("0", 0, "0"),
+        ("1.234", 3, "1.234"),
+        ("1.12", 5, "1.12"),
+        ("6.34567", 2, "6.35"),
+        ("6.51", 0, "7"),
+        ("12.5", 0, "12"),
+        ("0.6666666666666666666666666666", 2, "0.67"),
+        ("1.40", 0, "1"),
+        ("2.60", 0, "3"),
+        ("2.1234567890123456789012345678", 27, "2.123456789012345678901234568"),
+    ];
+    for &(input, dp, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        #[allow(deprecated)]
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundHalfDown);
+        assert_eq!(expected, b.to_string(), "RoundHalfDown");
+
+        // Recommended replacement
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::MidpointTowardZero);
+        assert_eq!(expected, b.to_string(), "MidpointTowardZero");
+    }
+}
+
+#[test]
+fn it_can_round_complex_numbers_using_round_half_down() {
+    // Issue #71
+    let rate = Decimal::new(19, 2); // 0.19
+    let one = Decimal::new(1, 0); // 1
+    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
+
+    #[allow(deprecated)]
+    let part = part.round_dp_with_strategy(2, RoundingStrategy::RoundHalfDown); // 0.16
+    assert_eq!("0.16", part.to_string(), "RoundHalfDown");
+
+    // Recommended replacement
+    let part = part.round_dp_with_strategy(2, RoundingStrategy::MidpointTowardZero); // 0.16
+    assert_eq!("0.16", part.to_string(), "RoundHalfDown");
+}
+
+#[test]
+fn it_can_round_to_2dp_using_explicit_function() {
+    let a = Decimal::from_str("6.12345").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("6.12", b.to_string());
+}
+
+#[test]
+fn it_can_round_up_to_2dp_using_explicit_function() {
+    let a = Decimal::from_str("6.126").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("6.13", b.to_string());
+}
+
+#[test]
+fn it_can_round_down_to_2dp_using_explicit_function() {
+    let a = Decimal::from_str("-6.126").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("-6.13", b.to_string());
+}
+
+#[test]
+fn it_can_round_down_using_bankers_rounding() {
+    let a = Decimal::from_str("6.5").unwrap();
+    let b = a.round_dp(0u32);
+    assert_eq!("6", b.to_string());
+}
+
+#[test]
+fn it_can_round_up_using_bankers_rounding() {
+    let a = Decimal::from_str("7.5").unwrap();
+    let b = a.round_dp(0u32);
+    assert_eq!("8", b.to_string());
+}
+
+#[test]
+fn it_can_round_correctly_using_bankers_rounding_1() {
+    let a = Decimal::from_str("1.2250").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("1.22", b.to_string());
+}
+
+#[test]
+fn it_can_round_correctly_using_bankers_rounding_2() {
+    let a = Decimal::from_str("1.2251").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("1.23", b.to_string());
+}
+
+#[test]
+fn it_can_round_down_when_required() {
+    let a = Decimal::from_str("1.2249").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("1.22", b.to_string());
+}
+
+#[test]
+fn it_can_round_to_2dp_using_explicit_function_without_changing_value() {
+    let a = Decimal::from_str("6.1").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("6.1", b.to_string());
+}
+
+#[test]
+fn it_can_round_zero() {
+    let a = Decimal::from_str("0.0000").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("0.00", b.to_string());
+}
+
+#[test]
+fn it_can_round_large_decimals() {
+    let a = Decimal::from_str("0.6666666666666666666666666666").unwrap();
+    let b = a.round_dp(2u32);
+    assert_eq!("0.67", b.to_string());
+}
+
+#[test]
+fn it_can_round_simple_numbers_down() {
+    let a = Decimal::from_str("1.40").unwrap();
+    let b = a.round_dp(0u32);
+    assert_eq!("1", b.to_string());
+}
+
+#[test]
+fn it_can_round_simple_numbers_up() {
+    let a = Decimal::from_str("2.60").unwrap();
+    let b = a.round_dp(0u32);
+    assert_eq!("3", b.to_string());
+}
+
+#[test]
+fn it_can_round_simple_numbers_with_high_precision() {
+    let a = Decimal::from_str("2.1234567890123456789012345678").unwrap();
+    let b = a.round_dp(27u32);
+    assert_eq!("2.123456789012345678901234568", b.to_string());
+}
+
+#[test]
+fn it_can_round_complex_numbers() {
+    // Issue #71
+    let rate = Decimal::new(19, 2); // 0.19
+    let one = Decimal::new(1, 0); // 1
+    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
+    let part = part.round_dp(2); // 0.16
+    assert_eq!("0.16", part.to_string());
+}
+
+#[test]
+fn it_can_round_down() {
+    let tests = &[
+        ("0.470", 1, "0.4"),
+        ("-0.470", 1, "-0.4"), // Toward zero
+        ("0.400", 1, "0.4"),
+        ("-0.400", 1, "-0.4"),
+    ];
+    for &(input, dp, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        #[allow(deprecated)]
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundDown);
+        assert_eq!(expected, b.to_string(), "RoundDown");
+
+        // Recommended replacement
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::ToZero);
+        assert_eq!(expected, b.to_string(), "ToZero");
+    }
+}
+
+#[test]
+fn it_can_round_up() {
+    let tests = &[
+        ("2.8", 0, "3"),
+        ("2.5", 0, "3"),
+        ("2.1", 0, "3"),
+        ("-2.1", 0, "-3"),
+        ("-2.5", 0, "-3"),
+        ("-2.8", 0, "-3"),
+        ("0.320", 1, "0.4"),
+        ("-0.320", 1, "-0.4"),
+        ("0.300", 1, "0.3"),
+        ("-0.300", 1, "-0.3"),
+    ];
+
+    for &(input, dp, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        #[allow(deprecated)]
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundUp);
+        assert_eq!(expected, b.to_string(), "RoundUp");
+
+        // Recommended replacement
+        let b = a.round_dp_with_strategy(dp, RoundingStrategy::AwayFromZero);
+        assert_eq!(expected, b.to_string(), "AwayFromZero");
+    }
+}
+
+#[test]
+fn it_can_round_significant_figures() {
+    let max = "79228162514264337593543950335";
+    let tests = &[
+        ("0.0012345", 3, Some("0.00123")),
+        ("123456", 3, Some("123000")),
+        ("-123456", 3, Some("-123000")),
+        ("123500", 3, Some("124000")),
+        ("122500", 3, Some("122000")),
+        ("9.99", 2, Some("10")),
+        ("9.99", 1, Some("10")),
+        ("99999", 2, Some("100000")),
+        ("1.2000", 3, Some("1.20")),
+        ("1.2", 5, Some("1.2")),
+        ("0", 2, Some("0")),
+        ("0.000", 2, Some("0.000")),
+        ("1", 0, None),
+        (max, 2, Some("79000000000000000000000000000")),
+        (max, 29, Some(max)),
+        (max, 28, None),
+        (max, 1, None),
+    ];
+    for &(input, digits, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        let b = a.round_sf(digits);
+        assert_eq!(
+            expected,
+            b.map(|d| d.to_string()).as_deref(),
+            "{} to {} sf",
+            input,
+            digits
+        );
+    }
+}
+
+#[test]
+fn it_can_round_significant_figures_with_strategy() {
+    let tests = &[
+        ("123456", 3, RoundingStrategy::ToPositiveInfinity, "124000"),
+        ("-123456", 3, RoundingStrategy::ToPositiveInfinity, "-123000"),
+        ("-123456", 3, RoundingStrategy::ToNegativeInfinity, "-124000"),
+        ("0.0012345", 4, RoundingStrategy::MidpointAwayFromZero, "0.001235"),
+        ("0.0012345", 4, RoundingStrategy::MidpointNearestEven, "0.001234"),
+        ("0.0012345", 4, RoundingStrategy::ToZero, "0.001234"),
+        ("0.0012341", 4, RoundingStrategy::AwayFromZero, "0.001235"),
+    ];
+    for &(input, digits, strategy, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        let b = a.round_sf_with_strategy(digits, strategy).unwrap();
+        assert_eq!(
+            expected,
+            b.to_string(),
+            "{} to {} sf using {:?}",
+            input,
+            digits,
+            strategy
+        );
+    }
+}
+
+#[test]
+fn it_can_round_to_increment() {
+    let max = "79228162514264337593543950335";
+    let tests = &[
+        ("1.225", "0.05", RoundingStrategy::MidpointAwayFromZero, Some("1.25")),
+        ("1.225", "0.05", RoundingStrategy::MidpointTowardZero, Some("1.20")),
+        ("1.225", "0.05", RoundingStrategy::MidpointNearestEven, Some("1.20")),
+        ("1.275", "0.05", RoundingStrategy::MidpointNearestEven, Some("1.30")),
+        ("-1.226", "0.05", RoundingStrategy::MidpointTowardZero, Some("-1.25")),
+        ("1.23", "0.05", RoundingStrategy::ToZero, Some("1.20")),
+        ("1.2", "0.05", RoundingStrategy::AwayFromZero, Some("1.20")),
+        ("10.3", "0.25", RoundingStrategy::ToPositiveInfinity, Some("10.50")),
+        ("-10.3", "0.25", RoundingStrategy::ToPositiveInfinity, Some("-10.25")),
+        ("-10.3", "0.25", RoundingStrategy::ToNegativeInfinity, Some("-10.50")),
+        ("1.2345", "0.009", RoundingStrategy::MidpointNearestEven, Some("1.233")),
+        ("7", "3", RoundingStrategy::MidpointNearestEven, Some("6")),
+        ("7.5", "3", RoundingStrategy::MidpointNearestEven, Some("6")),
+        ("0.01", "5", RoundingStrategy::MidpointNearestEven, Some("0")),
+        ("0.01", "5", RoundingStrategy::ToNegativeInfinity, Some("0")),
+        ("-0.01", "5", RoundingStrategy::ToNegativeInfinity, Some("-5")),
+        (max, "0.1", RoundingStrategy::ToZero, None),
+        (
+            max,
+            "10",
+            RoundingStrategy::ToZero,
+            Some("79228162514264337593543950330"),
+        ),
+        (max, "10", RoundingStrategy::ToPositiveInfinity, None),
+        ("1", "0", RoundingStrategy::ToZero, None),
+        ("1", "-0.05", RoundingStrategy::ToZero, None),
+    ];
+    for &(input, increment, strategy, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        let increment = Decimal::from_str(increment).unwrap();
+        let b = a.round_to_increment(increment, strategy);
+        assert_eq!(
+            expected,
+            b.map(|d| d.to_string()).as_deref(),
+            "{} to {} using {:?}",
+            input,
+            increment,
+            strategy
+        );
+    }
+}
+
+#[test]
+fn it_can_floor_and_ceil_to_increment() {
+    let tests = &[
+        ("1.23", "0.05", "1.20", "1.25"),
+        ("-1.23", "0.05", "-1.25", "-1.20"),
+        ("1.25", "0.05", "1.25", "1.25"),
+        ("123", "100", "100", "200"),
+    ];
+    for &(input, increment, floor, ceil) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        let increment = Decimal::from_str(increment).unwrap();
+        assert_eq!(floor, a.floor_to_increment(increment).unwrap().to_string());
+        assert_eq!(ceil, a.ceil_to_increment(increment).unwrap().to_string());
+    }
+}
+
+#[test]
+fn it_can_quantize() {
+    let tests = &[
+        ("12.345", "0.01", RoundingStrategy::MidpointAwayFromZero, Ok("12.35")),
+        ("12.345", "0.01", RoundingStrategy::MidpointNearestEven, Ok("12.34")),
+        ("-12.345", "0.01", RoundingStrategy::ToNegativeInfinity, Ok("-12.35")),
+        ("12.345", "1", RoundingStrategy::ToPositiveInfinity, Ok("13")),
+        ("12.345", "100", RoundingStrategy::ToZero, Ok("12")),
+        ("7", "0.01", RoundingStrategy::MidpointNearestEven, Ok("7.00")),
+        ("-7.1", "0.0001", RoundingStrategy::MidpointNearestEven, Ok("-7.1000")),
+        ("0", "0.001", RoundingStrategy::MidpointNearestEven, Ok("0.000")),
+        ("0.000", "1", RoundingStrategy::MidpointNearestEven, Ok("0")),
+        ("-0.4", "1", RoundingStrategy::MidpointNearestEven, Ok("0")),
+        (
+            "1",
+            "0.0000000000000000000000000001",
+            RoundingStrategy::ToZero,
+            Ok("1.0000000000000000000000000000"),
         ),
         (
-            "-3879592276836332218003.2886500",
-            "35612499407667292.686490959658",
-            Less,
+            "10",
+            "0.0000000000000000000000000001",
+            RoundingStrategy::ToZero,
+            Err(ErrorKind::ExceedsMaximumPossibleValue),
         ),
         (
-            "3879592276836332218003.2886500",
-            "-35612499407667292.686490959658",
-            Greater,
+            "-79228162514264337593543950335",
+            "0.1",
+            RoundingStrategy::ToZero,
+            Err(ErrorKind::LessThanMinimumPossibleValue),
         ),
         (
-            "-3879592276836332218003.2886500",
-            "-35612499407667292.686490959658",
-            Less,
+            "79228162514264337593543950335",
+            "1",
+            RoundingStrategy::ToZero,
+            Ok("79228162514264337593543950335"),
         ),
     ];
-    for &(a, b, c) in tests {
-        cmp(a, b, c);
+    for &(input, exp, strategy, expected) in tests {
+        let a = Decimal::from_str(input).unwrap();
+        let exp = Decimal::from_str(exp).unwrap();
+        let result = a.quantize(exp, strategy).map(|d| d.to_string()).map_err(|e| e.kind());
+        assert_eq!(expected.map(String::from), result, "quantize({}, {})", input, exp);
     }
 }
 
 #[test]
-fn it_floors_decimals() {
+fn it_can_trunc() {
+    let tests = &[("1.00000000000000000000", "1"), ("1.000000000000000000000001", "1")];
+
+    for &(value, expected) in tests {
+        let value = Decimal::from_str(value).unwrap();
+        let expected = Decimal::from_str(expected).unwrap();
+        let trunc = value.trunc();
+        assert_eq!(expected.to_string(), trunc.to_string());
+    }
+}
+
+#[test]
+fn it_can_fract() {
+    let tests = &[
+        ("1.00000000000000000000", "0.00000000000000000000"),
+        ("1.000000000000000000000001", "0.000000000000000000000001"),
+    ];
+
+    for &(value, expected) in tests {
+        let value = Decimal::from_str(value).unwrap();
+        let expected = Decimal::from_str(expected).unwrap();
+        let fract = value.fract();
+        assert_eq!(expected.to_string(), fract.to_string());
+    }
+}
+
+#[test]
+fn it_can_normalize() {
     let tests = &[
+        ("1.00000000000000000000", "1"),
+        ("1.10000000000000000000000", "1.1"),
+        ("1.00010000000000000000000", "1.0001"),
         ("1", "1"),
-        ("1.00", "1"),
-        ("1.2345", "1"),
-        ("-1", "-1"),
-        ("-1.00", "-1"),
-        ("-1.2345", "-2"),
+        ("1.1", "1.1"),
+        ("1.0001", "1.0001"),
+        ("-0", "0"),
+        ("-0.0", "0"),
+        ("-0.010", "-0.01"),
+        ("0.0", "0"),
     ];
-    for &(a, expected) in tests {
-        let a = Decimal::from_str(a).unwrap();
-        assert_eq!(expected, a.floor().to_string(), "Failed flooring {}", a);
+
+    for &(value, expected) in tests {
+        let value = Decimal::from_str(value).unwrap();
+        let expected = Decimal::from_str(expected).unwrap();
+        let normalized = value.normalize();
+        assert_eq!(expected.to_string(), normalized.to_string());
+    }
+}
+
+#[test]
+fn it_can_return_the_max_value() {
+    assert_eq!("79228162514264337593543950335", Decimal::max_value().to_string());
+}
+
+#[test]
+fn it_can_return_the_min_value() {
+    assert_eq!("-79228162514264337593543950335", Decimal::min_value().to_string());
+}
+
+#[test]
+fn it_has_correctly_rounded_constants() {
+    use rust_decimal::consts::*;
+
+    let test_cases = &[
+        (PI, "3.1415926535897932384626433833"),
+        (TAU, "6.2831853071795864769252867666"),
+        (FRAC_PI_2, "1.5707963267948966192313216916"),
+        (FRAC_PI_3, "1.0471975511965977461542144611"),
+        (FRAC_PI_4, "0.7853981633974483096156608458"),
+        (FRAC_PI_6, "0.5235987755982988730771072305"),
+        (FRAC_PI_8, "0.3926990816987241548078304229"),
+        (FRAC_1_PI, "0.3183098861837906715377675267"),
+        (FRAC_2_PI, "0.6366197723675813430755350535"),
+        (FRAC_2_SQRT_PI, "1.1283791670955125738961589031"),
+        (SQRT_2, "1.4142135623730950488016887242"),
+        (FRAC_1_SQRT_2, "0.7071067811865475244008443621"),
+        (E, "2.7182818284590452353602874714"),
+        (LOG2_E, "1.4426950408889634073599246810"),
+        (LOG2_10, "3.3219280948873623478703194295"),
+        (LOG10_E, "0.4342944819032518276511289189"),
+        (LOG10_2, "0.3010299956639811952137388947"),
+        (LN_2, "0.6931471805599453094172321215"),
+        (LN_10, "2.3025850929940456840179914547"),
+    ];
+    for &(constant, expected) in test_cases {
+        assert_eq!(expected, constant.to_string());
+    }
+}
+
+#[test]
+fn it_can_go_from_and_into() {
+    let d = Decimal::from_str("5").unwrap();
+    let di8 = 5u8.into();
+    let di32 = 5i32.into();
+    let disize = 5isize.into();
+    let di64 = 5i64.into();
+    let du8 = 5u8.into();
+    let du32 = 5u32.into();
+    let dusize = 5usize.into();
+    let du64 = 5u64.into();
+
+    assert_eq!(d, di8);
+    assert_eq!(di8, di32);
+    assert_eq!(di32, disize);
+    assert_eq!(disize, di64);
+    assert_eq!(di64, du8);
+    assert_eq!(du8, du32);
+    assert_eq!(du32, dusize);
+    assert_eq!(dusize, du64);
+}
+
+#[test]
+fn it_converts_to_f64() {
+    let tests = &[
+        ("5", Some(5f64)),
+        ("-5", Some(-5f64)),
+        ("0.1", Some(0.1f64)),
+        ("0.0", Some(0f64)),
+        ("-0.0", Some(0f64)),
+        ("0.0000000000025", Some(0.25e-11f64)),
+        ("1000000.0000000000025", Some(1e6f64)),
+        ("0.000000000000000000000000025", Some(0.25e-25_f64)),
+        (
+            "2.1234567890123456789012345678",
+            Some(2.1234567890123456789012345678_f64),
+        ),
+        (
+            "21234567890123456789012345678",
+            None, // Cannot be represented in an f64
+        ),
+        ("1.59283191", Some(1.59283191_f64)),
+    ];
+    for &(value, expected) in tests {
+        let value = Decimal::from_str(value).unwrap().to_f64();
+        assert_eq!(expected, value);
     }
 }
 
 #[test]
-fn it_ceils_decimals() {
+fn it_converts_to_f64_try() {
     let tests = &[
-        ("1", "1"),
-        ("1.00", "1"),
-        ("1.2345", "2"),
-        ("-1", "-1"),
-        ("-1.00", "-1"),
-        ("-1.2345", "-1"),
+        ("5", Some(5f64)),
+        ("-5", Some(-5f64)),
+        ("0.1", Some(0.1f64)),
+        ("0.0", Some(0f64)),
+        ("-0.0", Some(0f64)),
+        ("0.0000000000025", Some(0.25e-11f64)),
+        ("1000000.0000000000025", Some(1e6f64)),
+        ("0.000000000000000000000000025", Some(0.25e-25_f64)),
+        (
+            "2.1234567890123456789012345678",
+            Some(2.1234567890123456789012345678_f64),
+        ),
+        (
+            "21234567890123456789012345678",
+            None, // Cannot be represented in an f64
+        ),
+        ("1.59283191", Some(1.59283191_f64)),
     ];
-    for &(a, expected) in tests {
-        let a = Decimal::from_str(a).unwrap();
-        assert_eq!(expected, a.ceil().to_string(), "Failed ceiling {}", a);
-    }
-}
-
-#[test]
-fn it_finds_max_of_two() {
-    let tests = &[("1", "1", "1"), ("2", "1", "2"), ("1", "2", "2")];
-    for &(a, b, expected) in tests {
-        let a = Decimal::from_str(a).unwrap();
-        let b = Decimal::from_str(b).unwrap();
-        assert_eq!(expected, a.max(b).to_string());
-    }
-}
-
-#[test]
-fn it_finds_min_of_two() {
-    let tests = &[("1", "1", "1"), ("2", "1", "1"), ("1", "2", "1")];
-    for &(a, b, expected) in tests {
-        let a = Decimal::from_str(a).unwrap();
-        let b = Decimal::from_str(b).unwrap();
-        assert_eq!(expected, a.min(b).to_string());
+    for &(value, expected) in tests {
+        let value = Decimal::from_str(value).unwrap().try_into().ok();
+        assert_eq!(expected, value);
     }
 }
 
 #[test]
-fn test_max_compares() {
-    let x = "225.33543601344182".parse::<Decimal>().unwrap();
-    let y = Decimal::max_value();
-    assert!(x < y);
-    assert!(y > x);
-    assert_ne!(y, x);
+fn it_converts_to_i64() {
+    assert_eq!(5i64, Decimal::from_str("5").unwrap().to_i64().unwrap());
+    assert_eq!(-5i64, Decimal::from_str("-5").unwrap().to_i64().unwrap());
+    assert_eq!(5i64, Decimal::from_str("5.12345").unwrap().to_i64().unwrap());
+    assert_eq!(-5i64, Decimal::from_str("-5.12345").unwrap().to_i64().unwrap());
+    assert_eq!(
+        0x7FFF_FFFF_FFFF_FFFF,
+        Decimal::from_str("9223372036854775807").unwrap().to_i64().unwrap()
+    );
+    assert_eq!(None, Decimal::from_str("92233720368547758089").unwrap().to_i64());
 }
 
 #[test]
-fn test_min_compares() {
-    let x = "225.33543601344182".parse::<Decimal>().unwrap();
-    let y = Decimal::min_value();
-    assert!(x > y);
-    assert!(y < x);
-    assert_ne!(y, x);
+fn it_converts_to_u64() {
+    assert_eq!(5u64, Decimal::from_str("5").unwrap().to_u64().unwrap());
+    assert_eq!(None, Decimal::from_str("-5").unwrap().to_u64());
+    assert_eq!(5u64, Decimal::from_str("5.12345").unwrap().to_u64().unwrap());
+    assert_eq!(
+        0xFFFF_FFFF_FFFF_FFFF,
+        Decimal::from_str("18446744073709551615").unwrap().to_u64().unwrap()
+    );
+    assert_eq!(None, Decimal::from_str("18446744073709551616").unwrap().to_u64());
 }
 
 #[test]
-fn it_can_parse_from_i32() {
-    use num_traits::FromPrimitive;
-
+fn it_converts_to_i128() {
     let tests = &[
-        (0i32, "0"),
-        (1i32, "1"),
-        (-1i32, "-1"),
-        (i32::MAX, "2147483647"),
-        (i32::MIN, "-2147483648"),
+        ("5", Some(5i128)),
+        ("-5", Some(-5i128)),
+        ("5.12345", Some(5i128)),
+        ("-5.12345", Some(-5i128)),
+        ("9223372036854775807", Some(0x7FFF_FFFF_FFFF_FFFF)),
+        ("92233720368547758089", Some(92233720368547758089i128)),
     ];
-    for &(input, expected) in tests {
-        let parsed = Decimal::from_i32(input).unwrap();
-        assert_eq!(
-            expected,
-            parsed.to_string(),
-            "expected {} does not match parsed {}",
-            expected,
-            parsed
-        );
-        assert_eq!(
-            input.to_string(),
-            parsed.to_string(),
-            "i32 to_string {} does not match parsed {}",
-            input,
-            parsed
-        );
+    for (dec, expected) in tests {
+        assert_eq!(Decimal::from_str(dec).unwrap().to_i128(), *expected);
     }
+
+    assert_eq!(
+        79_228_162_514_264_337_593_543_950_335_i128,
+        Decimal::max_value().to_i128().unwrap()
+    );
 }
 
 #[test]
-fn it_can_parse_from_i64() {
-    use num_traits::FromPrimitive;
-
+fn it_converts_to_u128() {
     let tests = &[
-        (0i64, "0"),
-        (1i64, "1"),
-        (-1i64, "-1"),
-        (i64::MAX, "9223372036854775807"),
-        (i64::MIN, "-9223372036854775808"),
+        ("5", Some(5u128)),
+        ("-5", None),
+        ("5.12345", Some(5u128)),
+        ("-5.12345", None),
+        ("18446744073709551615", Some(0xFFFF_FFFF_FFFF_FFFF)),
+        ("18446744073709551616", Some(18446744073709551616u128)),
     ];
-    for &(input, expected) in tests {
-        let parsed = Decimal::from_i64(input).unwrap();
-        assert_eq!(
-            expected,
-            parsed.to_string(),
-            "expected {} does not match parsed {}",
-            expected,
-            parsed
-        );
-        assert_eq!(
-            input.to_string(),
-            parsed.to_string(),
-            "i64 to_string {} does not match parsed {}",
-            input,
-            parsed
-        );
+    for (dec, expected) in tests {
+        assert_eq!(Decimal::from_str(dec).unwrap().to_u128(), *expected);
     }
+    assert_eq!(
+        79_228_162_514_264_337_593_543_950_335_u128,
+        Decimal::max_value().to_u128().unwrap()
+    );
 }
 
 #[test]
-fn it_can_round_to_2dp() {
-    let a = Decimal::from_str("6.12345").unwrap();
-    let b = (Decimal::from_str("100").unwrap() * a).round() / Decimal::from_str("100").unwrap();
-    assert_eq!("6.12", b.to_string());
-}
-
-#[test]
-fn it_can_round_using_basic_midpoint_rules() {
-    let tests = &[
-        ("3.5", RoundingStrategy::MidpointAwayFromZero, "4"),
-        ("2.8", RoundingStrategy::MidpointAwayFromZero, "3"),
-        ("2.5", RoundingStrategy::MidpointAwayFromZero, "3"),
-        ("2.1", RoundingStrategy::MidpointAwayFromZero, "2"),
-        ("-2.1", RoundingStrategy::MidpointAwayFromZero, "-2"),
-        ("-2.5", RoundingStrategy::MidpointAwayFromZero, "-3"),
-        ("-2.8", RoundingStrategy::MidpointAwayFromZero, "-3"),
-        ("-3.5", RoundingStrategy::MidpointAwayFromZero, "-4"),
-        ("3.5", RoundingStrategy::MidpointNearestEven, "4"),
-        ("2.8", RoundingStrategy::MidpointNearestEven, "3"),
-        ("2.5", RoundingStrategy::MidpointNearestEven, "2"),
-        ("2.1", RoundingStrategy::MidpointNearestEven, "2"),
-        ("-2.1", RoundingStrategy::MidpointNearestEven, "-2"),
-        ("-2.5", RoundingStrategy::MidpointNearestEven, "-2"),
-        ("-2.8", RoundingStrategy::MidpointNearestEven, "-3"),
-        ("-3.5", RoundingStrategy::MidpointNearestEven, "-4"),
-        ("3.5", RoundingStrategy::MidpointTowardZero, "3"),
-        ("2.8", RoundingStrategy::MidpointTowardZero, "3"),
-        ("2.5", RoundingStrategy::MidpointTowardZero, "2"),
-        ("2.1", RoundingStrategy::MidpointTowardZero, "2"),
-        ("-2.1", RoundingStrategy::MidpointTowardZero, "-2"),
-        ("-2.5", RoundingStrategy::MidpointTowardZero, "-2"),
-        ("-2.8", RoundingStrategy::MidpointTowardZero, "-3"),
-        ("-3.5", RoundingStrategy::MidpointTowardZero, "-3"),
-        ("2.8", RoundingStrategy::ToNegativeInfinity, "2"),
-        ("2.5", RoundingStrategy::ToNegativeInfinity, "2"),
-        ("2.1", RoundingStrategy::ToNegativeInfinity, "2"),
-        ("-2.1", RoundingStrategy::ToNegativeInfinity, "-3"),
-        ("-2.5", RoundingStrategy::ToNegativeInfinity, "-3"),
-        ("-2.8", RoundingStrategy::ToNegativeInfinity, "-3"),
-        ("2.8", RoundingStrategy::ToPositiveInfinity, "3"),
-        ("2.5", RoundingStrategy::ToPositiveInfinity, "3"),
-        ("2.1", RoundingStrategy::ToPositiveInfinity, "3"),
-        ("-2.1", RoundingStrategy::ToPositiveInfinity, "-2"),
-        ("-2.5", RoundingStrategy::ToPositiveInfinity, "-2"),
-        ("-2.8", RoundingStrategy::ToPositiveInfinity, "-2"),
-        ("2.8", RoundingStrategy::ToZero, "2"),
-        ("2.5", RoundingStrategy::ToZero, "2"),
-        ("2.1", RoundingStrategy::ToZero, "2"),
-        ("-2.1", RoundingStrategy::ToZero, "-2"),
-        ("-2.5", RoundingStrategy::ToZero, "-2"),
-        ("-2.8", RoundingStrategy::ToZero, "-2"),
-        ("2.8", RoundingStrategy::AwayFromZero, "3"),
-        ("2.5", RoundingStrategy::AwayFromZero, "3"),
-        ("2.1", RoundingStrategy::AwayFromZero, "3"),
-        ("-2.1", RoundingStrategy::AwayFromZero, "-3"),
-        ("-2.5", RoundingStrategy::AwayFromZero, "-3"),
-        ("-2.8", RoundingStrategy::AwayFromZero, "-3"),
+fn it_converts_from_i128() {
+    let tests: &[(i128, Option<&str>)] = &[
+        (5, Some("5")),
+        (-5, Some("-5")),
+        (0x7FFF_FFFF_FFFF_FFFF, Some("9223372036854775807")),
+        (92233720368547758089, Some("92233720368547758089")),
+        (0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF, Some("79228162514264337593543950335")),
+        (0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF, None),
     ];
-
-    for &(input, strategy, expected) in tests {
-        let a = Decimal::from_str(input).unwrap();
-        let b = a.round_dp_with_strategy(0, strategy);
-        assert_eq!(expected, b.to_string(), "{} > {} for {:?}", input, expected, strategy);
+    for (value, expected) in tests {
+        if let Some(expected_value) = expected {
+            let decimal = Decimal::from_str(expected_value).unwrap();
+            assert_eq!(num_traits::FromPrimitive::from_i128(*value), Some(decimal));
+        }
     }
 }
 
 #[test]
-fn it_can_round_using_bankers_rounding() {
-    let tests = &[
-        ("6.12345", 2, "6.12"),
-        ("6.126", 2, "6.13"),
-        ("-6.126", 2, "-6.13"),
-        ("6.5", 0, "6"),
-        ("7.5", 0, "8"),
-        ("1.2250", 2, "1.22"),
-        ("1.2252", 2, "1.23"),
-        ("1.2249", 2, "1.22"),
-        ("6.1", 2, "6.1"),
-        ("0.0000", 2, "0.00"),
-        ("0.6666666666666666666666666666", 2, "0.67"),
-        ("1.40", 0, "1"),
-        ("2.60", 0, "3"),
-        ("2.1234567890123456789012345678", 27, "2.123456789012345678901234568"),
+fn it_converts_from_u128() {
+    let tests: &[(u128, Option<&str>)] = &[
+        (5, Some("5")),
+        (0xFFFF_FFFF_FFFF_FFFF, Some("18446744073709551615")),
+        (0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF, Some("79228162514264337593543950335")),
+        (0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF, None),
     ];
-    for &(input, dp, expected) in tests {
-        let a = Decimal::from_str(input).unwrap();
-        #[allow(deprecated)]
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::BankersRounding);
-        assert_eq!(expected, b.to_string(), "BankersRounding");
-
-        // Recommended replacement
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::MidpointNearestEven);
-        assert_eq!(expected, b.to_string(), "MidpointNearestEven");
+    for (value, expected) in tests {
+        if let Some(expected_value) = expected {
+            let decimal = Decimal::from_str(expected_value).unwrap();
+            assert_eq!(num_traits::FromPrimitive::from_u128(*value), Some(decimal));
+        }
     }
 }
 
 #[test]
-fn it_can_round_complex_numbers_using_bankers_rounding() {
-    // Issue #71
-    let rate = Decimal::new(19, 2); // 0.19
-    let one = Decimal::new(1, 0); // 1
-    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
+fn it_converts_from_f32() {
+    fn from_f32(f: f32) -> Option<Decimal> {
+        num_traits::FromPrimitive::from_f32(f)
+    }
 
-    #[allow(deprecated)]
-    let part = part.round_dp_with_strategy(2, RoundingStrategy::BankersRounding); // 0.16
-    assert_eq!("0.16", part.to_string(), "BankersRounding");
+    assert_eq!("1", from_f32(1f32).unwrap().to_string());
+    assert_eq!("0", from_f32(0f32).unwrap().to_string());
+    assert_eq!("0.12345", from_f32(0.12345f32).unwrap().to_string());
+    assert_eq!(
+        "0.12345678",
+        from_f32(0.1234567800123456789012345678f32).unwrap().to_string()
+    );
+    assert_eq!(
+        "0.12345679",
+        from_f32(0.12345678901234567890123456789f32).unwrap().to_string()
+    );
+    assert_eq!("0", from_f32(0.00000000000000000000000000001f32).unwrap().to_string());
 
-    // Recommended replacement
-    let part = part.round_dp_with_strategy(2, RoundingStrategy::MidpointNearestEven); // 0.16
-    assert_eq!("0.16", part.to_string(), "MidpointNearestEven");
+    assert!(from_f32(f32::NAN).is_none());
+    assert!(from_f32(f32::INFINITY).is_none());
+
+    // These both overflow
+    assert!(from_f32(f32::MAX).is_none());
+    assert!(from_f32(f32::MIN).is_none());
 }
 
 #[test]
-fn it_can_round_using_round_half_up() {
-    let tests = &[
-        ("0", 0, "0"),
-        ("1.234", 3, "1.234"),
-        ("1.12", 5, "1.12"),
-        ("6.34567", 2, "6.35"),
-        ("6.5", 0, "7"),
-        ("12.49", 0, "12"),
-        ("0.6666666666666666666666666666", 2, "0.67"),
-        ("1.40", 0, "1"),
-        ("2.60", 0, "3"),
-        ("2.1234567890123456789012345678", 27, "2.123456789012345678901234568"),
-    ];
-    for &(input, dp, expected) in tests {
-        let a = Decimal::from_str(input).unwrap();
-        #[allow(deprecated)]
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundHalfUp);
-        assert_eq!(expected, b.to_string(), "RoundHalfUp");
+fn it_converts_from_f32_try() {
+    assert_eq!("1", Decimal::try_from(1f32).unwrap().to_string());
+    assert_eq!("0", Decimal::try_from(0f32).unwrap().to_string());
+    assert_eq!("0.12345", Decimal::try_from(0.12345f32).unwrap().to_string());
+    assert_eq!(
+        "0.12345678",
+        Decimal::try_from(0.1234567800123456789012345678f32)
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!(
+        "0.12345679",
+        Decimal::try_from(0.12345678901234567890123456789f32)
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!(
+        "0",
+        Decimal::try_from(0.00000000000000000000000000001f32)
+            .unwrap()
+            .to_string()
+    );
 
-        // Recommended replacement
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero);
-        assert_eq!(expected, b.to_string(), "MidpointAwayFromZero");
-    }
+    assert!(Decimal::try_from(f32::NAN).is_err());
+    assert!(Decimal::try_from(f32::INFINITY).is_err());
+
+    // These both overflow
+    assert!(Decimal::try_from(f32::MAX).is_err());
+    assert!(Decimal::try_from(f32::MIN).is_err());
 }
 
 #[test]
-fn it_can_round_complex_numbers_using_round_half_up() {
-    // Issue #71
-    let rate = Decimal::new(19, 2); // 0.19
-    let one = Decimal::new(1, 0); // 1
-    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
-    #[allow(deprecated)]
-    let part = part.round_dp_with_strategy(2, RoundingStrategy::RoundHalfUp); // 0.16
-    assert_eq!("0.16", part.to_string(), "RoundHalfUp");
+fn it_converts_from_f64() {
+    fn from_f64(f: f64) -> Option<Decimal> {
+        num_traits::FromPrimitive::from_f64(f)
+    }
 
-    // Recommended replacement
-    let part = part.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero); // 0.16
-    assert_eq!("0.16", part.to_string(), "MidpointAwayFromZero");
+    assert_eq!("1", from_f64(1f64).unwrap().to_string());
+    assert_eq!("0", from_f64(0f64).unwrap().to_string());
+    assert_eq!("0.12345", from_f64(0.12345f64).unwrap().to_string());
+    assert_eq!(
+        "0.1234567890123456",
+        from_f64(0.1234567890123456089012345678f64).unwrap().to_string()
+    );
+    assert_eq!(
+        "0.1234567890123457",
+        from_f64(0.12345678901234567890123456789f64).unwrap().to_string()
+    );
+    assert_eq!("0", from_f64(0.00000000000000000000000000001f64).unwrap().to_string());
+    assert_eq!("0.6927", from_f64(0.6927f64).unwrap().to_string());
+    assert_eq!("0.00006927", from_f64(0.00006927f64).unwrap().to_string());
+    assert_eq!("0.000000006927", from_f64(0.000000006927f64).unwrap().to_string());
+
+    assert!(from_f64(f64::NAN).is_none());
+    assert!(from_f64(f64::INFINITY).is_none());
+
+    // These both overflow
+    assert!(from_f64(f64::MAX).is_none());
+    assert!(from_f64(f64::MIN).is_none());
 }
 
 #[test]
-fn it_can_round_using_round_half_down() {
-    let tests = &[
-        ("0", 0, "0"),
-        ("1.234", 3, "1.234"),
-        ("1.12", 5, "1.12"),
-        ("6.34567", 2, "6.35"),
-        ("6.51", 0, "7"),
-        ("12.5", 0, "12"),
-        ("0.6666666666666666666666666666", 2, "0.67"),
-        ("1.40", 0, "1"),
-        ("2.60", 0, "3"),
-        ("2.1234567890123456789012345678", 27, "2.123456789012345678901234568"),
-    ];
-    for &(input, dp, expected) in tests {
-        let a = Decimal::from_str(input).unwrap();
-        #[allow(deprecated)]
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundHalfDown);
-        assert_eq!(expected, b.to_string(), "RoundHalfDown");
+fn it_converts_from_f64_try() {
+    assert_eq!("1", Decimal::try_from(1f64).unwrap().to_string());
+    assert_eq!("0", Decimal::try_from(0f64).unwrap().to_string());
+    assert_eq!("0.12345", Decimal::try_from(0.12345f64).unwrap().to_string());
+    assert_eq!(
+        "0.1234567890123456",
+        Decimal::try_from(0.1234567890123456089012345678f64)
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!(
+        "0.1234567890123457",
+        Decimal::try_from(0.12345678901234567890123456789f64)
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!(
+        "0",
+        Decimal::try_from(0.00000000000000000000000000001f64)
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!("0.6927", Decimal::try_from(0.6927f64).unwrap().to_string());
+    assert_eq!("0.00006927", Decimal::try_from(0.00006927f64).unwrap().to_string());
+    assert_eq!(
+        "0.000000006927",
+        Decimal::try_from(0.000000006927f64).unwrap().to_string()
+    );
 
-        // Recommended replacement
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::MidpointTowardZero);
-        assert_eq!(expected, b.to_string(), "MidpointTowardZero");
-    }
+    assert!(Decimal::try_from(f64::NAN).is_err());
+    assert!(Decimal::try_from(f64::INFINITY).is_err());
+
+    // These both overflow
+    assert!(Decimal::try_from(f64::MAX).is_err());
+    assert!(Decimal::try_from(f64::MIN).is_err());
 }
 
 #[test]
-fn it_can_round_complex_numbers_using_round_half_down() {
+fn it_handles_simple_underflow() {
     // Issue #71
     let rate = Decimal::new(19, 2); // 0.19
     let one = Decimal::new(1, 0); // 1
     let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
+    let result = one * part;
+    assert_eq!("0.1596638655462184873949579832", result.to_string());
 
-    #[allow(deprecated)]
-    let part = part.round_dp_with_strategy(2, RoundingStrategy::RoundHalfDown); // 0.16
-    assert_eq!("0.16", part.to_string(), "RoundHalfDown");
-
-    // Recommended replacement
-    let part = part.round_dp_with_strategy(2, RoundingStrategy::MidpointTowardZero); // 0.16
-    assert_eq!("0.16", part.to_string(), "RoundHalfDown");
+    // 169 * 0.1596638655462184873949579832 = 26.983193277310924
+    let result = part * Decimal::new(169, 0);
+    assert_eq!("26.983193277310924369747899161", result.to_string());
+    let result = Decimal::new(169, 0) * part;
+    assert_eq!("26.983193277310924369747899161", result.to_string());
 }
 
 #[test]
-fn it_can_round_to_2dp_using_explicit_function() {
-    let a = Decimal::from_str("6.12345").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("6.12", b.to_string());
+fn it_can_parse_highly_significant_numbers() {
+    let tests = &[
+        ("11.111111111111111111111111111", "11.111111111111111111111111111"),
+        ("11.11111111111111111111111111111", "11.111111111111111111111111111"),
+        ("11.1111111111111111111111111115", "11.111111111111111111111111112"),
+        ("115.111111111111111111111111111", "115.11111111111111111111111111"),
+        ("1115.11111111111111111111111111", "1115.1111111111111111111111111"),
+        ("11.1111111111111111111111111195", "11.111111111111111111111111120"),
+        ("99.9999999999999999999999999995", "100.00000000000000000000000000"),
+        ("-11.1111111111111111111111111195", "-11.111111111111111111111111120"),
+        ("-99.9999999999999999999999999995", "-100.00000000000000000000000000"),
+        ("3.1415926535897932384626433832", "3.1415926535897932384626433832"),
+        (
+            "8808257419827262908.5944405087133154018",
+            "8808257419827262908.594440509",
+        ),
+        (
+            "8097370036018690744.2590371109596744091",
+            "8097370036018690744.259037111",
+        ),
+        (
+            "8097370036018690744.2590371149596744091",
+            "8097370036018690744.259037115",
+        ),
+        (
+            "8097370036018690744.2590371159596744091",
+            "8097370036018690744.259037116",
+        ),
+        ("1.234567890123456789012345678949999", "1.2345678901234567890123456789"),
+    ];
+    for &(value, expected) in tests {
+        assert_eq!(expected, Decimal::from_str(value).unwrap().to_string());
+    }
 }
 
 #[test]
-fn it_can_round_up_to_2dp_using_explicit_function() {
-    let a = Decimal::from_str("6.126").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("6.13", b.to_string());
+fn it_can_parse_alternative_formats() {
+    let tests = &[
+        ("1_000", "1000"),
+        ("1_000_000", "1000000"),
+        ("10_000_000", "10000000"),
+        ("100_000", "100000"),
+        // At the moment, we'll accept this
+        ("1_____________0", "10"),
+    ];
+    for &(value, expected) in tests {
+        assert_eq!(expected, Decimal::from_str(value).unwrap().to_string());
+    }
 }
 
 #[test]
-fn it_can_round_down_to_2dp_using_explicit_function() {
-    let a = Decimal::from_str("-6.126").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("-6.13", b.to_string());
+fn it_can_parse_fractional_numbers_with_underscore_separators() {
+    let a = Decimal::from_str("0.1_23_456").unwrap();
+    assert_eq!(a.is_sign_negative(), false);
+    assert_eq!(a.scale(), 6);
+    assert_eq!("0.123456", a.to_string());
 }
 
 #[test]
-fn it_can_round_down_using_bankers_rounding() {
-    let a = Decimal::from_str("6.5").unwrap();
-    let b = a.round_dp(0u32);
-    assert_eq!("6", b.to_string());
+fn it_can_parse_numbers_with_underscore_separators_before_decimal_point() {
+    let a = Decimal::from_str("1_234.56").unwrap();
+    assert_eq!(a.is_sign_negative(), false);
+    assert_eq!(a.scale(), 2);
+    assert_eq!("1234.56", a.to_string());
 }
 
 #[test]
-fn it_can_round_up_using_bankers_rounding() {
-    let a = Decimal::from_str("7.5").unwrap();
-    let b = a.round_dp(0u32);
-    assert_eq!("8", b.to_string());
+fn it_can_parse_numbers_and_round_correctly_with_underscore_separators_before_decimal_point() {
+    let tests = &[
+        (
+            "8_097_370_036_018_690_744.2590371159596744091",
+            "8097370036018690744.259037116",
+        ),
+        (
+            "8097370036018690744.259_037_115_959_674_409_1",
+            "8097370036018690744.259037116",
+        ),
+        (
+            "8_097_370_036_018_690_744.259_037_115_959_674_409_1",
+            "8097370036018690744.259037116",
+        ),
+    ];
+    for &(value, expected) in tests {
+        assert_eq!(expected, Decimal::from_str(value).unwrap().to_string());
+    }
 }
 
 #[test]
-fn it_can_round_correctly_using_bankers_rounding_1() {
-    let a = Decimal::from_str("1.2250").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("1.22", b.to_string());
+fn it_can_reject_invalid_formats() {
+    let tests = &["_1", "1.0.0", "10_00.0_00.0"];
+    for &value in tests {
+        assert!(
+            Decimal::from_str(value).is_err(),
+            "This succeeded unexpectedly: {}",
+            value
+        );
+    }
 }
 
 #[test]
-fn it_can_round_correctly_using_bankers_rounding_2() {
-    let a = Decimal::from_str("1.2251").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("1.23", b.to_string());
+fn it_can_reject_large_numbers_with_panic() {
+    let tests = &[
+        // The maximum number supported is 79,228,162,514,264,337,593,543,950,335
+        "79228162514264337593543950336",
+        "79228162514264337593543950337",
+        "79228162514264337593543950338",
+        "79228162514264337593543950339",
+        "79228162514264337593543950340",
+    ];
+    for &value in tests {
+        assert!(
+            Decimal::from_str(value).is_err(),
+            "This succeeded unexpectedly: {}",
+            value
+        );
+    }
 }
 
 #[test]
-fn it_can_round_down_when_required() {
-    let a = Decimal::from_str("1.2249").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("1.22", b.to_string());
+fn it_can_parse_individual_parts() {
+    let pi = Decimal::from_parts(1102470952, 185874565, 1703060790, false, 28);
+    assert_eq!(pi.to_string(), "3.1415926535897932384626433832");
 }
 
 #[test]
-fn it_can_round_to_2dp_using_explicit_function_without_changing_value() {
-    let a = Decimal::from_str("6.1").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("6.1", b.to_string());
-}
+fn it_can_parse_scientific_notation() {
+    let tests = &[
+        ("9.7e-7", "0.00000097"),
+        ("9e-7", "0.0000009"),
+        ("1.2e10", "12000000000"),
+        ("1.2e+10", "12000000000"),
+        ("12e10", "120000000000"),
+        ("9.7E-7", "0.00000097"),
+    ];
 
-#[test]
-fn it_can_round_zero() {
-    let a = Decimal::from_str("0.0000").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("0.00", b.to_string());
+    for &(value, expected) in tests {
+        assert_eq!(expected, Decimal::from_scientific(value).unwrap().to_string());
+    }
 }
 
 #[test]
-fn it_can_round_large_decimals() {
-    let a = Decimal::from_str("0.6666666666666666666666666666").unwrap();
-    let b = a.round_dp(2u32);
-    assert_eq!("0.67", b.to_string());
-}
+fn it_can_parse_different_radix() {
+    let tests = &[
+        // Input, Radix, Success, to_string()
+        ("123", 10, true, "123"),
+        ("123", 8, true, "83"),
+        ("123", 16, true, "291"),
+        ("abc", 10, false, ""),
+        ("abc", 16, true, "2748"),
+        ("78", 10, true, "78"),
+        ("78", 8, false, ""),
+        ("101", 2, true, "5"),
+        // Parse base 2
+        ("1111_1111_1111_1111_1111_1111_1111_1111", 2, true, "4294967295"),
+        // Max supported value
+        (
+            "1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_\
+          1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111",
+            2,
+            true,
+            &Decimal::max_value().to_string(),
+        ),
+        // We limit to 28 dp
+        (
+            "843.6500000000000000000000000000",
+            10,
+            true,
+            "843.6500000000000000000000000",
+        ),
+    ];
 
-#[test]
-fn it_can_round_simple_numbers_down() {
-    let a = Decimal::from_str("1.40").unwrap();
-    let b = a.round_dp(0u32);
-    assert_eq!("1", b.to_string());
+    for &(input, radix, success, expected) in tests {
+        let result = Decimal::from_str_radix(input, radix);
+        assert_eq!(
+            success,
+            result.is_ok(),
+            "Failed to parse: {} radix {}: {:?}",
+            input,
+            radix,
+            result.err()
+        );
+        if result.is_ok() {
+            assert_eq!(
+                expected,
+                result.unwrap().to_string(),
+                "Original input: {} radix {}",
+                input,
+                radix
+            );
+        }
+    }
 }
 
 #[test]
-fn it_can_round_simple_numbers_up() {
-    let a = Decimal::from_str("2.60").unwrap();
-    let b = a.round_dp(0u32);
-    assert_eq!("3", b.to_string());
+fn it_reports_the_kind_of_parse_error() {
+    let tests = &[
+        ("", 10, ErrorKind::Empty, "Invalid decimal: empty"),
+        (
+            "1.2a",
+            10,
+            ErrorKind::InvalidDigit { position: 3 },
+            "Invalid decimal: unknown character",
+        ),
+        (
+            "-x",
+            10,
+            ErrorKind::InvalidDigit { position: 1 },
+            "Invalid decimal: unknown character",
+        ),
+        (
+            "1.0.0",
+            10,
+            ErrorKind::MultipleDecimalPoints,
+            "Invalid decimal: two decimal points",
+        ),
+        (
+            "_1",
+            10,
+            ErrorKind::LeadingSeparator,
+            "Invalid decimal: must start lead with a number",
+        ),
+        ("-", 10, ErrorKind::NoDigits, "Invalid decimal: no digits found"),
+        (
+            "79228162514264337593543950336",
+            10,
+            ErrorKind::ExceedsMaximumPossibleValue,
+            "Invalid decimal: overflow from carry",
+        ),
+        (
+            "-79228162514264337593543950336",
+            10,
+            ErrorKind::LessThanMinimumPossibleValue,
+            "Invalid decimal: overflow from carry",
+        ),
+        (
+            "78",
+            8,
+            ErrorKind::InvalidDigit { position: 1 },
+            "Invalid decimal: invalid character",
+        ),
+        ("1", 1, ErrorKind::UnsupportedRadix(1), "Unsupported radix < 2"),
+        ("1", 37, ErrorKind::UnsupportedRadix(37), "Unsupported radix > 36"),
+    ];
+    for &(input, radix, kind, message) in tests {
+        let err = Decimal::from_str_radix(input, radix).unwrap_err();
+        assert_eq!(kind, err.kind(), "Input: {} radix {}", input, radix);
+        assert_eq!(message, err.to_string(), "Input: {} radix {}", input, radix);
+    }
 }
 
 #[test]
-fn it_can_round_simple_numbers_with_high_precision() {
-    let a = Decimal::from_str("2.1234567890123456789012345678").unwrap();
-    let b = a.round_dp(27u32);
-    assert_eq!("2.123456789012345678901234568", b.to_string());
+fn it_reports_the_kind_of_scientific_notation_error() {
+    let tests = &[
+        ("1.2", ErrorKind::InvalidFormat),
+        ("1.2ex", ErrorKind::InvalidFormat),
+        ("1e-29", ErrorKind::Underflow),
+        ("1e29", ErrorKind::ExceedsMaximumPossibleValue),
+        ("-1e40", ErrorKind::LessThanMinimumPossibleValue),
+    ];
+    for &(input, kind) in tests {
+        assert_eq!(
+            kind,
+            Decimal::from_scientific(input).unwrap_err().kind(),
+            "Input: {}",
+            input
+        );
+    }
+    assert_eq!(
+        "100000000000000000000",
+        Decimal::from_scientific("1e20").unwrap().to_string()
+    );
 }
 
 #[test]
-fn it_can_round_complex_numbers() {
-    // Issue #71
-    let rate = Decimal::new(19, 2); // 0.19
-    let one = Decimal::new(1, 0); // 1
-    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
-    let part = part.round_dp(2); // 0.16
-    assert_eq!("0.16", part.to_string());
+fn it_reports_the_kind_of_scale_and_conversion_errors() {
+    let mut value = Decimal::ONE;
+    assert_eq!(
+        ErrorKind::ScaleExceedsMaximumPrecision(29),
+        value.set_scale(29).unwrap_err().kind()
+    );
+    assert_eq!(
+        ErrorKind::ConversionTo("Decimal"),
+        Decimal::try_from(f64::NAN).unwrap_err().kind()
+    );
 }
 
 #[test]
-fn it_can_round_down() {
-    let tests = &[
-        ("0.470", 1, "0.4"),
-        ("-0.470", 1, "-0.4"), // Toward zero
-        ("0.400", 1, "0.4"),
-        ("-0.400", 1, "-0.4"),
-    ];
-    for &(input, dp, expected) in tests {
-        let a = Decimal::from_str(input).unwrap();
-        #[allow(deprecated)]
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundDown);
-        assert_eq!(expected, b.to_string(), "RoundDown");
+fn it_can_calculate_signum() {
+    let tests = &[("123", 1), ("-123", -1), ("0", 0)];
 
-        // Recommended replacement
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::ToZero);
-        assert_eq!(expected, b.to_string(), "ToZero");
+    for &(input, expected) in tests {
+        let input = Decimal::from_str(input).unwrap();
+        assert_eq!(expected, input.signum().to_i32().unwrap(), "Input: {}", input);
+        assert_eq!(expected, Signed::signum(&input).to_i32().unwrap(), "Input: {}", input);
     }
 }
 
 #[test]
-fn it_can_round_up() {
+fn it_can_calculate_abs_sub() {
     let tests = &[
-        ("2.8", 0, "3"),
-        ("2.5", 0, "3"),
-        ("2.1", 0, "3"),
-        ("-2.1", 0, "-3"),
-        ("-2.5", 0, "-3"),
-        ("-2.8", 0, "-3"),
-        ("0.320", 1, "0.4"),
-        ("-0.320", 1, "-0.4"),
-        ("0.300", 1, "0.3"),
-        ("-0.300", 1, "-0.3"),
+        ("123", "124", "0"),
+        ("123", "123", "0"),
+        ("123", "122", "1"),
+        ("-123", "-124", "1"),
+        ("-123", "-123", "0"),
+        ("-123", "-122", "0"),
+        ("1.5", "-2.25", "3.75"),
+        ("-1.5", "2.25", "0"),
     ];
 
-    for &(input, dp, expected) in tests {
-        let a = Decimal::from_str(input).unwrap();
-        #[allow(deprecated)]
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::RoundUp);
-        assert_eq!(expected, b.to_string(), "RoundUp");
-
-        // Recommended replacement
-        let b = a.round_dp_with_strategy(dp, RoundingStrategy::AwayFromZero);
-        assert_eq!(expected, b.to_string(), "AwayFromZero");
+    for &(input1, input2, expected) in tests {
+        let input1 = Decimal::from_str(input1).unwrap();
+        let input2 = Decimal::from_str(input2).unwrap();
+        assert_eq!(
+            expected,
+            input1.abs_sub(&input2).to_string(),
+            "Input: {} {}",
+            input1,
+            input2
+        );
+        assert_eq!(
+            expected,
+            Signed::abs_sub(&input1, &input2).to_string(),
+            "Input: {} {}",
+            input1,
+            input2
+        );
     }
 }
 
 #[test]
-fn it_can_trunc() {
-    let tests = &[("1.00000000000000000000", "1"), ("1.000000000000000000000001", "1")];
+fn it_can_calculate_checked_neg_and_abs() {
+    let tests = &[
+        ("123.45", "-123.45", "123.45"),
+        ("-123.45", "123.45", "123.45"),
+        (
+            "79228162514264337593543950335",
+            "-79228162514264337593543950335",
+            "79228162514264337593543950335",
+        ),
+        (
+            "-79228162514264337593543950335",
+            "79228162514264337593543950335",
+            "79228162514264337593543950335",
+        ),
+    ];
 
-    for &(value, expected) in tests {
-        let value = Decimal::from_str(value).unwrap();
-        let expected = Decimal::from_str(expected).unwrap();
-        let trunc = value.trunc();
-        assert_eq!(expected.to_string(), trunc.to_string());
+    for &(input, neg, abs) in tests {
+        let input = Decimal::from_str(input).unwrap();
+        assert_eq!(neg, input.checked_neg().unwrap().to_string(), "Input: {}", input);
+        assert_eq!(
+            neg,
+            CheckedNeg::checked_neg(&input).unwrap().to_string(),
+            "Input: {}",
+            input
+        );
+        assert_eq!(abs, input.checked_abs().unwrap().to_string(), "Input: {}", input);
     }
 }
 
 #[test]
-fn it_can_fract() {
+fn it_can_copysign() {
     let tests = &[
-        ("1.00000000000000000000", "0.00000000000000000000"),
-        ("1.000000000000000000000001", "0.000000000000000000000001"),
+        ("1.5", "2", "1.5"),
+        ("1.5", "-2", "-1.5"),
+        ("-1.5", "2", "1.5"),
+        ("-1.5", "-0.0001", "-1.5"),
+        ("-1.5", "0", "1.5"),
     ];
 
-    for &(value, expected) in tests {
-        let value = Decimal::from_str(value).unwrap();
-        let expected = Decimal::from_str(expected).unwrap();
-        let fract = value.fract();
-        assert_eq!(expected.to_string(), fract.to_string());
+    for &(input, sign, expected) in tests {
+        let input = Decimal::from_str(input).unwrap();
+        let sign = Decimal::from_str(sign).unwrap();
+        assert_eq!(expected, input.copysign(sign).to_string(), "Input: {} {}", input, sign);
     }
 }
 
 #[test]
-fn it_can_normalize() {
+fn it_can_invert() {
     let tests = &[
-        ("1.00000000000000000000", "1"),
-        ("1.10000000000000000000000", "1.1"),
-        ("1.00010000000000000000000", "1.0001"),
-        ("1", "1"),
-        ("1.1", "1.1"),
-        ("1.0001", "1.0001"),
-        ("-0", "0"),
-        ("-0.0", "0"),
-        ("-0.010", "-0.01"),
-        ("0.0", "0"),
+        ("2", "0.5"),
+        ("-4", "-0.25"),
+        ("0.125", "8"),
+        ("3", "0.3333333333333333333333333333"),
     ];
 
-    for &(value, expected) in tests {
-        let value = Decimal::from_str(value).unwrap();
+    for &(input, expected) in tests {
+        let input = Decimal::from_str(input).unwrap();
         let expected = Decimal::from_str(expected).unwrap();
-        let normalized = value.normalize();
-        assert_eq!(expected.to_string(), normalized.to_string());
+        assert_eq!(expected, input.inv(), "Input: {}", input);
+        assert_eq!(expected, (&input).inv(), "Input: {}", input);
     }
 }
 
 #[test]
-fn it_can_return_the_max_value() {
-    assert_eq!("79228162514264337593543950335", Decimal::max_value().to_string());
+fn it_is_bounded() {
+    assert_eq!(Decimal::MIN, <Decimal as Bounded>::min_value());
+    assert_eq!(Decimal::MAX, <Decimal as Bounded>::max_value());
 }
 
 #[test]
-fn it_can_return_the_min_value() {
-    assert_eq!("-79228162514264337593543950335", Decimal::min_value().to_string());
+fn it_can_parse_num_from_str_radix() {
+    let tests = &[("ff", 16), ("-1.1", 2), ("123.456", 10), ("zz", 36), ("12", 2)];
+
+    for &(input, radix) in tests {
+        assert_eq!(
+            Decimal::from_str_radix(input, radix),
+            <Decimal as Num>::from_str_radix(input, radix),
+            "Input: {} {}",
+            input,
+            radix
+        );
+    }
 }
 
 #[test]
-fn it_can_go_from_and_into() {
-    let d = Decimal::from_str("5").unwrap();
-    let di8 = 5u8.into();
-    let di32 = 5i32.into();
-    let disize = 5isize.into();
-    let di64 = 5i64.into();
-    let du8 = 5u8.into();
-    let du32 = 5u32.into();
-    let dusize = 5usize.into();
-    let du64 = 5u64.into();
+#[should_panic]
+fn it_panics_when_scale_too_large() {
+    let _ = Decimal::new(1, 29);
+}
 
-    assert_eq!(d, di8);
-    assert_eq!(di8, di32);
-    assert_eq!(di32, disize);
-    assert_eq!(disize, di64);
-    assert_eq!(di64, du8);
-    assert_eq!(du8, du32);
-    assert_eq!(du32, dusize);
-    assert_eq!(dusize, du64);
+#[test]
+fn test_zero_eq_negative_zero() {
+    let zero: Decimal = 0.into();
+
+    assert_eq!(zero, zero);
+    assert_eq!(-zero, zero);
+    assert_eq!(zero, -zero);
 }
 
 #[test]
-fn it_converts_to_f64() {
-    let tests = &[
-        ("5", Some(5f64)),
-        ("-5", Some(-5f64)),
-        ("0.1", Some(0.1f64)),
-        ("0.0", Some(0f64)),
-        ("-0.0", Some(0f64)),
-        ("0.0000000000025", Some(0.25e-11f64)),
-        ("1000000.0000000000025", Some(1e6f64)),
-        ("0.000000000000000000000000025", Some(0.25e-25_f64)),
-        (
-            "2.1234567890123456789012345678",
-            Some(2.1234567890123456789012345678_f64),
-        ),
-        (
-            "21234567890123456789012345678",
-            None, // Cannot be represented in an f64
-        ),
-        ("1.59283191", Some(1.59283191_f64)),
-    ];
-    for &(value, expected) in tests {
-        let value = Decimal::from_str(value).unwrap().to_f64();
-        assert_eq!(expected, value);
-    }
+fn declarative_dec_sum() {
+    let vs = (0..10).map(|i| i.into()).collect::<Vec<Decimal>>();
+    let sum: Decimal = vs.iter().cloned().sum();
+
+    assert_eq!(sum, Decimal::from(45))
 }
 
 #[test]
-fn it_converts_to_f64_try() {
-    let tests = &[
-        ("5", Some(5f64)),
-        ("-5", Some(-5f64)),
-        ("0.1", Some(0.1f64)),
-        ("0.0", Some(0f64)),
-        ("-0.0", Some(0f64)),
-        ("0.0000000000025", Some(0.25e-11f64)),
-        ("1000000.0000000000025", Some(1e6f64)),
-        ("0.000000000000000000000000025", Some(0.25e-25_f64)),
-        (
-            "2.1234567890123456789012345678",
-            Some(2.1234567890123456789012345678_f64),
-        ),
-        (
-            "21234567890123456789012345678",
-            None, // Cannot be represented in an f64
-        ),
-        ("1.59283191", Some(1.59283191_f64)),
-    ];
-    for &(value, expected) in tests {
-        let value = Decimal::from_str(value).unwrap().try_into().ok();
-        assert_eq!(expected, value);
-    }
+fn declarative_ref_dec_sum() {
+    let vs = (0..10).map(|i| i.into()).collect::<Vec<Decimal>>();
+    let sum: Decimal = vs.iter().sum();
+
+    assert_eq!(sum, Decimal::from(45))
 }
 
 #[test]
-fn it_converts_to_i64() {
-    assert_eq!(5i64, Decimal::from_str("5").unwrap().to_i64().unwrap());
-    assert_eq!(-5i64, Decimal::from_str("-5").unwrap().to_i64().unwrap());
-    assert_eq!(5i64, Decimal::from_str("5.12345").unwrap().to_i64().unwrap());
-    assert_eq!(-5i64, Decimal::from_str("-5.12345").unwrap().to_i64().unwrap());
-    assert_eq!(
-        0x7FFF_FFFF_FFFF_FFFF,
-        Decimal::from_str("9223372036854775807").unwrap().to_i64().unwrap()
-    );
-    assert_eq!(None, Decimal::from_str("92233720368547758089").unwrap().to_i64());
+fn declarative_dec_product() {
+    let vs = (1..6).map(|i| i.into()).collect::<Vec<Decimal>>();
+    let product: Decimal = vs.iter().cloned().product();
+
+    assert_eq!(product, Decimal::from(120))
 }
 
 #[test]
-fn it_converts_to_u64() {
-    assert_eq!(5u64, Decimal::from_str("5").unwrap().to_u64().unwrap());
-    assert_eq!(None, Decimal::from_str("-5").unwrap().to_u64());
-    assert_eq!(5u64, Decimal::from_str("5.12345").unwrap().to_u64().unwrap());
-    assert_eq!(
-        0xFFFF_FFFF_FFFF_FFFF,
-        Decimal::from_str("18446744073709551615").unwrap().to_u64().unwrap()
-    );
-    assert_eq!(None, Decimal::from_str("18446744073709551616").unwrap().to_u64());
+fn declarative_ref_dec_product() {
+    let vs = (1..6).map(|i| i.into()).collect::<Vec<Decimal>>();
+    let product: Decimal = vs.iter().product();
+
+    assert_eq!(product, Decimal::from(120))
 }
 
 #[test]
-fn it_converts_to_i128() {
-    let tests = &[
-        ("5", Some(5i128)),
-        ("-5", Some(-5i128)),
-        ("5.12345", Some(5i128)),
-        ("-5.12345", Some(-5i128)),
-        ("9223372036854775807", Some(0x7FFF_FFFF_FFFF_FFFF)),
-        ("92233720368547758089", Some(92233720368547758089i128)),
+fn it_can_checked_sum() {
+    let tests: &[(&[&str], Option<&str>)] = &[
+        (&[], Some("0")),
+        (&["1.5", "2.25", "-0.75"], Some("3.00")),
+        (&["79228162514264337593543950335", "1"], None),
+        (&["79228162514264337593543950335", "1", "-1"], None),
+        (
+            &["79228162514264337593543950335", "-1", "1"],
+            Some("79228162514264337593543950335"),
+        ),
+        (&["-79228162514264337593543950335", "-1"], None),
     ];
-    for (dec, expected) in tests {
-        assert_eq!(Decimal::from_str(dec).unwrap().to_i128(), *expected);
+    for &(values, expected) in tests {
+        let values = values.iter().map(|v| Decimal::from_str(v).unwrap()).collect::<Vec<_>>();
+        let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+        assert_eq!(expected, Decimal::checked_sum(&values), "{:?}", values);
+        assert_eq!(expected, Decimal::checked_sum(values.iter().cloned()), "{:?}", values);
     }
-
-    assert_eq!(
-        79_228_162_514_264_337_593_543_950_335_i128,
-        Decimal::max_value().to_i128().unwrap()
-    );
 }
 
 #[test]
-fn it_converts_to_u128() {
-    let tests = &[
-        ("5", Some(5u128)),
-        ("-5", None),
-        ("5.12345", Some(5u128)),
-        ("-5.12345", None),
-        ("18446744073709551615", Some(0xFFFF_FFFF_FFFF_FFFF)),
-        ("18446744073709551616", Some(18446744073709551616u128)),
+fn it_can_checked_product() {
+    let tests: &[(&[&str], Option<&str>)] = &[
+        (&[], Some("1")),
+        (&["1.5", "2", "-0.5"], Some("-1.50")),
+        (&["79228162514264337593543950335", "2"], None),
+        (&["79228162514264337593543950335", "2", "0"], None),
+        (
+            &["79228162514264337593543950335", "-1", "-1"],
+            Some("79228162514264337593543950335"),
+        ),
     ];
-    for (dec, expected) in tests {
-        assert_eq!(Decimal::from_str(dec).unwrap().to_u128(), *expected);
+    for &(values, expected) in tests {
+        let values = values.iter().map(|v| Decimal::from_str(v).unwrap()).collect::<Vec<_>>();
+        let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+        assert_eq!(expected, Decimal::checked_product(&values), "{:?}", values);
+        assert_eq!(
+            expected,
+            Decimal::checked_product(values.iter().cloned()),
+            "{:?}",
+            values
+        );
     }
-    assert_eq!(
-        79_228_162_514_264_337_593_543_950_335_u128,
-        Decimal::max_value().to_u128().unwrap()
-    );
 }
 
+#[cfg(feature = "postgres")]
 #[test]
-fn it_converts_from_i128() {
-    let tests: &[(i128, Option<&str>)] = &[
-        (5, Some("5")),
-        (-5, Some("-5")),
-        (0x7FFF_FFFF_FFFF_FFFF, Some("9223372036854775807")),
-        (92233720368547758089, Some("92233720368547758089")),
-        (0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF, Some("79228162514264337593543950335")),
-        (0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF, None),
+fn to_from_sql() {
+    use bytes::BytesMut;
+    use postgres::types::{FromSql, Kind, ToSql, Type};
+
+    let tests = &[
+        "3950.123456",
+        "3950",
+        "0.1",
+        "0.01",
+        "0.001",
+        "0.0001",
+        "0.00001",
+        "0.000001",
+        "1",
+        "-100",
+        "-123.456",
+        "119996.25",
+        "1000000",
+        "9999999.99999",
+        "12340.56789",
+        "79228162514264337593543950335", // 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF (96 bit)
+        "4951760157141521099596496895",  // 0x0FFF_FFFF_FFFF_FFFF_FFFF_FFFF (95 bit)
+        "4951760157141521099596496896",  // 0x1000_0000_0000_0000_0000_0000
+        "18446744073709551615",
+        "-18446744073709551615",
     ];
-    for (value, expected) in tests {
-        if let Some(expected_value) = expected {
-            let decimal = Decimal::from_str(expected_value).unwrap();
-            assert_eq!(num_traits::FromPrimitive::from_i128(*value), Some(decimal));
-        }
+
+    let t = Type::new("".into(), 0, Kind::Simple, "".into());
+
+    for test in tests {
+        let input = Decimal::from_str(test).unwrap();
+        let mut bytes = BytesMut::new();
+        input.to_sql(&t, &mut bytes).unwrap();
+        let output = Decimal::from_sql(&t, &bytes).unwrap();
+
+        assert_eq!(input, output);
     }
 }
 
-#[test]
-fn it_converts_from_u128() {
-    let tests: &[(u128, Option<&str>)] = &[
-        (5, Some("5")),
-        (0xFFFF_FFFF_FFFF_FFFF, Some("18446744073709551615")),
-        (0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF, Some("79228162514264337593543950335")),
-        (0x7FFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF, None),
-    ];
-    for (value, expected) in tests {
-        if let Some(expected_value) = expected {
-            let decimal = Decimal::from_str(expected_value).unwrap();
-            assert_eq!(num_traits::FromPrimitive::from_u128(*value), Some(decimal));
-        }
-    }
+fn hash_it(d: Decimal) -> u64 {
+    use core::hash::{Hash, Hasher};
+    use std::collections::hash_map::DefaultHasher;
+
+    let mut h = DefaultHasher::new();
+    d.hash(&mut h);
+    h.finish()
 }
 
 #[test]
-fn it_converts_from_f32() {
-    fn from_f32(f: f32) -> Option<Decimal> {
-        num_traits::FromPrimitive::from_f32(f)
-    }
+fn it_computes_equal_hashes_for_equal_values() {
+    // From the Rust Hash docs:
+    //
+    // "When implementing both Hash and Eq, it is important that the following property holds:
+    //
+    //     k1 == k2 -> hash(k1) == hash(k2)"
 
-    assert_eq!("1", from_f32(1f32).unwrap().to_string());
-    assert_eq!("0", from_f32(0f32).unwrap().to_string());
-    assert_eq!("0.12345", from_f32(0.12345f32).unwrap().to_string());
-    assert_eq!(
-        "0.12345678",
-        from_f32(0.1234567800123456789012345678f32).unwrap().to_string()
-    );
-    assert_eq!(
-        "0.12345679",
-        from_f32(0.12345678901234567890123456789f32).unwrap().to_string()
-    );
-    assert_eq!("0", from_f32(0.00000000000000000000000000001f32).unwrap().to_string());
+    let k1 = Decimal::from_str("1").unwrap();
+    let k2 = Decimal::from_str("1.0").unwrap();
+    let k3 = Decimal::from_str("1.00").unwrap();
+    let k4 = Decimal::from_str("1.01").unwrap();
 
-    assert!(from_f32(f32::NAN).is_none());
-    assert!(from_f32(f32::INFINITY).is_none());
+    assert_eq!(k1, k2);
+    assert_eq!(k1, k3);
+    assert_ne!(k1, k4);
 
-    // These both overflow
-    assert!(from_f32(f32::MAX).is_none());
-    assert!(from_f32(f32::MIN).is_none());
-}
+    let h1 = hash_it(k1);
+    let h2 = hash_it(k2);
+    let h3 = hash_it(k3);
+    let h4 = hash_it(k4);
 
-#[test]
-fn it_converts_from_f32_try() {
-    assert_eq!("1", Decimal::try_from(1f32).unwrap().to_string());
-    assert_eq!("0", Decimal::try_from(0f32).unwrap().to_string());
-    assert_eq!("0.12345", Decimal::try_from(0.12345f32).unwrap().to_string());
-    assert_eq!(
-        "0.12345678",
-        Decimal::try_from(0.1234567800123456789012345678f32)
-            .unwrap()
-            .to_string()
-    );
-    assert_eq!(
-        "0.12345679",
-        Decimal::try_from(0.12345678901234567890123456789f32)
-            .unwrap()
-            .to_string()
-    );
-    assert_eq!(
-        "0",
-        Decimal::try_from(0.00000000000000000000000000001f32)
-            .unwrap()
-            .to_string()
-    );
+    assert_eq!(h1, h2);
+    assert_eq!(h1, h3);
+    assert_ne!(h1, h4);
 
-    assert!(Decimal::try_from(f32::NAN).is_err());
-    assert!(Decimal::try_from(f32::INFINITY).is_err());
+    // Test the application of Hash calculation to a HashMap.
 
-    // These both overflow
-    assert!(Decimal::try_from(f32::MAX).is_err());
-    assert!(Decimal::try_from(f32::MIN).is_err());
-}
+    let mut map = std::collections::HashMap::new();
 
-#[test]
-fn it_converts_from_f64() {
-    fn from_f64(f: f64) -> Option<Decimal> {
-        num_traits::FromPrimitive::from_f64(f)
-    }
+    map.insert(k1, k1.to_string());
+    // map[k2] should overwrite map[k1] because k1 == k2.
+    map.insert(k2, k2.to_string());
 
-    assert_eq!("1", from_f64(1f64).unwrap().to_string());
-    assert_eq!("0", from_f64(0f64).unwrap().to_string());
-    assert_eq!("0.12345", from_f64(0.12345f64).unwrap().to_string());
-    assert_eq!(
-        "0.1234567890123456",
-        from_f64(0.1234567890123456089012345678f64).unwrap().to_string()
-    );
-    assert_eq!(
-        "0.1234567890123457",
-        from_f64(0.12345678901234567890123456789f64).unwrap().to_string()
-    );
-    assert_eq!("0", from_f64(0.00000000000000000000000000001f64).unwrap().to_string());
-    assert_eq!("0.6927", from_f64(0.6927f64).unwrap().to_string());
-    assert_eq!("0.00006927", from_f64(0.00006927f64).unwrap().to_string());
-    assert_eq!("0.000000006927", from_f64(0.000000006927f64).unwrap().to_string());
+    assert_eq!("1.0", map.get(&k3).expect("could not get k3"));
+    assert_eq!(1, map.len());
 
-    assert!(from_f64(f64::NAN).is_none());
-    assert!(from_f64(f64::INFINITY).is_none());
+    // map[k3] should overwrite map[k2] because k3 == k2.
+    map.insert(k3, k3.to_string());
+    // map[k4] should not overwrite map[k3] because k4 != k3.
+    map.insert(k4, k4.to_string());
 
-    // These both overflow
-    assert!(from_f64(f64::MAX).is_none());
-    assert!(from_f64(f64::MIN).is_none());
+    assert_eq!(2, map.len());
+    assert_eq!("1.00", map.get(&k1).expect("could not get k1"));
 }
 
 #[test]
-fn it_converts_from_f64_try() {
-    assert_eq!("1", Decimal::try_from(1f64).unwrap().to_string());
-    assert_eq!("0", Decimal::try_from(0f64).unwrap().to_string());
-    assert_eq!("0.12345", Decimal::try_from(0.12345f64).unwrap().to_string());
-    assert_eq!(
-        "0.1234567890123456",
-        Decimal::try_from(0.1234567890123456089012345678f64)
-            .unwrap()
-            .to_string()
-    );
-    assert_eq!(
-        "0.1234567890123457",
-        Decimal::try_from(0.12345678901234567890123456789f64)
-            .unwrap()
-            .to_string()
-    );
-    assert_eq!(
-        "0",
-        Decimal::try_from(0.00000000000000000000000000001f64)
-            .unwrap()
-            .to_string()
-    );
-    assert_eq!("0.6927", Decimal::try_from(0.6927f64).unwrap().to_string());
-    assert_eq!("0.00006927", Decimal::try_from(0.00006927f64).unwrap().to_string());
-    assert_eq!(
-        "0.000000006927",
-        Decimal::try_from(0.000000006927f64).unwrap().to_string()
-    );
+fn it_computes_equal_hashes_for_positive_and_negative_zero() {
+    // Verify 0 and -0 have the same hash
+    let k1 = Decimal::from_str("0").unwrap();
+    let k2 = Decimal::from_str("-0").unwrap();
+    assert_eq!("-0", k2.to_string());
+    assert_eq!(k1, k2);
+    let h1 = hash_it(k1);
+    let h2 = hash_it(k2);
+    assert_eq!(h1, h2);
 
-    assert!(Decimal::try_from(f64::NAN).is_err());
-    assert!(Decimal::try_from(f64::INFINITY).is_err());
+    // Verify 0 and -0.0 have the same hash
+    let k1 = Decimal::from_str("0").unwrap();
+    let k2 = Decimal::from_str("-0.0").unwrap();
+    assert_eq!("-0.0", k2.to_string());
+    assert_eq!(k1, k2);
+    let h1 = hash_it(k1);
+    let h2 = hash_it(k2);
+    assert_eq!(h1, h2);
+}
 
-    // These both overflow
-    assert!(Decimal::try_from(f64::MAX).is_err());
-    assert!(Decimal::try_from(f64::MIN).is_err());
+#[test]
+#[should_panic]
+fn it_handles_i128_min() {
+    Decimal::from_i128_with_scale(i128::MIN, 0);
 }
 
 #[test]
-fn it_handles_simple_underflow() {
-    // Issue #71
-    let rate = Decimal::new(19, 2); // 0.19
-    let one = Decimal::new(1, 0); // 1
-    let part = rate / (rate + one); // 0.19 / (0.19 + 1) = 0.1596638655462184873949579832
-    let result = one * part;
-    assert_eq!("0.1596638655462184873949579832", result.to_string());
+fn it_can_rescale() {
+    let tests = &[
+        ("0", 6, "0.000000"),
+        ("0.000000", 2, "0.00"),
+        ("0.12345600000", 6, "0.123456"),
+        ("0.123456", 12, "0.123456000000"),
+        ("0.123456", 0, "0"),
+        ("0.000001", 4, "0.0000"),
+        ("1233456", 4, "1233456.0000"),
+        ("1.2", 30, "1.2000000000000000000000000000"),
+        ("79228162514264337593543950335", 0, "79228162514264337593543950335"),
+        ("4951760157141521099596496895", 1, "4951760157141521099596496895.0"),
+        ("4951760157141521099596496896", 1, "4951760157141521099596496896.0"),
+        ("18446744073709551615", 6, "18446744073709551615.000000"),
+        ("-18446744073709551615", 6, "-18446744073709551615.000000"),
+    ];
+
+    for &(value_raw, new_scale, expected_value) in tests {
+        let new_value = Decimal::from_str(expected_value).unwrap();
+        let mut value = Decimal::from_str(value_raw).unwrap();
+        value.rescale(new_scale);
+        assert_eq!(new_value.to_string(), value.to_string());
+    }
+}
+
+// Mathematical features
+#[cfg(feature = "maths")]
+mod maths {
+    use super::*;
+    use rust_decimal::MathematicalOps;
+
+    use num_traits::One;
+
+    #[test]
+    fn test_powu() {
+        let test_cases = &[
+            (Decimal::new(4, 0), 3_u64, Decimal::new(64, 0)),
+            (
+                Decimal::from_str("3.222").unwrap(),
+                5_u64,
+                Decimal::from_str("347.238347228449632").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.1").unwrap(),
+                0_u64,
+                Decimal::from_str("1").unwrap(),
+            ),
+            (
+                Decimal::from_str("342.4").unwrap(),
+                1_u64,
+                Decimal::from_str("342.4").unwrap(),
+            ),
+            (
+                Decimal::from_str("2.0").unwrap(),
+                16_u64,
+                Decimal::from_str("65536").unwrap(),
+            ),
+        ];
+        for case in test_cases {
+            assert_eq!(case.2, case.0.powu(case.1));
+        }
+    }
+
+    #[test]
+    fn test_pow_trait() {
+        use num_traits::Pow;
 
-    // 169 * 0.1596638655462184873949579832 = 26.983193277310924
-    let result = part * Decimal::new(169, 0);
-    assert_eq!("26.983193277310924369747899161", result.to_string());
-    let result = Decimal::new(169, 0) * part;
-    assert_eq!("26.983193277310924369747899161", result.to_string());
-}
+        let two = Decimal::new(2, 0);
+        assert_eq!(Decimal::new(1024, 0), two.pow(10_u64));
+        assert_eq!(Decimal::new(-27, 0), (&Decimal::new(-3, 0)).pow(3_u64));
+        assert_eq!(Decimal::new(25, 2), two.pow(-2_i64));
+        assert_eq!(Decimal::new(10, 0), (&Decimal::new(100, 0)).pow(Decimal::new(5, 1)));
+        assert_eq!(Decimal::new(10, 0), Decimal::new(100, 0).pow(0.5_f64));
+    }
 
-#[test]
-fn it_can_parse_highly_significant_numbers() {
-    let tests = &[
-        ("11.111111111111111111111111111", "11.111111111111111111111111111"),
-        ("11.11111111111111111111111111111", "11.111111111111111111111111111"),
-        ("11.1111111111111111111111111115", "11.111111111111111111111111112"),
-        ("115.111111111111111111111111111", "115.11111111111111111111111111"),
-        ("1115.11111111111111111111111111", "1115.1111111111111111111111111"),
-        ("11.1111111111111111111111111195", "11.111111111111111111111111120"),
-        ("99.9999999999999999999999999995", "100.00000000000000000000000000"),
-        ("-11.1111111111111111111111111195", "-11.111111111111111111111111120"),
-        ("-99.9999999999999999999999999995", "-100.00000000000000000000000000"),
-        ("3.1415926535897932384626433832", "3.1415926535897932384626433832"),
-        (
-            "8808257419827262908.5944405087133154018",
-            "8808257419827262908.594440509",
-        ),
-        (
-            "8097370036018690744.2590371109596744091",
-            "8097370036018690744.259037111",
-        ),
-        (
-            "8097370036018690744.2590371149596744091",
-            "8097370036018690744.259037115",
-        ),
-        (
-            "8097370036018690744.2590371159596744091",
-            "8097370036018690744.259037116",
-        ),
-        ("1.234567890123456789012345678949999", "1.2345678901234567890123456789"),
-    ];
-    for &(value, expected) in tests {
-        assert_eq!(expected, Decimal::from_str(value).unwrap().to_string());
+    #[test]
+    #[should_panic]
+    fn test_powu_panic() {
+        let two = Decimal::new(2, 0);
+        let _ = two.powu(128);
     }
-}
 
-#[test]
-fn it_can_parse_alternative_formats() {
-    let tests = &[
-        ("1_000", "1000"),
-        ("1_000_000", "1000000"),
-        ("10_000_000", "10000000"),
-        ("100_000", "100000"),
-        // At the moment, we'll accept this
-        ("1_____________0", "10"),
-    ];
-    for &(value, expected) in tests {
-        assert_eq!(expected, Decimal::from_str(value).unwrap().to_string());
+    #[test]
+    fn test_checked_powu() {
+        let test_cases = &[
+            (Decimal::new(4, 0), 3_u64, Some(Decimal::new(64, 0))),
+            (
+                Decimal::from_str("3.222").unwrap(),
+                5_u64,
+                Some(Decimal::from_str("347.238347228449632").unwrap()),
+            ),
+            (
+                Decimal::from_str("0.1").unwrap(),
+                0_u64,
+                Some(Decimal::from_str("1").unwrap()),
+            ),
+            (
+                Decimal::from_str("342.4").unwrap(),
+                1_u64,
+                Some(Decimal::from_str("342.4").unwrap()),
+            ),
+            (
+                Decimal::from_str("2.0").unwrap(),
+                16_u64,
+                Some(Decimal::from_str("65536").unwrap()),
+            ),
+            (Decimal::from_str("2.0").unwrap(), 128_u64, None),
+            (
+                Decimal::from_str("1.0000000000000000000000000001").unwrap(),
+                10_000_000_000_000_000_000_u64,
+                Some(Decimal::from_str("1.0000000010000000005000000002").unwrap()),
+            ),
+            (Decimal::ONE, u64::MAX, Some(Decimal::ONE)),
+        ];
+        for case in test_cases {
+            assert_eq!(case.2, case.0.checked_powu(case.1));
+        }
     }
-}
 
-#[test]
-fn it_can_parse_fractional_numbers_with_underscore_separators() {
-    let a = Decimal::from_str("0.1_23_456").unwrap();
-    assert_eq!(a.is_sign_negative(), false);
-    assert_eq!(a.scale(), 6);
-    assert_eq!("0.123456", a.to_string());
-}
+    #[test]
+    fn test_saturating_powu() {
+        let test_cases = &[
+            ("4", 3_u64, "64"),
+            ("2", 16_u64, "65536"),
+            ("2", 128_u64, "79228162514264337593543950335"),
+            ("-2", 128_u64, "79228162514264337593543950335"),
+            ("-2", 129_u64, "-79228162514264337593543950335"),
+        ];
+        for &(base, exp, expected) in test_cases {
+            let base = Decimal::from_str(base).unwrap();
+            assert_eq!(expected, base.saturating_powu(exp).to_string(), "{}^{}", base, exp);
+        }
+    }
 
-#[test]
-fn it_can_parse_numbers_with_underscore_separators_before_decimal_point() {
-    let a = Decimal::from_str("1_234.56").unwrap();
-    assert_eq!(a.is_sign_negative(), false);
-    assert_eq!(a.scale(), 2);
-    assert_eq!("1234.56", a.to_string());
-}
+    #[test]
+    fn test_powi() {
+        let test_cases = &[
+            ("4", 3_i64, Some("64")),
+            ("2", -2_i64, Some("0.25")),
+            ("-2", -3_i64, Some("-0.125")),
+            ("0.5", -3_i64, Some("8")),
+            ("10", -28_i64, Some("0.0000000000000000000000000001")),
+            ("2", -200_i64, Some("0")),
+            ("2", 128_i64, None),
+            ("0", -1_i64, None),
+            ("0", 0_i64, Some("1")),
+            // The reciprocal is only rounded once, after calculating the power with guard digits
+            ("0.9", -600_i64, Some("2847700663325686066049598033.2")),
+            ("-1.5", -151_i64, Some("-0.0000000000000000000000000026")),
+            ("1.01", 1000_i64, Some("20959.155637813660064441245789")),
+            // Bases close to one keep their precision with very large exponents
+            (
+                "1.0000000000000000000000000001",
+                5_000_000_000_000_000_000_i64,
+                Some("1.0000000005000000001250000000"),
+            ),
+            (
+                "0.9999999999999999999999999999",
+                i64::MIN,
+                Some("1.0000000009223372041108305396"),
+            ),
+            ("1", i64::MAX, Some("1")),
+            ("-1", i64::MAX, Some("-1")),
+            ("1.0000001", i64::MAX, None),
+            ("0.5", i64::MAX, Some("0")),
+        ];
+        for &(base, exp, expected) in test_cases {
+            let base = Decimal::from_str(base).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, base.checked_powi(exp), "{}^{}", base, exp);
+            if let Some(expected) = expected {
+                assert_eq!(expected, base.powi(exp), "{}^{}", base, exp);
+            }
+        }
+    }
 
-#[test]
-fn it_can_parse_numbers_and_round_correctly_with_underscore_separators_before_decimal_point() {
-    let tests = &[
-        (
-            "8_097_370_036_018_690_744.2590371159596744091",
-            "8097370036018690744.259037116",
-        ),
-        (
-            "8097370036018690744.259_037_115_959_674_409_1",
-            "8097370036018690744.259037116",
-        ),
-        (
-            "8_097_370_036_018_690_744.259_037_115_959_674_409_1",
-            "8097370036018690744.259037116",
-        ),
-    ];
-    for &(value, expected) in tests {
-        assert_eq!(expected, Decimal::from_str(value).unwrap().to_string());
+    #[test]
+    fn test_saturating_powi() {
+        let test_cases = &[
+            ("2", -2_i64, "0.25"),
+            ("2", -200_i64, "0"),
+            ("0", -1_i64, "79228162514264337593543950335"),
+            ("2", 128_i64, "79228162514264337593543950335"),
+            ("-2", 129_i64, "-79228162514264337593543950335"),
+            ("-0.0001", -11_i64, "-79228162514264337593543950335"),
+            ("-0.0001", -12_i64, "79228162514264337593543950335"),
+        ];
+        for &(base, exp, expected) in test_cases {
+            let base = Decimal::from_str(base).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, base.saturating_powi(exp), "{}^{}", base, exp);
+        }
     }
-}
 
-#[test]
-fn it_can_reject_invalid_formats() {
-    let tests = &["_1", "1.0.0", "10_00.0_00.0"];
-    for &value in tests {
-        assert!(
-            Decimal::from_str(value).is_err(),
-            "This succeeded unexpectedly: {}",
-            value
+    #[test]
+    fn test_powd() {
+        let test_cases = &[
+            ("1.05", "0.5", Some("1.0246950765959598383221038681")),
+            ("2", "0.5", Some("1.4142135623730950488016887242")),
+            ("100", "0.5", Some("10")),
+            ("1.05", "-2.5", Some("0.8851701341936808883032967222")),
+            ("1.0001", "365", Some("1.0371724113025519299020280171")),
+            ("1.01", "1000", Some("20959.155637813660064441245789")),
+            ("0.9", "-600", Some("2847700663325686066049598033.2")),
+            ("1", "10000000000", Some("1")),
+            ("0.9999999999", "10000000000", Some("0.3678794411530483495361852386")),
+            ("9", "-0.5", Some("0.3333333333333333333333333333")),
+            ("7.5", "3.25", Some("698.15058461301229559296982678")),
+            ("10", "-3", Some("0.001")),
+            ("2", "10", Some("1024")),
+            ("-2", "3", Some("-8")),
+            ("0", "0.5", Some("0")),
+            ("0", "0", Some("1")),
+            ("-2", "0.5", None),
+            ("0", "-1", None),
+            ("2", "96", None),
+            ("-8", "1000000000000000000001", None),
+        ];
+        for &(base, exp, expected) in test_cases {
+            let base = Decimal::from_str(base).unwrap();
+            let exp = Decimal::from_str(exp).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, base.checked_powd(exp), "{}^{}", base, exp);
+            if let Some(expected) = expected {
+                assert_eq!(expected, base.powd(exp), "{}^{}", base, exp);
+            }
+        }
+
+        // Exact powers have the guard digits removed, in the same way as the roots
+        let test_cases = &[
+            ("4", "0.5", "2"),
+            ("100", "0.5", "10"),
+            ("16", "0.75", "8"),
+            ("0.25", "-0.5", "2"),
+            (
+                "27",
+                "-0.6666666666666666666666666667",
+                "0.1111111111111111111111111111",
+            ),
+            ("2", "0.5", "1.4142135623730950488016887242"),
+        ];
+        for &(base, exp, expected) in test_cases {
+            let base = Decimal::from_str(base).unwrap();
+            let exp = Decimal::from_str(exp).unwrap();
+            assert_eq!(expected, base.powd(exp).to_string(), "{}^{}", base, exp);
+        }
+        assert_eq!(
+            Decimal::from(4).sqrt().unwrap().to_string(),
+            Decimal::from(4).powd(Decimal::new(5, 1)).to_string()
         );
     }
-}
 
-#[test]
-fn it_can_reject_large_numbers_with_panic() {
-    let tests = &[
-        // The maximum number supported is 79,228,162,514,264,337,593,543,950,335
-        "79228162514264337593543950336",
-        "79228162514264337593543950337",
-        "79228162514264337593543950338",
-        "79228162514264337593543950339",
-        "79228162514264337593543950340",
-    ];
-    for &value in tests {
-        assert!(
-            Decimal::from_str(value).is_err(),
-            "This succeeded unexpectedly: {}",
-            value
-        );
+    #[test]
+    #[should_panic(expected = "Pow overflowed")]
+    fn test_powd_panic() {
+        let _ = Decimal::new(-2, 0).powd(Decimal::new(5, 1));
     }
-}
 
-#[test]
-fn it_can_parse_individual_parts() {
-    let pi = Decimal::from_parts(1102470952, 185874565, 1703060790, false, 28);
-    assert_eq!(pi.to_string(), "3.1415926535897932384626433832");
-}
+    #[test]
+    fn test_powd_with_precision() {
+        let test_cases = &[
+            ("2", "0.5", 10, "1.4142135624"),
+            ("4", "0.5", 2, "2"),
+            ("1.5", "3", 2, "3.38"),
+            ("3", "-1", 5, "0.33333"),
+            ("2", "-3", 2, "0.12"),
+            ("2.5", "1.5", 4, "3.9528"),
+        ];
+        for &(x, exp, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let exp = Decimal::from_str(exp).unwrap();
+            let result = x.powd_with_precision(exp, dp);
+            assert_eq!(expected, result.to_string(), "{}.powd({}) to {} dp", x, exp, dp);
+        }
+    }
 
-#[test]
-fn it_can_parse_scientific_notation() {
-    let tests = &[
-        ("9.7e-7", "0.00000097"),
-        ("9e-7", "0.0000009"),
-        ("1.2e10", "12000000000"),
-        ("1.2e+10", "12000000000"),
-        ("12e10", "120000000000"),
-        ("9.7E-7", "0.00000097"),
-    ];
+    #[test]
+    #[should_panic(expected = "Pow overflowed")]
+    fn test_powd_with_precision_panic() {
+        let _ = Decimal::from(10).powd_with_precision(Decimal::from(30), 2);
+    }
 
-    for &(value, expected) in tests {
-        assert_eq!(expected, Decimal::from_scientific(value).unwrap().to_string());
+    #[test]
+    fn test_powf() {
+        let test_cases = &[
+            ("2", 0.5_f64, Some("1.4142135623730950488016887242")),
+            ("4", 2_f64, Some("16")),
+            ("100", 0.5_f64, Some("10")),
+            ("-2", 0.5_f64, None),
+            ("2", f64::NAN, None),
+            ("2", f64::INFINITY, None),
+        ];
+        for &(base, exp, expected) in test_cases {
+            let base = Decimal::from_str(base).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, base.checked_powf(exp), "{}^{}", base, exp);
+            if let Some(expected) = expected {
+                assert_eq!(expected, base.powf(exp), "{}^{}", base, exp);
+            }
+        }
     }
-}
 
-#[test]
-fn it_can_parse_different_radix() {
-    let tests = &[
-        // Input, Radix, Success, to_string()
-        ("123", 10, true, "123"),
-        ("123", 8, true, "83"),
-        ("123", 16, true, "291"),
-        ("abc", 10, false, ""),
-        ("abc", 16, true, "2748"),
-        ("78", 10, true, "78"),
-        ("78", 8, false, ""),
-        ("101", 2, true, "5"),
-        // Parse base 2
-        ("1111_1111_1111_1111_1111_1111_1111_1111", 2, true, "4294967295"),
-        // Max supported value
-        (
-            "1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_\
-          1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111",
-            2,
-            true,
-            &Decimal::max_value().to_string(),
-        ),
-        // We limit to 28 dp
-        (
-            "843.6500000000000000000000000000",
-            10,
-            true,
-            "843.6500000000000000000000000",
-        ),
-    ];
+    #[test]
+    fn test_sqrt() {
+        let test_cases = &[
+            ("4", "2"),
+            ("3.222", "1.7949930361981909371487724124"),
+            ("199.45", "14.122676800097069416754994263"),
+            ("342.4", "18.504053609952604112132102541"),
+            ("2", "1.414213562373095048801688724209698078569671875376948073176"),
+            ("0.0000000000000000000000000001", "0.0000000000000100000000000000"),
+        ];
+        for case in test_cases {
+            let a = Decimal::from_str(case.0).unwrap();
+            let expected = Decimal::from_str(case.1).unwrap();
+            assert_eq!(expected, a.sqrt().unwrap());
+        }
+
+        assert_eq!(Decimal::new(-2, 0).sqrt(), None);
+    }
 
-    for &(input, radix, success, expected) in tests {
-        let result = Decimal::from_str_radix(input, radix);
-        assert_eq!(
-            success,
-            result.is_ok(),
-            "Failed to parse: {} radix {}: {:?}",
-            input,
-            radix,
-            result.err()
-        );
-        if result.is_ok() {
+    #[test]
+    fn test_sqrt_with_precision() {
+        let test_cases = &[
+            ("2", 2, Some("1.41")),
+            ("2", 28, Some("1.4142135623730950488016887242")),
+            ("0.0002", 28, Some("0.0141421356237309504880168872")),
+            ("1000000", 3, Some("1000")),
+            ("-2", 2, None),
+        ];
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, x.sqrt_with_precision(dp), "sqrt({}) to {} dp", x, dp);
+        }
+    }
+
+    #[test]
+    fn test_cbrt() {
+        let test_cases = &[
+            ("0", "0"),
+            ("27", "3"),
+            ("-8", "-2"),
+            ("0.001", "0.1"),
+            ("0.000000000000000000000000001", "0.000000001"),
+            ("2", "1.2599210498948731647672106073"),
+            ("-2", "-1.2599210498948731647672106073"),
+            ("10", "2.1544346900318837217592935665"),
+            ("0.5", "0.7937005259840997373758528196"),
+            ("79228162514264337593543950335", "4294967296.0000000000000000000"),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(expected, x.cbrt().to_string(), "cbrt({})", x);
+        }
+    }
+
+    #[test]
+    fn test_nth_root() {
+        let test_cases = &[
+            ("5", 1, Some("5")),
+            ("2", 2, Some("1.4142135623730950488016887242")),
+            ("2", 5, Some("1.1486983549970350067986269468")),
+            ("-7", 7, Some("-1.3204692477561237918093273315")),
+            ("3", 4, Some("1.3160740129524924608192189018")),
+            ("1.5", 12, Some("1.0343660831319165749697725532")),
+            ("1.21", 365, Some("1.0005223839555718565741095941")),
+            ("2", 100, Some("1.0069555500567188088326982141")),
+            ("1024", 10, Some("2")),
+            ("-0.00032", 5, Some("-0.2")),
+            ("0.0001", 4, Some("0.1")),
+            ("1", 4000000000, Some("1")),
+            ("0", 6, Some("0")),
+            ("-16", 4, None),
+            ("2", 0, None),
+        ];
+        for &(x, n, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
             assert_eq!(
-                expected,
-                result.unwrap().to_string(),
-                "Original input: {} radix {}",
-                input,
-                radix
+                expected.map(String::from),
+                x.checked_nth_root(n).map(|d| d.to_string()),
+                "nth_root({}, {})",
+                x,
+                n
             );
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.nth_root(n).to_string(), "nth_root({}, {})", x, n);
+            }
         }
     }
-}
 
-#[test]
-fn it_can_calculate_signum() {
-    let tests = &[("123", 1), ("-123", -1), ("0", 0)];
+    #[test]
+    #[should_panic(expected = "Even roots are only defined for non-negative values")]
+    fn test_nth_root_even_panic() {
+        let _ = Decimal::new(-16, 0).nth_root(4);
+    }
 
-    for &(input, expected) in tests {
-        let input = Decimal::from_str(input).unwrap();
-        assert_eq!(expected, input.signum().to_i32().unwrap(), "Input: {}", input);
+    #[test]
+    #[should_panic(expected = "The zeroth root is undefined")]
+    fn test_nth_root_zeroth_panic() {
+        let _ = Decimal::new(2, 0).nth_root(0);
     }
-}
 
-#[test]
-fn it_can_calculate_abs_sub() {
-    let tests = &[
-        ("123", "124", 0),
-        ("123", "123", 0),
-        ("123", "122", 123),
-        ("-123", "-124", 123),
-        ("-123", "-123", 0),
-        ("-123", "-122", 0),
-    ];
+    #[test]
+    fn test_nth_root_with_precision() {
+        let test_cases = &[
+            ("2", 3, 10, "1.2599210499"),
+            ("-2", 3, 4, "-1.2599"),
+            ("10", 5, 6, "1.584893"),
+            ("27", 3, 5, "3"),
+            ("2", 2, 3, "1.414"),
+            ("2.5", 1, 0, "2"),
+        ];
+        for &(x, n, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let result = x.nth_root_with_precision(n, dp);
+            assert_eq!(expected, result.to_string(), "{}.nth_root({}) to {} dp", x, n, dp);
+        }
+    }
 
-    for &(input1, input2, expected) in tests {
-        let input1 = Decimal::from_str(input1).unwrap();
-        let input2 = Decimal::from_str(input2).unwrap();
-        assert_eq!(
-            expected,
-            input1.abs_sub(&input2).to_i32().unwrap(),
-            "Input: {} {}",
-            input1,
-            input2
-        );
+    #[test]
+    #[should_panic(expected = "Even roots are only defined for non-negative values")]
+    fn test_nth_root_with_precision_panic() {
+        let _ = Decimal::from(-16).nth_root_with_precision(4, 2);
     }
-}
 
-#[test]
-#[should_panic]
-fn it_panics_when_scale_too_large() {
-    let _ = Decimal::new(1, 29);
-}
+    #[test]
+    fn test_exp() {
+        let test_cases = &[
+            ("10", "22026.465794806716516957900645"),
+            ("11", "59874.141715197818455326485792"),
+            ("3", "20.085536923187667740928529655"),
+            ("8", "2980.9579870417282747435920995"),
+            ("0.1", "1.1051709180756476248117078265"),
+            ("2.0", "7.3890560989306502272304274606"),
+            ("-2", "0.1353352832366126918939994950"),
+            ("-1", "0.3678794411714423215955237702"),
+        ];
+        for case in test_cases {
+            let x = Decimal::from_str(case.0).unwrap();
+            let expected = Decimal::from_str(case.1).unwrap();
+            assert_eq!(expected, x.exp());
+        }
+    }
 
-#[test]
-fn test_zero_eq_negative_zero() {
-    let zero: Decimal = 0.into();
+    #[test]
+    fn test_exp_with_tolerance() {
+        let test_cases = &[
+            (
+                Decimal::new(10, 0),
+                Decimal::new(3, 2),
+                Decimal::from_str("22023.81992829").unwrap(),
+            ),
+            (
+                Decimal::new(11, 0),
+                Decimal::new(2, 4),
+                Decimal::from_str("59846.36875797").unwrap(),
+            ),
+            (
+                Decimal::new(3, 0),
+                Decimal::new(2, 5),
+                Decimal::from_str("20.08553442").unwrap(),
+            ),
+            (
+                Decimal::from_str("8").unwrap(),
+                Decimal::new(2, 4),
+                Decimal::from_str("2980.94688158").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.1").unwrap(),
+                Decimal::new(2, 4),
+                Decimal::from_str("1.10516667").unwrap(),
+            ),
+            (
+                Decimal::from_str("2.0").unwrap(),
+                Decimal::new(2, 4),
+                Decimal::from_str("7.38904603").unwrap(),
+            ),
+        ];
+        for case in test_cases {
+            assert_eq!(case.2, case.0.exp_with_tolerance(case.1));
+        }
+    }
 
-    assert_eq!(zero, zero);
-    assert_eq!(-zero, zero);
-    assert_eq!(zero, -zero);
-}
+    #[test]
+    fn test_checked_exp() {
+        let test_cases = &[
+            ("0", Some("1")),
+            ("1", Some("2.7182818284590452353602874714")),
+            ("-100", Some("0")),
+            ("66.5", Some("75959666021073336334634473276")),
+            ("66.6", None),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, x.checked_exp(), "exp({})", x);
+        }
+    }
 
-#[test]
-fn declarative_dec_sum() {
-    let vs = (0..10).map(|i| i.into()).collect::<Vec<Decimal>>();
-    let sum: Decimal = vs.iter().cloned().sum();
+    #[test]
+    #[should_panic(expected = "Exponent overflowed")]
+    fn test_exp_panic() {
+        let _ = Decimal::new(70, 0).exp();
+    }
 
-    assert_eq!(sum, Decimal::from(45))
-}
+    #[test]
+    fn test_exp_with_precision() {
+        let test_cases = &[
+            ("1", 0, "3"),
+            ("1", 10, "2.7182818285"),
+            ("-1", 28, "0.3678794411714423215955237702"),
+            ("0.5", 3, "1.649"),
+            ("-30", 10, "0"),
+            // Too large for 10 decimal places, so as few as possible are discarded
+            ("60", 10, "114200738981568428366295718.31"),
+        ];
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, x.exp_with_precision(dp), "exp({}) to {} dp", x, dp);
+        }
+    }
 
-#[test]
-fn declarative_ref_dec_sum() {
-    let vs = (0..10).map(|i| i.into()).collect::<Vec<Decimal>>();
-    let sum: Decimal = vs.iter().sum();
+    #[test]
+    fn test_norm_cdf() {
+        let test_cases = &[
+            (
+                Decimal::from_str("-0.4").unwrap(),
+                Decimal::from_str("0.3445782583896758332631193240").unwrap(),
+            ),
+            (
+                Decimal::from_str("-0.1").unwrap(),
+                Decimal::from_str("0.4601721627229710185345953818").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.1").unwrap(),
+                Decimal::from_str("0.5398278372770289814654046182").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.4").unwrap(),
+                Decimal::from_str("0.6554217416103241667368806760").unwrap(),
+            ),
+            (
+                Decimal::from_str("2.0").unwrap(),
+                Decimal::from_str("0.9772498680518207927997173628").unwrap(),
+            ),
+        ];
+        for case in test_cases {
+            assert_eq!(case.1, case.0.norm_cdf());
+        }
+    }
 
-    assert_eq!(sum, Decimal::from(45))
-}
+    #[test]
+    fn test_norm_pdf() {
+        let test_cases = &[
+            (
+                Decimal::from_str("-2.0").unwrap(),
+                Decimal::from_str("0.0539909665131880519505642004").unwrap(),
+            ),
+            (
+                Decimal::from_str("-0.4").unwrap(),
+                Decimal::from_str("0.3682701403033233077441296229").unwrap(),
+            ),
+            (
+                Decimal::from_str("-0.1").unwrap(),
+                Decimal::from_str("0.3969525474770117655105297435").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.1").unwrap(),
+                Decimal::from_str("0.3969525474770117655105297435").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.4").unwrap(),
+                Decimal::from_str("0.3682701403033233077441296229").unwrap(),
+            ),
+            (
+                Decimal::from_str("2.0").unwrap(),
+                Decimal::from_str("0.0539909665131880519505642004").unwrap(),
+            ),
+        ];
+        for case in test_cases {
+            assert_eq!(case.1, case.0.norm_pdf());
+        }
+    }
 
-#[cfg(feature = "postgres")]
-#[test]
-fn to_from_sql() {
-    use bytes::BytesMut;
-    use postgres::types::{FromSql, Kind, ToSql, Type};
+    #[test]
+    fn test_norm_cdf_with_precision() {
+        let test_cases = &[
+            ("1.96", 4, "0.9750"),
+            ("-1.96", 28, "0.0249978951482204341365842690"),
+            ("-8", 28, "0.0000000000000006220960574272"),
+            ("3", 10, "0.9986501020"),
+            ("40", 28, "1"),
+            ("-40", 28, "0"),
+        ];
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, x.norm_cdf_with_precision(dp), "norm_cdf({}) to {} dp", x, dp);
+        }
+    }
 
-    let tests = &[
-        "3950.123456",
-        "3950",
-        "0.1",
-        "0.01",
-        "0.001",
-        "0.0001",
-        "0.00001",
-        "0.000001",
-        "1",
-        "-100",
-        "-123.456",
-        "119996.25",
-        "1000000",
-        "9999999.99999",
-        "12340.56789",
-        "79228162514264337593543950335", // 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF (96 bit)
-        "4951760157141521099596496895",  // 0x0FFF_FFFF_FFFF_FFFF_FFFF_FFFF (95 bit)
-        "4951760157141521099596496896",  // 0x1000_0000_0000_0000_0000_0000
-        "18446744073709551615",
-        "-18446744073709551615",
-    ];
+    #[test]
+    fn test_norm_pdf_with_precision() {
+        let test_cases = &[
+            ("0", 28, "0.3989422804014326779399460599"),
+            ("1", 6, "0.241971"),
+            ("-5", 20, "0.00000148671951473430"),
+            ("20", 28, "0"),
+        ];
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, x.norm_pdf_with_precision(dp), "norm_pdf({}) to {} dp", x, dp);
+        }
+    }
 
-    let t = Type::new("".into(), 0, Kind::Simple, "".into());
+    #[test]
+    fn test_erfc() {
+        let test_cases = &[
+            ("0", "1"),
+            ("0.5", "0.4795001221869534623172533461"),
+            ("-1", "1.8427007929497148693412206351"),
+            ("3", "0.0000220904969985854413727761"),
+            ("6", "0.0000000000000000215197367125"),
+            ("-10", "2"),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, x.erfc(), "erfc({})", x);
+        }
+    }
 
-    for test in tests {
-        let input = Decimal::from_str(test).unwrap();
-        let mut bytes = BytesMut::new();
-        input.to_sql(&t, &mut bytes).unwrap();
-        let output = Decimal::from_sql(&t, &bytes).unwrap();
+    #[test]
+    fn test_erf_inv() {
+        let test_cases = &[
+            ("0", Some("0")),
+            ("0.5", Some("0.4769362762044698733814183536")),
+            ("-0.9", Some("-1.1630871536766740867262542606")),
+            ("0.999999", Some("3.4589107372795000221509276360")),
+            ("0.9999999999999999999999999999", Some("7.8631987627881460746955094745")),
+            ("0.0000000000000000000000000001", Some("0.0000000000000000000000000001")),
+            (
+                "-0.0000000000000000000000000001",
+                Some("-0.0000000000000000000000000001"),
+            ),
+            ("0.00000000000000000001", Some("0.0000000000000000000088622693")),
+            ("0.0000000000000001", Some("0.0000000000000000886226925453")),
+            ("0.000000000000001", Some("0.0000000000000008862269254528")),
+            ("0.00000000000001", Some("0.0000000000000088622692545276")),
+            ("0.0001", Some("0.0000886226927772894691751246")),
+            ("0.00011", Some("0.0000974849621086135737133293")),
+            ("1", None),
+            ("-1.5", None),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, x.checked_erf_inv(), "erf_inv({})", x);
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.erf_inv(), "erf_inv({})", x);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Inverse error function is only defined for values between -1 and 1")]
+    fn test_erf_inv_panic() {
+        let _ = Decimal::new(-1, 0).erf_inv();
+    }
+
+    #[test]
+    fn test_norm_inv_cdf() {
+        let test_cases = &[
+            ("0.5", Some("0")),
+            ("0.975", Some("1.9599639845400542355245944305")),
+            ("0.025", Some("-1.9599639845400542355245944305")),
+            ("0.99", Some("2.3263478740408411008856061633")),
+            (
+                "0.0000000000000000000000000001",
+                Some("-11.058232414058736821298464079"),
+            ),
+            ("0.9999999999999999999999999999", Some("11.058232414058736821298464079")),
+            ("0.500000000000001", Some("0.0000000000000025066282746310")),
+            ("0.5000000000000001", Some("0.0000000000000002506628274631")),
+            ("0.5000000000000000000000000001", Some("0.0000000000000000000000000003")),
+            (
+                "0.4999999999999999999999999999",
+                Some("-0.0000000000000000000000000003"),
+            ),
+            ("0", None),
+            ("1", None),
+            ("-0.5", None),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, x.checked_norm_inv_cdf(), "norm_inv_cdf({})", x);
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.norm_inv_cdf(), "norm_inv_cdf({})", x);
+            }
+        }
+    }
 
-        assert_eq!(input, output);
+    #[test]
+    #[should_panic(expected = "Inverse normal distribution function is only defined for values between 0 and 1")]
+    fn test_norm_inv_cdf_panic() {
+        let _ = Decimal::ONE.norm_inv_cdf();
     }
-}
 
-fn hash_it(d: Decimal) -> u64 {
-    use core::hash::{Hash, Hasher};
-    use std::collections::hash_map::DefaultHasher;
+    #[test]
+    fn test_erfc_and_inverse_with_precision() {
+        let test_cases = &[("0.5", 10, "0.4795001222"), ("-1", 4, "1.8427")];
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(
+                expected,
+                x.erfc_with_precision(dp).to_string(),
+                "erfc({}) to {} dp",
+                x,
+                dp
+            );
+        }
+        let half = Decimal::new(5, 1);
+        assert_eq!("0.4769362762", half.erf_inv_with_precision(10).to_string());
+        let p = Decimal::new(975, 3);
+        assert_eq!("1.959964", p.norm_inv_cdf_with_precision(6).to_string());
+    }
 
-    let mut h = DefaultHasher::new();
-    d.hash(&mut h);
-    h.finish()
-}
+    #[test]
+    #[should_panic(expected = "Inverse error function is only defined for values between -1 and 1")]
+    fn test_erf_inv_with_precision_panic() {
+        let _ = Decimal::ONE.erf_inv_with_precision(4);
+    }
 
-#[test]
-fn it_computes_equal_hashes_for_equal_values() {
-    // From the Rust Hash docs:
-    //
-    // "When implementing both Hash and Eq, it is important that the following property holds:
-    //
-    //     k1 == k2 -> hash(k1) == hash(k2)"
+    #[test]
+    fn test_ln() {
+        let test_cases = &[
+            (Decimal::from_str("1").unwrap(), Decimal::from_str("0").unwrap()),
+            (
+                Decimal::from_str("0.23").unwrap(),
+                // Wolfram Alpha gives -1.46968
+                Decimal::from_str("-1.4696759700589416772292300776").unwrap(),
+            ),
+            (
+                Decimal::from_str("2").unwrap(),
+                // Wolfram Alpha gives 0.693147180559945309417232121458176568075500134360255254120
+                Decimal::from_str("0.6931471805599453094172321215").unwrap(),
+            ),
+            (
+                Decimal::from_str("25").unwrap(),
+                // Wolfram Alpha gives 3.218875824868200749201518666452375279051202708537035443825
+                Decimal::from_str("3.2188758248682007492015186665").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.0000000000000000000000000001").unwrap(),
+                Decimal::from_str("-64.472382603833279152503760731").unwrap(),
+            ),
+            (
+                Decimal::from_str("79228162514264337593543950335").unwrap(),
+                Decimal::from_str("66.542129333754749704054283660").unwrap(),
+            ),
+        ];
 
-    let k1 = Decimal::from_str("1").unwrap();
-    let k2 = Decimal::from_str("1.0").unwrap();
-    let k3 = Decimal::from_str("1.00").unwrap();
-    let k4 = Decimal::from_str("1.01").unwrap();
+        for case in test_cases {
+            assert_eq!(case.1, case.0.ln());
+            assert_eq!(Some(case.1), case.0.checked_ln());
+        }
 
-    assert_eq!(k1, k2);
-    assert_eq!(k1, k3);
-    assert_ne!(k1, k4);
+        assert_eq!(None, Decimal::ZERO.checked_ln());
+        assert_eq!(None, Decimal::from_str("-2.0").unwrap().checked_ln());
+    }
 
-    let h1 = hash_it(k1);
-    let h2 = hash_it(k2);
-    let h3 = hash_it(k3);
-    let h4 = hash_it(k4);
+    #[test]
+    #[should_panic(expected = "Logarithm is only defined for positive values")]
+    fn test_ln_panic() {
+        let _ = Decimal::from_str("-2.0").unwrap().ln();
+    }
 
-    assert_eq!(h1, h2);
-    assert_eq!(h1, h3);
-    assert_ne!(h1, h4);
+    #[test]
+    fn test_ln_with_precision() {
+        let test_cases = &[
+            ("2", 10, "0.6931471806"),
+            ("10", 28, "2.3025850929940456840179914547"),
+            ("0.5", 3, "-0.693"),
+            ("79228162514264337593543950335", 5, "66.54213"),
+        ];
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, x.ln_with_precision(dp), "ln({}) to {} dp", x, dp);
+        }
+    }
 
-    // Test the application of Hash calculation to a HashMap.
+    #[test]
+    fn test_log10() {
+        let test_cases = &[
+            ("1", Some("0")),
+            ("10", Some("1")),
+            ("100", Some("2")),
+            ("1000000000000000000000000000", Some("27")),
+            ("0.1", Some("-1")),
+            ("0.001", Some("-3")),
+            ("0.0000000000000000000000000001", Some("-28")),
+            ("2", Some("0.3010299956639811952137388947")),
+            ("0.23", Some("-0.6382721639824071211322228877")),
+            ("1024", Some("3.0102999566398119521373889472")),
+            ("0", None),
+            ("-10", None),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| e.to_string());
+            assert_eq!(expected, x.checked_log10().map(|d| d.to_string()), "log10({})", x);
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.log10().to_string(), "log10({})", x);
+            }
+        }
+    }
 
-    let mut map = std::collections::HashMap::new();
+    #[test]
+    fn test_log2() {
+        let test_cases = &[
+            ("1", Some("0")),
+            ("2", Some("1")),
+            ("1024", Some("10")),
+            ("0.5", Some("-1")),
+            ("0.0009765625", Some("-10")),
+            ("0.0000000000000000000000000001", Some("-93.01398665684614574036894403")),
+            ("3", Some("1.5849625007211561814537389439")),
+            ("0", None),
+            ("-2", None),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| e.to_string());
+            assert_eq!(expected, x.checked_log2().map(|d| d.to_string()), "log2({})", x);
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.log2().to_string(), "log2({})", x);
+            }
+        }
+    }
 
-    map.insert(k1, k1.to_string());
-    // map[k2] should overwrite map[k1] because k1 == k2.
-    map.insert(k2, k2.to_string());
+    #[test]
+    fn test_log() {
+        let test_cases = &[
+            ("2.25", "1.5", Some("2")),
+            ("1.5", "1.5", Some("1")),
+            ("0.5", "0.5", Some("1")),
+            ("2", "0.5", Some("-1")),
+            ("1024", "0.5", Some("-10")),
+            ("100", "10", Some("2")),
+            ("2.25", "0.5", Some("-1.1699250014423123629074778879")),
+            ("2", "1", None),
+            ("2", "0", None),
+            ("2", "-2", None),
+            ("0", "2", None),
+        ];
+        for &(x, base, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let base = Decimal::from_str(base).unwrap();
+            let expected = expected.map(|e| e.to_string());
+            assert_eq!(
+                expected,
+                x.checked_log(base).map(|d| d.to_string()),
+                "log({}, {})",
+                x,
+                base
+            );
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.log(base).to_string(), "log({}, {})", x, base);
+            }
+        }
+    }
 
-    assert_eq!("1.0", map.get(&k3).expect("could not get k3"));
-    assert_eq!(1, map.len());
+    #[test]
+    fn test_log_with_precision() {
+        let two = Decimal::from(2);
+        assert_eq!("0.3010299957", two.log10_with_precision(10).to_string());
+        assert_eq!("3", Decimal::from(1000).log10_with_precision(5).to_string());
+        assert_eq!("3.32193", Decimal::from(10).log2_with_precision(5).to_string());
+        assert_eq!("-1", Decimal::new(5, 1).log2_with_precision(5).to_string());
+        let test_cases = &[
+            ("10", "3", 8, "2.09590327"),
+            ("0.5", "7", 6, "-0.356207"),
+            ("100", "10", 3, "2"),
+        ];
+        for &(x, base, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let base = Decimal::from_str(base).unwrap();
+            let result = x.log_with_precision(base, dp);
+            assert_eq!(expected, result.to_string(), "{}.log({}) to {} dp", x, base, dp);
+        }
+    }
 
-    // map[k3] should overwrite map[k2] because k3 == k2.
-    map.insert(k3, k3.to_string());
-    // map[k4] should not overwrite map[k3] because k4 != k3.
-    map.insert(k4, k4.to_string());
+    #[test]
+    #[should_panic(expected = "Logarithm is only defined for positive values and bases other than one")]
+    fn test_log_with_precision_panic() {
+        let _ = Decimal::from(10).log_with_precision(Decimal::ONE, 4);
+    }
 
-    assert_eq!(2, map.len());
-    assert_eq!("1.00", map.get(&k1).expect("could not get k1"));
-}
+    #[test]
+    #[should_panic(expected = "Logarithm is only defined for positive values and bases other than one")]
+    fn test_log_panic() {
+        let _ = Decimal::new(10, 0).log(Decimal::ONE);
+    }
 
-#[test]
-fn it_computes_equal_hashes_for_positive_and_negative_zero() {
-    // Verify 0 and -0 have the same hash
-    let k1 = Decimal::from_str("0").unwrap();
-    let k2 = Decimal::from_str("-0").unwrap();
-    assert_eq!("-0", k2.to_string());
-    assert_eq!(k1, k2);
-    let h1 = hash_it(k1);
-    let h2 = hash_it(k2);
-    assert_eq!(h1, h2);
+    #[test]
+    fn test_erf() {
+        let test_cases = &[
+            (
+                Decimal::from_str("-2.0").unwrap(),
+                // Wolfram give -0.9953222650189527
+                Decimal::from_str("-0.9953222650189527341620692564").unwrap(),
+            ),
+            (
+                Decimal::from_str("-0.4").unwrap(),
+                Decimal::from_str("-0.4283923550466684551036038453").unwrap(),
+            ),
+            (
+                Decimal::from_str("0.4").unwrap(),
+                Decimal::from_str("0.4283923550466684551036038453").unwrap(),
+            ),
+            (
+                Decimal::one(),
+                Decimal::from_str("0.8427007929497148693412206351").unwrap(),
+            ),
+            (
+                Decimal::from_str("2").unwrap(),
+                Decimal::from_str("0.9953222650189527341620692564").unwrap(),
+            ),
+        ];
+        for case in test_cases {
+            assert_eq!(case.1, case.0.erf());
+        }
+    }
 
-    // Verify 0 and -0.0 have the same hash
-    let k1 = Decimal::from_str("0").unwrap();
-    let k2 = Decimal::from_str("-0.0").unwrap();
-    assert_eq!("-0.0", k2.to_string());
-    assert_eq!(k1, k2);
-    let h1 = hash_it(k1);
-    let h2 = hash_it(k2);
-    assert_eq!(h1, h2);
-}
+    #[test]
+    fn test_erf_with_precision() {
+        let test_cases = &[
+            ("0.5", 10, "0.5204998778"),
+            ("-3", 20, "-0.99997790950300141456"),
+            ("5", 28, "0.9999999999984625402055719651"),
+            ("-0.0001", 28, "-0.0001128379163334248694861575"),
+            ("10", 28, "1"),
+        ];
+        for &(x, dp, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, x.erf_with_precision(dp), "erf({}) to {} dp", x, dp);
+        }
+    }
 
-#[test]
-#[should_panic]
-fn it_handles_i128_min() {
-    Decimal::from_i128_with_scale(i128::MIN, 0);
-}
+    #[test]
+    fn test_sin() {
+        let test_cases = &[
+            ("0", "0"),
+            ("0.1", "0.0998334166468281523068141984"),
+            ("-0.5", "-0.4794255386042030002732879352"),
+            ("1", "0.8414709848078965066525023216"),
+            ("1.5707963267948966192313216916", "1"),
+            ("3", "0.1411200080598672221007448028"),
+            ("-3.3", "0.1577456941432483820116542776"),
+            ("4.7", "-0.9999232575641008841795365416"),
+            ("10", "-0.5440211108893698134047476619"),
+            ("-1000", "-0.8268795405320025602558874291"),
+            ("100000000000000000000", "-0.6452512852657808442058117113"),
+            ("-79228162514264337593543950335", "-0.8753832556517298517600511989"),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(expected, x.sin().to_string(), "sin({})", x);
+        }
+    }
 
-#[test]
-fn it_can_rescale() {
-    let tests = &[
-        ("0", 6, "0.000000"),
-        ("0.000000", 2, "0.00"),
-        ("0.12345600000", 6, "0.123456"),
-        ("0.123456", 12, "0.123456000000"),
-        ("0.123456", 0, "0"),
-        ("0.000001", 4, "0.0000"),
-        ("1233456", 4, "1233456.0000"),
-        ("1.2", 30, "1.2000000000000000000000000000"),
-        ("79228162514264337593543950335", 0, "79228162514264337593543950335"),
-        ("4951760157141521099596496895", 1, "4951760157141521099596496895.0"),
-        ("4951760157141521099596496896", 1, "4951760157141521099596496896.0"),
-        ("18446744073709551615", 6, "18446744073709551615.000000"),
-        ("-18446744073709551615", 6, "-18446744073709551615.000000"),
-    ];
+    #[test]
+    fn test_cos() {
+        let test_cases = &[
+            ("0", "1"),
+            ("0.1", "0.9950041652780257660955619878"),
+            ("-0.5", "0.8775825618903727161162815826"),
+            ("1", "0.5403023058681397174009366074"),
+            ("2", "-0.4161468365471423869975682295"),
+            ("3.1415926535897932384626433833", "-1"),
+            ("-3.3", "-0.9874797699088648839365910511"),
+            ("7", "0.7539022543433046381411975217"),
+            ("100", "0.8623188722876839341019385140"),
+            ("79228162514264337593543950335", "-0.4834295767995356907034052511"),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(expected, x.cos().to_string(), "cos({})", x);
+        }
+    }
 
-    for &(value_raw, new_scale, expected_value) in tests {
-        let new_value = Decimal::from_str(expected_value).unwrap();
-        let mut value = Decimal::from_str(value_raw).unwrap();
-        value.rescale(new_scale);
-        assert_eq!(new_value.to_string(), value.to_string());
+    #[test]
+    fn test_trig_with_precision() {
+        let test_cases = &[
+            (
+                "1",
+                28,
+                "0.8414709848078965066525023216",
+                "0.5403023058681397174009366074",
+            ),
+            ("1", 10, "0.8414709848", "0.5403023059"),
+            ("-2", 5, "-0.90930", "-0.41615"),
+            ("1000", 0, "1", "1"),
+        ];
+        for &(x, dp, sin, cos) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(sin, x.sin_with_precision(dp).to_string(), "sin({}) to {} dp", x, dp);
+            assert_eq!(cos, x.cos_with_precision(dp).to_string(), "cos({}) to {} dp", x, dp);
+        }
+        let test_cases = &[
+            (
+                "1",
+                28,
+                "1.5574077246549022305069748075",
+                "0.7853981633974483096156608458",
+            ),
+            ("1", 3, "1.557", "0.785"),
+            ("-0.5", 4, "-0.5463", "-0.4636"),
+        ];
+        for &(x, dp, tan, atan) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(tan, x.tan_with_precision(dp).to_string(), "tan({}) to {} dp", x, dp);
+            assert_eq!(atan, x.atan_with_precision(dp).to_string(), "atan({}) to {} dp", x, dp);
+        }
+        let test_cases = &[
+            ("0.5", 6, "0.523599", "1.047198"),
+            ("-0.99", 3, "-1.429", "3.000"),
+            ("0.9999999", 10, "1.5703491132", "0.0004472136"),
+        ];
+        for &(x, dp, asin, acos) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(asin, x.asin_with_precision(dp).to_string(), "asin({}) to {} dp", x, dp);
+            assert_eq!(acos, x.acos_with_precision(dp).to_string(), "acos({}) to {} dp", x, dp);
+        }
+        let test_cases = &[
+            ("1", "-1", 5, "2.35619"),
+            ("-3", "0", 4, "-1.5708"),
+            ("2", "3", 10, "0.5880026035"),
+        ];
+        for &(y, x, dp, expected) in test_cases {
+            let (y, x) = (Decimal::from_str(y).unwrap(), Decimal::from_str(x).unwrap());
+            let result = y.atan2_with_precision(&x, dp);
+            assert_eq!(expected, result.to_string(), "atan2({}, {}) to {} dp", y, x, dp);
+        }
     }
-}
 
-// Mathematical features
-#[cfg(feature = "maths")]
-mod maths {
-    use super::*;
-    use rust_decimal::MathematicalOps;
+    #[test]
+    fn test_tan() {
+        let test_cases = &[
+            ("0", Some("0")),
+            ("0.5", Some("0.5463024898437905132551794658")),
+            ("-1", Some("-1.5574077246549022305069748075")),
+            ("1.5", Some("14.101419947171719387646083652")),
+            ("3", Some("-0.1425465430742778052956354105")),
+            ("100000000000000000000", Some("-0.8446024630198842541840932340")),
+            ("1.5707963267948966192313216916", Some("25156320052992586843308997627")),
+            ("4.7123889803846898576939650749", Some("51936379629159848763421120931")),
+            ("-1.570796326794896619231321692", Some("2775861216004249611331142063.4")),
+            ("1.57079632679489661923132169", Some("609848526018175787771376963.61")),
+            ("-7.8539816339744830961566084582", None),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(
+                expected.map(String::from),
+                x.checked_tan().map(|d| d.to_string()),
+                "tan({})",
+                x
+            );
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.tan().to_string(), "tan({})", x);
+            }
+        }
+    }
 
-    use num_traits::One;
+    #[test]
+    #[should_panic(expected = "Tangent overflowed")]
+    fn test_tan_panic() {
+        let _ = Decimal::from_str("-7.8539816339744830961566084582").unwrap().tan();
+    }
 
     #[test]
-    fn test_powi() {
+    fn test_asin_and_acos() {
         let test_cases = &[
-            (Decimal::new(4, 0), 3_u64, Decimal::new(64, 0)),
+            ("0", Some(("0", "1.5707963267948966192313216916"))),
             (
-                Decimal::from_str("3.222").unwrap(),
-                5_u64,
-                Decimal::from_str("347.238347228449632").unwrap(),
+                "0.5",
+                Some(("0.5235987755982988730771072305", "1.0471975511965977461542144611")),
             ),
             (
-                Decimal::from_str("0.1").unwrap(),
-                0_u64,
-                Decimal::from_str("1").unwrap(),
+                "-0.99",
+                Some(("-1.4292568534704694004855323347", "3.0000531802653660197168540263")),
             ),
-            (
-                Decimal::from_str("342.4").unwrap(),
-                1_u64,
-                Decimal::from_str("342.4").unwrap(),
+            (
+                "0.9999999",
+                Some(("1.5703491131956698812456876558", "0.0004472135992267379856340359")),
             ),
+            ("1", Some(("1.5707963267948966192313216916", "0"))),
             (
-                Decimal::from_str("2.0").unwrap(),
-                16_u64,
-                Decimal::from_str("65536").unwrap(),
+                "-1",
+                Some(("-1.5707963267948966192313216916", "3.1415926535897932384626433833")),
             ),
+            ("1.0000000000000000000000000001", None),
+            ("-2", None),
         ];
-        for case in test_cases {
-            assert_eq!(case.2, case.0.powi(case.1));
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let asin = x.checked_asin().map(|d| d.to_string());
+            let acos = x.checked_acos().map(|d| d.to_string());
+            assert_eq!(expected.map(|e| e.0.to_string()), asin, "asin({})", x);
+            assert_eq!(expected.map(|e| e.1.to_string()), acos, "acos({})", x);
         }
     }
 
     #[test]
-    #[should_panic]
-    fn test_powi_panic() {
-        let two = Decimal::new(2, 0);
-        let _ = two.powi(128);
+    #[should_panic(expected = "Arcsine is only defined for values between -1 and 1")]
+    fn test_asin_panic() {
+        let _ = Decimal::new(2, 0).asin();
+    }
+
+    #[test]
+    #[should_panic(expected = "Arccosine is only defined for values between -1 and 1")]
+    fn test_acos_panic() {
+        let _ = Decimal::new(-2, 0).acos();
+    }
+
+    #[test]
+    #[should_panic(expected = "Arcsine is only defined for values between -1 and 1")]
+    fn test_asin_with_precision_panic() {
+        let _ = Decimal::new(-11, 1).asin_with_precision(4);
     }
 
     #[test]
-    fn test_checked_powi() {
+    fn test_atan() {
         let test_cases = &[
-            (Decimal::new(4, 0), 3_u64, Some(Decimal::new(64, 0))),
-            (
-                Decimal::from_str("3.222").unwrap(),
-                5_u64,
-                Some(Decimal::from_str("347.238347228449632").unwrap()),
-            ),
-            (
-                Decimal::from_str("0.1").unwrap(),
-                0_u64,
-                Some(Decimal::from_str("1").unwrap()),
-            ),
+            ("0", "0"),
+            ("0.1", "0.0996686524911620273784461199"),
+            ("-0.5", "-0.4636476090008061162142562315"),
+            ("1", "0.7853981633974483096156608458"),
+            ("2", "1.1071487177940905030170654602"),
+            ("-1000", "-1.5697963271282297525647978820"),
+            ("79228162514264337593543950335", "1.5707963267948966192313216916"),
+            ("0.0000000000000000000000000001", "0.0000000000000000000000000001"),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(expected, x.atan().to_string(), "atan({})", x);
+        }
+    }
+
+    #[test]
+    fn test_atan2() {
+        let test_cases = &[
+            ("1", "1", "0.7853981633974483096156608458"),
+            ("1", "-1", "2.3561944901923449288469825375"),
+            ("-1", "-1", "-2.3561944901923449288469825375"),
+            ("-1", "1", "-0.7853981633974483096156608458"),
+            ("0", "-1", "3.1415926535897932384626433833"),
+            ("0", "1", "0"),
+            ("2", "0", "1.5707963267948966192313216916"),
+            ("-2", "0", "-1.5707963267948966192313216916"),
+            ("0", "0", "0"),
+            ("3", "0.0001", "1.5707629934615756315769924735"),
             (
-                Decimal::from_str("342.4").unwrap(),
-                1_u64,
-                Some(Decimal::from_str("342.4").unwrap()),
+                "79228162514264337593543950335",
+                "0.0000000000000000000000000001",
+                "1.5707963267948966192313216916",
             ),
             (
-                Decimal::from_str("2.0").unwrap(),
-                16_u64,
-                Some(Decimal::from_str("65536").unwrap()),
+                "-0.0000000000000000000000000001",
+                "-79228162514264337593543950335",
+                "-3.1415926535897932384626433833",
             ),
-            (Decimal::from_str("2.0").unwrap(), 128_u64, None),
         ];
-        for case in test_cases {
-            assert_eq!(case.2, case.0.checked_powi(case.1));
+        for &(y, x, expected) in test_cases {
+            let y = Decimal::from_str(y).unwrap();
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(expected, y.atan2(&x).to_string(), "atan2({}, {})", y, x);
         }
     }
 
     #[test]
-    fn test_sqrt() {
+    fn test_sinh() {
         let test_cases = &[
-            ("4", "2"),
-            ("3.222", "1.7949930361981909371487724124"),
-            ("199.45", "14.122676800097069416754994263"),
-            ("342.4", "18.504053609952604112132102540"),
-            ("2", "1.414213562373095048801688724209698078569671875376948073176"),
-            ("0.0000000000000000000000000001", "0.0000000000000100000000000000"),
+            ("0", Some("0")),
+            ("1", Some("1.1752011936438014568823818506")),
+            ("-0.5", Some("-0.5210953054937473616224256264")),
+            ("10", Some("11013.232874703393377236524555")),
+            ("-67", Some("-62618158542110689025676098037")),
+            ("67.3", None),
+            ("-100", None),
         ];
-        for case in test_cases {
-            let a = Decimal::from_str(case.0).unwrap();
-            let expected = Decimal::from_str(case.1).unwrap();
-            assert_eq!(expected, a.sqrt().unwrap());
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, x.checked_sinh(), "sinh({})", x);
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.sinh(), "sinh({})", x);
+            }
         }
+    }
 
-        assert_eq!(Decimal::new(-2, 0).sqrt(), None);
+    #[test]
+    #[should_panic(expected = "Hyperbolic sine overflowed")]
+    fn test_sinh_panic() {
+        let _ = Decimal::new(100, 0).sinh();
     }
 
     #[test]
-    fn test_exp() {
+    fn test_cosh() {
         let test_cases = &[
-            ("10", "22023.81992829"),
-            ("11", "59846.36875797"),
-            ("3", "20.08553690"),
-            ("8", "2980.94688158"),
-            ("0.1", "1.10517092"),
-            ("2.0", "7.38905609"),
-            ("-2", "0.13533531"),
-            ("-1", "0.36787944"),
+            ("0", Some("1")),
+            ("1", Some("1.5430806348152437784779056208")),
+            ("-0.5", Some("1.1276259652063807852262251614")),
+            ("10", Some("11013.232920103323139721376090")),
+            ("67", Some("62618158542110689025676098037")),
+            ("-67.3", None),
+            ("100", None),
         ];
-        for case in test_cases {
-            let x = Decimal::from_str(case.0).unwrap();
-            let expected = Decimal::from_str(case.1).unwrap();
-            assert_eq!(expected, x.exp());
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            assert_eq!(expected, x.checked_cosh(), "cosh({})", x);
+            if let Some(expected) = expected {
+                assert_eq!(expected, x.cosh(), "cosh({})", x);
+            }
         }
     }
 
     #[test]
-    fn test_exp_with_tolerance() {
+    #[should_panic(expected = "Hyperbolic cosine overflowed")]
+    fn test_cosh_panic() {
+        let _ = Decimal::new(-100, 0).cosh();
+    }
+
+    #[test]
+    fn test_tanh() {
+        let test_cases = &[
+            ("0", "0"),
+            ("0.5", "0.4621171572600097585023184836"),
+            ("-1", "-0.7615941559557648881194582826"),
+            ("20", "0.9999999999999999915032914894"),
+            ("-50", "-1"),
+            ("79228162514264337593543950335", "1"),
+        ];
+        for &(x, expected) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            let expected = Decimal::from_str(expected).unwrap();
+            assert_eq!(expected, x.tanh(), "tanh({})", x);
+        }
+    }
+
+    #[test]
+    fn test_hyperbolic_with_precision() {
+        let test_cases = &[
+            ("1", 10, "1.1752011936", "1.5430806348", "0.7615941560"),
+            ("-2", 3, "-3.627", "3.762", "-0.964"),
+            ("-0.5", 5, "-0.52110", "1.12763", "-0.46212"),
+        ];
+        for &(x, dp, sinh, cosh, tanh) in test_cases {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(sinh, x.sinh_with_precision(dp).to_string(), "sinh({}) to {} dp", x, dp);
+            assert_eq!(cosh, x.cosh_with_precision(dp).to_string(), "cosh({}) to {} dp", x, dp);
+            assert_eq!(tanh, x.tanh_with_precision(dp).to_string(), "tanh({}) to {} dp", x, dp);
+        }
+        // Digits are only discarded when the integral part leaves no room for them
+        let sixty = Decimal::from(60);
+        assert_eq!(
+            "57100369490784214183147859.157",
+            sixty.sinh_with_precision(28).to_string()
+        );
+        assert_eq!("57100369490784214183147859", sixty.cosh_with_precision(0).to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Hyperbolic cosine overflowed")]
+    fn test_cosh_with_precision_panic() {
+        let _ = Decimal::from(100).cosh_with_precision(2);
+    }
+}
+
+#[cfg(feature = "finance")]
+mod finance {
+    use super::*;
+    use rust_decimal::finance::{self, AmortizationPeriod, AmortizationSchedule, DayCount, PaymentTiming};
+
+    fn decimal(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    // Results are only compared to 20 decimal places since they are not correctly rounded
+    fn assert_close(expected: Option<&str>, actual: Option<Decimal>, message: &str) {
+        assert_eq!(
+            expected.map(|e| decimal(e).round_dp(20)),
+            actual.map(|a| a.round_dp(20)),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn test_pmt() {
         let test_cases = &[
             (
-                Decimal::new(10, 0),
-                Decimal::new(3, 2),
-                Decimal::from_str("22023.81992829").unwrap(),
+                "0.005",
+                "360",
+                "200000",
+                "0",
+                PaymentTiming::End,
+                Some("-1199.1010503055047891829224874"),
             ),
             (
-                Decimal::new(11, 0),
-                Decimal::new(2, 4),
-                Decimal::from_str("59846.36875797").unwrap(),
+                "0.005",
+                "360",
+                "200000",
+                "0",
+                PaymentTiming::Beginning,
+                Some("-1193.1353734383132230675845645"),
             ),
+            ("0", "10", "1000", "-200", PaymentTiming::End, Some("-80")),
+            ("0.01", "0", "1000", "0", PaymentTiming::End, None),
+        ];
+        for &(rate, nper, pv, fv, timing, expected) in test_cases {
+            let actual = finance::pmt(decimal(rate), decimal(nper), decimal(pv), decimal(fv), timing);
+            assert_close(
+                expected,
+                actual,
+                &format!("pmt({}, {}, {}, {}, {:?})", rate, nper, pv, fv, timing),
+            );
+        }
+    }
+
+    #[test]
+    fn test_fv() {
+        let test_cases = &[
             (
-                Decimal::new(3, 0),
-                Decimal::new(2, 5),
-                Decimal::from_str("20.08553442").unwrap(),
+                "0.005",
+                "10",
+                "-200",
+                "-500",
+                PaymentTiming::Beginning,
+                Some("2581.4033740601791537250068359"),
             ),
             (
-                Decimal::from_str("8").unwrap(),
-                Decimal::new(2, 4),
-                Decimal::from_str("2980.94688158").unwrap(),
+                "0.005",
+                "10",
+                "-200",
+                "-500",
+                PaymentTiming::End,
+                Some("2571.1753476520210252054736328"),
             ),
+            ("0", "12", "-100", "-1000", PaymentTiming::End, Some("2200")),
+            ("1000", "30", "-1", "0", PaymentTiming::End, None),
+        ];
+        for &(rate, nper, pmt, pv, timing, expected) in test_cases {
+            let actual = finance::fv(decimal(rate), decimal(nper), decimal(pmt), decimal(pv), timing);
+            assert_close(
+                expected,
+                actual,
+                &format!("fv({}, {}, {}, {}, {:?})", rate, nper, pmt, pv, timing),
+            );
+        }
+    }
+
+    #[test]
+    fn test_pv() {
+        let test_cases = &[
             (
-                Decimal::from_str("0.1").unwrap(),
-                Decimal::new(2, 4),
-                Decimal::from_str("1.10516667").unwrap(),
+                "0.08",
+                "20",
+                "500",
+                "0",
+                PaymentTiming::End,
+                Some("-4909.0737037246453513654224066"),
             ),
             (
-                Decimal::from_str("2.0").unwrap(),
-                Decimal::new(2, 4),
-                Decimal::from_str("7.38904603").unwrap(),
+                "0.08",
+                "20",
+                "500",
+                "1000",
+                PaymentTiming::Beginning,
+                Some("-5516.3478074266737232561886141"),
             ),
+            ("0", "20", "500", "1000", PaymentTiming::End, Some("-11000")),
         ];
-        for case in test_cases {
-            assert_eq!(case.2, case.0.exp_with_tolerance(case.1));
+        for &(rate, nper, pmt, fv, timing, expected) in test_cases {
+            let actual = finance::pv(decimal(rate), decimal(nper), decimal(pmt), decimal(fv), timing);
+            assert_close(
+                expected,
+                actual,
+                &format!("pv({}, {}, {}, {}, {:?})", rate, nper, pmt, fv, timing),
+            );
         }
     }
 
     #[test]
-    fn test_norm_cdf() {
+    fn test_nper() {
         let test_cases = &[
             (
-                Decimal::from_str("-0.4").unwrap(),
-                either!(
-                    Decimal::from_str("0.3445781286821245037094401704").unwrap(),
-                    Decimal::from_str("0.3445781286821245037094401728").unwrap()
-                ),
-            ),
-            (
-                Decimal::from_str("-0.1").unwrap(),
-                either!(
-                    Decimal::from_str("0.4601722899186706579921922696").unwrap(),
-                    Decimal::from_str("0.4601722899186706579921922711").unwrap()
-                ),
+                "0.01",
+                "-100",
+                "-1000",
+                "10000",
+                PaymentTiming::Beginning,
+                Some("59.673865674294625587503590430"),
             ),
             (
-                Decimal::from_str("0.1").unwrap(),
-                Decimal::from_str(either!(
-                    "0.5398277100813293420078077304",
-                    "0.5398277100813293420078077290"
-                ))
-                .unwrap(),
+                "0.01",
+                "-100",
+                "1000",
+                "0",
+                PaymentTiming::End,
+                Some("10.588644459423235995188205269"),
             ),
+            ("0", "-100", "1000", "0", PaymentTiming::End, Some("10")),
+            // The payment never covers the interest
+            ("0.1", "-50", "1000", "0", PaymentTiming::End, None),
+        ];
+        for &(rate, pmt, pv, fv, timing, expected) in test_cases {
+            let actual = finance::nper(decimal(rate), decimal(pmt), decimal(pv), decimal(fv), timing);
+            assert_close(
+                expected,
+                actual,
+                &format!("nper({}, {}, {}, {}, {:?})", rate, pmt, pv, fv, timing),
+            );
+        }
+    }
+
+    #[test]
+    fn test_rate() {
+        let test_cases = &[
             (
-                Decimal::from_str("0.4").unwrap(),
-                either!(
-                    Decimal::from_str("0.6554218713178754962905598296").unwrap(),
-                    Decimal::from_str("0.6554218713178754962905598272").unwrap()
-                ),
+                "48",
+                "-200",
+                "8000",
+                "0",
+                PaymentTiming::End,
+                Some("0.0077014724882020438159691301"),
             ),
             (
-                Decimal::from_str("2.0").unwrap(),
-                either!(
-                    Decimal::from_str("0.9772497381095865280953380673").unwrap(),
-                    Decimal::from_str("0.9772497381095865280953380672").unwrap()
-                ),
+                "60",
+                "-1000",
+                "40000",
+                "5000",
+                PaymentTiming::Beginning,
+                Some("0.0126989184531347621050327999"),
             ),
+            ("10", "-100", "1000", "0", PaymentTiming::End, Some("0")),
         ];
-        for case in test_cases {
-            assert_eq!(case.1, case.0.norm_cdf());
+        for &(nper, pmt, pv, fv, timing, expected) in test_cases {
+            let actual = finance::rate(decimal(nper), decimal(pmt), decimal(pv), decimal(fv), timing);
+            assert_close(
+                expected,
+                actual,
+                &format!("rate({}, {}, {}, {}, {:?})", nper, pmt, pv, fv, timing),
+            );
         }
     }
 
     #[test]
-    fn test_norm_pdf() {
-        let test_cases = &[
+    fn test_npv() {
+        let test_cases: &[(&str, &[&str], Option<&str>)] = &[
             (
-                Decimal::from_str("-2.0").unwrap(),
-                Decimal::from_str("0.0539909771902348159131327614").unwrap(),
+                "0.1",
+                &["-10000", "3000", "4200", "6800"],
+                Some("1188.4434123352230038931766956"),
             ),
+            ("0", &["-10000", "3000", "4200", "6800"], Some("4000")),
+            ("0.1", &[], Some("0")),
+            ("-1", &["100"], None),
+        ];
+        for &(rate, values, expected) in test_cases {
+            let values = values.iter().map(|v| decimal(v)).collect::<Vec<_>>();
+            let actual = finance::npv(decimal(rate), &values);
+            assert_close(expected, actual, &format!("npv({}, {:?})", rate, values));
+        }
+    }
+
+    #[test]
+    fn test_irr() {
+        let test_cases: &[(&[&str], Option<&str>)] = &[
             (
-                Decimal::from_str("-0.4").unwrap(),
-                Decimal::from_str("0.3682701417448470684306485260").unwrap(),
+                &["-70000", "12000", "15000", "18000", "21000", "26000"],
+                Some("0.0866309480365316142930942025"),
             ),
             (
-                Decimal::from_str("-0.1").unwrap(),
-                Decimal::from_str("0.3969525477990849244300670202").unwrap(),
+                &["-70000", "12000", "15000", "18000", "21000"],
+                Some("-0.0212448482734109910310502248"),
             ),
+            (&["-100", "100"], Some("0")),
+            (&["-100"], None),
+            // Every cash flow has the same sign, so there is no solution
+            (&["100", "200", "300"], None),
+        ];
+        for &(values, expected) in test_cases {
+            let values = values.iter().map(|v| decimal(v)).collect::<Vec<_>>();
+            assert_close(expected, finance::irr(&values), &format!("irr({:?})", values));
+        }
+    }
+
+    #[test]
+    fn test_xnpv_and_xirr() {
+        let values = ["-10000", "2750", "4250", "3250", "2750"]
+            .iter()
+            .map(|v| decimal(v))
+            .collect::<Vec<_>>();
+        let dates = [39448, 39508, 39751, 39859, 39904];
+        let test_cases = &[
             (
-                Decimal::from_str("0.1").unwrap(),
-                Decimal::from_str("0.3969525477990849244300670202").unwrap(),
+                DayCount::Actual365,
+                "2086.6476020315366216636100943",
+                "0.3733625335188315103084554119",
             ),
             (
-                Decimal::from_str("0.4").unwrap(),
-                Decimal::from_str("0.3682701417448470684306485260").unwrap(),
+                DayCount::Actual360,
+                "2074.5227849244788725454243916",
+                "0.3674067735326008003266813983",
             ),
             (
-                Decimal::from_str("2.0").unwrap(),
-                Decimal::from_str("0.0539909771902348159131327614").unwrap(),
+                DayCount::Actual36525,
+                "2087.2455137721482806537260154",
+                "0.3736610015164226520869332360",
             ),
         ];
-        for case in test_cases {
-            assert_eq!(case.1, case.0.norm_pdf());
+        for &(day_count, xnpv, xirr) in test_cases {
+            assert_close(
+                Some(xnpv),
+                finance::xnpv(decimal("0.09"), &values, &dates, day_count),
+                &format!("xnpv({:?})", day_count),
+            );
+            assert_close(
+                Some(xirr),
+                finance::xirr(&values, &dates, day_count),
+                &format!("xirr({:?})", day_count),
+            );
         }
+        // Actual/365 is the default, as used by the spreadsheet functions
+        assert_eq!(DayCount::Actual365, DayCount::default());
+        // The result is independent of the day numbering
+        let shifted = dates.iter().map(|d| d - 39448).collect::<Vec<_>>();
+        assert_eq!(
+            finance::xirr(&values, &dates, DayCount::Actual365),
+            finance::xirr(&values, &shifted, DayCount::Actual365)
+        );
+
+        let day_count = DayCount::default();
+        assert_eq!(None, finance::xnpv(decimal("0.09"), &values, &dates[1..], day_count));
+        assert_eq!(None, finance::xnpv(decimal("0.09"), &[], &[], day_count));
+        assert_eq!(None, finance::xirr(&values[..1], &dates[..1], day_count));
     }
 
     #[test]
-    fn test_ln() {
-        let test_cases = &[
-            (Decimal::from_str("1").unwrap(), Decimal::from_str("0").unwrap()),
-            (Decimal::from_str("-2.0").unwrap(), Decimal::from_str("0").unwrap()),
+    fn test_amortization_schedule() {
+        let schedule = AmortizationSchedule::new(
+            decimal("1000"),
+            decimal("0.01"),
+            3,
+            2,
+            RoundingStrategy::MidpointAwayFromZero,
+        )
+        .unwrap();
+        assert_eq!(decimal("340.02"), schedule.payment());
+        assert_eq!(3, schedule.len());
+        let expected = [
+            ("340.02", "10.00", "330.02", "669.98"),
+            ("340.02", "6.70", "333.32", "336.66"),
+            ("340.03", "3.37", "336.66", "0.00"),
+        ];
+        for (index, (actual, &(payment, interest, principal, balance))) in schedule.zip(expected.iter()).enumerate() {
+            let expected = AmortizationPeriod {
+                period: index as u32 + 1,
+                payment: decimal(payment),
+                interest: decimal(interest),
+                principal: decimal(principal),
+                balance: decimal(balance),
+            };
+            assert_eq!(expected, actual);
+            assert_eq!(payment, actual.payment.to_string());
+            assert_eq!(interest, actual.interest.to_string());
+        }
+
+        // The principal repaid always sums to the amount borrowed
+        let principal = decimal("200000");
+        let rate = decimal("0.005");
+        for &strategy in &[
+            RoundingStrategy::MidpointNearestEven,
+            RoundingStrategy::ToZero,
+            RoundingStrategy::AwayFromZero,
+        ] {
+            let periods = AmortizationSchedule::new(principal, rate, 360, 2, strategy)
+                .unwrap()
+                .collect::<Vec<_>>();
+            assert_eq!(360, periods.len());
+            assert_eq!(principal, periods.iter().map(|p| p.principal).sum::<Decimal>());
+            assert!(periods.last().unwrap().balance.is_zero());
+            for period in &periods {
+                assert_eq!(period.payment, period.interest + period.principal);
+                assert!(period.interest.scale() <= 2);
+            }
+        }
+
+        assert!(AmortizationSchedule::new(principal, rate, 0, 2, RoundingStrategy::ToZero).is_none());
+        // The payment fits, but the interest on the principal does not
+        let principal = decimal("31691265005705735037417580134");
+        let rate = decimal("-3");
+        assert!(AmortizationSchedule::new(principal, rate, 1, 2, RoundingStrategy::ToZero).is_none());
+    }
+}
+
+#[cfg(feature = "stats")]
+mod stats {
+    use super::*;
+    use rust_decimal::stats::{self, Interpolation, VarianceKind};
+
+    fn decimals(values: &[&str]) -> Vec<Decimal> {
+        values.iter().map(|v| Decimal::from_str(v).unwrap()).collect()
+    }
+
+    fn expected(value: Option<&str>) -> Option<Decimal> {
+        value.map(|v| Decimal::from_str(v).unwrap())
+    }
+
+    #[test]
+    fn test_mean() {
+        let test_cases: &[(&[&str], Option<&str>)] = &[
+            (&["1", "2", "3", "4"], Some("2.5")),
+            (&["0.1", "0.2", "0.3"], Some("0.2")),
+            (&["1", "2", "2"], Some("1.6666666666666666666666666667")),
+            (&["-5.5"], Some("-5.5")),
+            // The sum is exact even though it can't be represented at this scale
             (
-                Decimal::from_str("0.23").unwrap(),
-                // Wolfram Alpha gives -1.46968
-                Decimal::from_str("-1.4661188292208822723626471532").unwrap(),
+                &[
+                    "10000000000000000000000000000",
+                    "0.4",
+                    "0.4",
+                    "-10000000000000000000000000000",
+                ],
+                Some("0.2"),
             ),
+            // The sum is divided before it is narrowed, so it can exceed the range of a Decimal
             (
-                Decimal::from_str("2").unwrap(),
-                // Wolfram Alpha gives 0.693147180559945309417232121458176568075500134360255254120
-                Decimal::from_str(either!(
-                    "0.6932271134541528994884316448",
-                    "0.6932271134541528994884316422"
-                ))
-                .unwrap(),
+                &["79228162514264337593543950335", "79228162514264337593543950335"],
+                Some("79228162514264337593543950335"),
             ),
             (
-                Decimal::from_str("25").unwrap(),
-                // Wolfram Alpha gives 3.218875824868200749201518666452375279051202708537035443825
-                Decimal::from_str(either!(
-                    "3.2188760588726737559923925704",
-                    "3.218876058872673755992392564"
-                ))
-                .unwrap(),
+                &["79228162514264337593543950335", "1"],
+                Some("39614081257132168796771975168"),
+            ),
+            (
+                &[
+                    "-79228162514264337593543950335",
+                    "-79228162514264337593543950335",
+                    "-0.0000000000000000000000000001",
+                ],
+                Some("-52818775009509558395695966890"),
             ),
+            (&[], None),
         ];
+        for &(values, result) in test_cases {
+            let values = decimals(values);
+            assert_eq!(expected(result), stats::mean(values.clone()), "mean({:?})", values);
+        }
+    }
 
-        for case in test_cases {
-            assert_eq!(case.1, case.0.ln());
+    #[test]
+    fn test_weighted_mean() {
+        type WeightedValues = &'static [(&'static str, &'static str)];
+        let test_cases: &[(WeightedValues, Option<&str>)] = &[
+            (&[("10", "1"), ("20", "3")], Some("17.5")),
+            (&[("1.5", "0.25"), ("2.5", "0.75")], Some("2.25")),
+            (&[("1", "1"), ("2", "-1")], None),
+            (&[], None),
+        ];
+        for &(values, result) in test_cases {
+            let values = values
+                .iter()
+                .map(|&(v, w)| (Decimal::from_str(v).unwrap(), Decimal::from_str(w).unwrap()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                expected(result),
+                stats::weighted_mean(values.clone()),
+                "weighted_mean({:?})",
+                values
+            );
         }
     }
 
     #[test]
-    fn test_erf() {
-        let test_cases = &[
+    fn test_median() {
+        let test_cases: &[(&[&str], Option<&str>)] = &[
+            (&["3", "1", "2"], Some("2")),
+            (&["4", "1", "3", "2"], Some("2.5")),
+            (&["-1", "1"], Some("0")),
             (
-                Decimal::from_str("-2.0").unwrap(),
-                // Wolfram give -0.9953222650189527
-                Decimal::from_str("-0.9953225170750043399400930073").unwrap(),
+                &["79228162514264337593543950335", "79228162514264337593543950333"],
+                Some("79228162514264337593543950334"),
             ),
             (
-                Decimal::from_str("-0.4").unwrap(),
-                Decimal::from_str("-0.4283924127205154977961931420").unwrap(),
+                &["79228162514264337593543950335", "-79228162514264337593543950335"],
+                Some("0"),
+            ),
+            (&[], None),
+        ];
+        for &(values, result) in test_cases {
+            let values = decimals(values);
+            assert_eq!(expected(result), stats::median(values.clone()), "median({:?})", values);
+        }
+    }
+
+    #[test]
+    fn test_variance_and_std_dev() {
+        type Expected = Option<&'static str>;
+        let test_cases: &[(&[&str], VarianceKind, Expected, Expected)] = &[
+            (
+                &["2", "4", "4", "4", "5", "5", "7", "9"],
+                VarianceKind::Population,
+                Some("4"),
+                Some("2"),
             ),
             (
-                Decimal::from_str("0.4").unwrap(),
-                Decimal::from_str("0.4283924127205154977961931420").unwrap(),
+                &["2", "4", "4", "4", "5", "5", "7", "9"],
+                VarianceKind::Sample,
+                Some("4.5714285714285714285714285714"),
+                Some("2.1380899352993950774764278470"),
             ),
+            (&["1.5"], VarianceKind::Population, Some("0"), Some("0")),
+            (&["1.5"], VarianceKind::Sample, None, None),
+            (&[], VarianceKind::Population, None, None),
             (
-                Decimal::one(),
-                Decimal::from_str("0.8427010463338918630217928957").unwrap(),
+                &["79228162514264337593543950335", "-79228162514264337593543950335"],
+                VarianceKind::Population,
+                None,
+                None,
             ),
+            // The squared deviations sum to more than Decimal::MAX
             (
-                Decimal::from_str("2").unwrap(),
-                Decimal::from_str("0.9953225170750043399400930073").unwrap(),
+                &["0", "500000000000000"],
+                VarianceKind::Population,
+                Some("62500000000000000000000000000"),
+                Some("250000000000000"),
             ),
+            (&["0", "500000000000000"], VarianceKind::Sample, None, None),
         ];
-        for case in test_cases {
-            assert_eq!(case.1, case.0.erf());
+        for &(values, kind, variance, std_dev) in test_cases {
+            let values = decimals(values);
+            assert_eq!(
+                expected(variance),
+                stats::variance(values.clone(), kind),
+                "variance({:?}, {:?})",
+                values,
+                kind
+            );
+            assert_eq!(
+                expected(std_dev),
+                stats::std_dev(values.clone(), kind),
+                "std_dev({:?}, {:?})",
+                values,
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values = decimals(&["15", "20", "35", "40", "50"]);
+        let test_cases = &[
+            ("0", Interpolation::Linear, Some("15")),
+            ("100", Interpolation::Linear, Some("50")),
+            ("50", Interpolation::Linear, Some("35")),
+            ("40", Interpolation::Linear, Some("29")),
+            ("40", Interpolation::Lower, Some("20")),
+            ("40", Interpolation::Higher, Some("35")),
+            ("40", Interpolation::Nearest, Some("35")),
+            ("30", Interpolation::Nearest, Some("20")),
+            ("40", Interpolation::Midpoint, Some("27.5")),
+            // A rank of 2.5 is halfway between two values, so the one with an even index is used
+            ("62.5", Interpolation::Nearest, Some("35")),
+            ("87.5", Interpolation::Nearest, Some("50")),
+            ("-1", Interpolation::Linear, None),
+            ("100.1", Interpolation::Linear, None),
+        ];
+        for &(percentile, interpolation, result) in test_cases {
+            let percentile = Decimal::from_str(percentile).unwrap();
+            assert_eq!(
+                expected(result),
+                stats::percentile(values.iter().rev().copied(), percentile, interpolation),
+                "percentile({}, {:?})",
+                percentile,
+                interpolation
+            );
+        }
+        assert_eq!(None, stats::percentile(Vec::new(), Decimal::ONE, Interpolation::Linear));
+        // Negative zero is a valid percentile
+        let negative_zero = Decimal::from_str("-0").unwrap();
+        assert!(negative_zero.is_sign_negative());
+        assert_eq!(
+            expected(Some("15")),
+            stats::percentile(values.clone(), negative_zero, Interpolation::Linear)
+        );
+
+        // Interpolating halfway gives the same scale as the midpoint
+        let values = decimals(&["1", "2", "3", "4", "5"]);
+        for &(percentile, interpolation, result) in &[
+            ("12.5", Interpolation::Linear, "1.5"),
+            ("12.5", Interpolation::Midpoint, "1.5"),
+            ("37.50", Interpolation::Linear, "2.5"),
+            ("10", Interpolation::Linear, "1.4"),
+        ] {
+            let percentile = Decimal::from_str(percentile).unwrap();
+            let actual = stats::percentile(values.clone(), percentile, interpolation).map(|d| d.to_string());
+            assert_eq!(
+                Some(result.to_string()),
+                actual,
+                "percentile({}, {:?})",
+                percentile,
+                interpolation
+            );
+        }
+
+        // The rank is just below or above an index, so must not be rounded onto it
+        let values = decimals(&["1", "2", "3", "4"]);
+        let test_cases = &[
+            ("33.333333333333333333333333333", Interpolation::Lower, "1"),
+            ("33.333333333333333333333333333", Interpolation::Higher, "2"),
+            ("33.333333333333333333333333333", Interpolation::Nearest, "2"),
+            (
+                "33.333333333333333333333333333",
+                Interpolation::Linear,
+                "2.0000000000000000000000000000",
+            ),
+            ("33.33333333333333333333333333", Interpolation::Lower, "1"),
+            ("66.666666666666666666666666667", Interpolation::Lower, "3"),
+            ("66.666666666666666666666666667", Interpolation::Higher, "4"),
+            ("66.666666666666666666666666667", Interpolation::Nearest, "3"),
+            ("0.0000000000000000000000000001", Interpolation::Higher, "2"),
+            (
+                "0.0000000000000000000000000001",
+                Interpolation::Linear,
+                "1.0000000000000000000000000000",
+            ),
+            ("16.666666666666666666666666667", Interpolation::Nearest, "2"),
+        ];
+        for &(percentile, interpolation, result) in test_cases {
+            let percentile = Decimal::from_str(percentile).unwrap();
+            assert_eq!(
+                expected(Some(result)),
+                stats::percentile(values.clone(), percentile, interpolation),
+                "percentile({}, {:?})",
+                percentile,
+                interpolation
+            );
         }
     }
+
+    #[test]
+    fn test_min_and_max() {
+        let values = decimals(&["1.5", "-2", "10.25", "0"]);
+        assert_eq!(expected(Some("-2")), stats::min(values.clone()));
+        assert_eq!(expected(Some("10.25")), stats::max(values));
+        assert_eq!(None, stats::min(Vec::new()));
+        assert_eq!(None, stats::max(Vec::new()));
+    }
 }
 
 // Generated tests